@@ -51,6 +51,34 @@ pub const VALID_INITIALS_2: &[[u16; 2]] = &[
     [keys::T, keys::R], // tr
 ];
 
+/// Consonant clusters accepted as initials by the loanword profile
+/// Used in Vietnamese spellings of loanwords (blốc, clo, phanh-xtốp)
+pub const LOANWORD_INITIALS_2: &[[u16; 2]] = &[
+    [keys::B, keys::L], // bl
+    [keys::B, keys::R], // br
+    [keys::C, keys::L], // cl
+    [keys::C, keys::R], // cr
+    [keys::D, keys::R], // dr
+    [keys::F, keys::L], // fl
+    [keys::F, keys::R], // fr
+    [keys::G, keys::L], // gl
+    [keys::G, keys::R], // gr
+    [keys::K, keys::L], // kl
+    [keys::P, keys::L], // pl
+    [keys::P, keys::R], // pr
+    [keys::S, keys::L], // sl
+    [keys::S, keys::P], // sp
+    [keys::S, keys::T], // st
+    [keys::X, keys::T], // xt
+];
+
+/// Three-letter clusters accepted as initials by the loanword profile
+pub const LOANWORD_INITIALS_3: &[[u16; 3]] = &[
+    [keys::S, keys::P, keys::R], // spr
+    [keys::S, keys::T, keys::R], // str
+    [keys::X, keys::T, keys::R], // xtr (Xtrét)
+];
+
 // =============================================================================
 // FINAL CONSONANTS
 // =============================================================================
@@ -77,6 +105,9 @@ pub const VALID_FINALS_2: &[[u16; 2]] = &[
     [keys::N, keys::H], // nh
 ];
 
+/// Foreign finals accepted by the loanword profile (-l, -s)
+pub const LOANWORD_FINALS_1: &[u16] = &[keys::L, keys::S];
+
 // =============================================================================
 // VALID VOWEL PATTERNS (Whitelist from docs 7.6.1)
 // =============================================================================
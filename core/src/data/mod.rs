@@ -4,12 +4,14 @@
 //! - `keys`: Virtual keycode definitions (platform-specific)
 //! - `chars`: Unicode character conversion (includes tone/mark constants)
 //! - `vowel`: Vietnamese vowel phonology system
+//! - `ngram`: English/Vietnamese trigram tables for the word classifier
 //! - `telex_doubles`: English words with Telex double patterns for auto-restore
 
 pub mod chars;
 pub mod constants;
 pub mod english_dict;
 pub mod keys;
+pub mod ngram;
 pub mod telex_doubles;
 pub mod vowel;
 
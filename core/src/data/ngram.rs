@@ -0,0 +1,1471 @@
+//! English/Vietnamese trigram tables for `engine::classifier`
+//!
+//! Generated by `cargo test --test classifier_test train -- --ignored`
+//! from `english_dict_merged.txt` and `tests/data/vietnamese_22k.txt`.
+//! Index: `trigram_index(a, b, c)`, value: -log2 p(c | a b) in quarter bits.
+
+#[rustfmt::skip]
+pub static ENGLISH: [u8; 19683] = [
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    28, 24, 19, 15, 13, 31, 15, 20, 35, 22, 41, 34, 15, 20, 14, 36, 17, 35, 13, 11, 17, 18, 25, 27, 40, 35, 34,
+    30, 10, 31, 36, 34, 10, 44, 38, 35, 15, 39, 40, 13, 34, 38, 11, 38, 61, 11, 30, 35, 12, 47, 39, 56, 26, 55,
+    29, 11, 38, 33, 34, 19, 37, 37, 12, 21, 43, 31, 16, 37, 34, 5, 37, 50, 14, 31, 27, 18, 39, 42, 56, 30, 39,
+    21, 15, 35, 36, 27, 7, 35, 36, 34, 7, 39, 39, 31, 36, 32, 13, 35, 58, 15, 28, 34, 17, 33, 42, 40, 28, 59,
+    25, 18, 30, 23, 17, 21, 15, 27, 33, 25, 38, 44, 16, 16, 11, 34, 25, 24, 17, 14, 25, 25, 18, 42, 9, 26, 38,
+    27, 12, 37, 33, 32, 11, 22, 37, 44, 11, 59, 49, 13, 36, 38, 9, 37, 61, 12, 31, 29, 16, 50, 35, 39, 35, 59,
+    20, 12, 31, 33, 32, 12, 44, 34, 26, 17, 59, 49, 15, 29, 26, 11, 29, 61, 7, 28, 31, 15, 50, 37, 56, 35, 38,
+    26, 7, 35, 35, 32, 9, 43, 45, 36, 14, 59, 37, 33, 39, 35, 8, 36, 38, 28, 32, 27, 14, 49, 36, 56, 24, 38,
+    30, 28, 32, 24, 20, 27, 32, 28, 45, 30, 52, 44, 24, 13, 3, 24, 29, 51, 12, 16, 22, 42, 30, 49, 37, 48, 36,
+    26, 8, 47, 30, 30, 7, 45, 45, 42, 22, 30, 50, 41, 30, 38, 9, 24, 61, 29, 29, 40, 11, 31, 50, 56, 48, 59,
+    21, 11, 30, 40, 31, 8, 40, 31, 27, 9, 50, 45, 19, 31, 13, 13, 31, 61, 20, 25, 38, 24, 50, 30, 49, 27, 59,
+    25, 9, 32, 32, 32, 9, 35, 36, 44, 9, 59, 43, 24, 35, 31, 9, 35, 61, 33, 27, 30, 19, 43, 45, 23, 24, 57,
+    26, 7, 30, 22, 37, 10, 38, 38, 35, 10, 40, 50, 32, 32, 35, 10, 26, 61, 32, 27, 34, 15, 39, 39, 40, 24, 59,
+    23, 9, 28, 27, 28, 7, 33, 24, 28, 14, 35, 41, 33, 34, 31, 8, 34, 54, 32, 25, 24, 16, 30, 34, 54, 27, 35,
+    29, 25, 16, 18, 20, 26, 11, 30, 31, 26, 54, 26, 21, 24, 17, 17, 13, 60, 13, 18, 24, 16, 12, 23, 29, 43, 31,
+    28, 10, 39, 32, 31, 12, 33, 36, 19, 18, 41, 40, 16, 34, 36, 11, 30, 61, 7, 27, 29, 16, 40, 46, 56, 30, 56,
+    23, 21, 47, 25, 42, 35, 45, 45, 46, 35, 59, 50, 25, 45, 39, 38, 44, 61, 38, 35, 21, 1, 50, 50, 56, 48, 59,
+    26, 13, 37, 33, 33, 3, 34, 35, 29, 16, 40, 41, 35, 35, 32, 11, 32, 58, 24, 28, 31, 17, 37, 35, 40, 32, 58,
+    21, 15, 38, 15, 38, 12, 40, 40, 14, 17, 59, 25, 21, 24, 23, 16, 15, 29, 31, 22, 11, 12, 39, 21, 56, 26, 59,
+    24, 13, 35, 31, 37, 9, 34, 37, 11, 14, 57, 49, 35, 34, 34, 12, 37, 61, 9, 27, 32, 18, 35, 23, 39, 23, 51,
+    29, 31, 34, 28, 25, 30, 28, 25, 32, 28, 29, 29, 21, 26, 3, 37, 14, 55, 17, 13, 20, 44, 32, 32, 49, 45, 32,
+    28, 9, 28, 28, 41, 8, 45, 32, 32, 7, 59, 50, 41, 45, 39, 11, 28, 61, 27, 27, 31, 28, 50, 50, 52, 43, 59,
+    26, 9, 35, 35, 39, 10, 40, 45, 13, 11, 50, 47, 36, 32, 30, 8, 35, 61, 20, 29, 31, 32, 36, 33, 37, 27, 59,
+    18, 19, 20, 29, 42, 19, 43, 45, 20, 19, 59, 50, 20, 20, 20, 36, 18, 61, 38, 36, 27, 35, 35, 46, 3, 38, 59,
+    15, 8, 39, 37, 39, 8, 43, 42, 43, 18, 59, 50, 36, 36, 23, 8, 37, 61, 20, 30, 36, 18, 50, 41, 56, 48, 52,
+    20, 15, 43, 42, 22, 10, 42, 45, 46, 13, 59, 41, 38, 45, 39, 4, 44, 61, 36, 22, 40, 15, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    14, 19, 18, 18, 18, 35, 20, 22, 28, 26, 53, 19, 13, 20, 14, 38, 24, 53, 10, 13, 13, 28, 25, 41, 48, 37, 46,
+    19, 19, 24, 31, 28, 15, 44, 32, 26, 12, 45, 49, 5, 40, 30, 15, 43, 61, 19, 15, 30, 22, 47, 32, 56, 20, 55,
+    21, 21, 46, 13, 31, 12, 35, 43, 12, 18, 57, 10, 25, 31, 33, 19, 35, 25, 19, 28, 9, 21, 49, 49, 56, 21, 55,
+    12, 19, 34, 29, 7, 12, 31, 31, 34, 14, 26, 49, 22, 19, 25, 18, 34, 36, 24, 18, 39, 22, 17, 29, 55, 26, 59,
+    7, 32, 41, 33, 28, 18, 20, 39, 43, 36, 57, 44, 9, 10, 18, 37, 36, 49, 13, 17, 33, 42, 40, 20, 39, 41, 49,
+    18, 25, 45, 41, 41, 17, 2, 32, 44, 24, 59, 49, 25, 42, 30, 23, 44, 61, 21, 29, 13, 31, 50, 48, 54, 31, 59,
+    16, 17, 43, 42, 41, 4, 44, 19, 24, 15, 59, 49, 25, 43, 18, 17, 43, 61, 13, 21, 36, 17, 50, 32, 56, 40, 56,
+    6, 11, 41, 40, 40, 15, 43, 45, 44, 20, 59, 48, 22, 18, 35, 9, 42, 54, 21, 16, 31, 32, 49, 44, 56, 37, 52,
+    18, 26, 38, 26, 19, 27, 30, 21, 45, 28, 34, 44, 11, 19, 6, 27, 37, 51, 11, 15, 17, 42, 29, 33, 46, 48, 42,
+    12, 23, 47, 39, 40, 21, 45, 45, 42, 31, 8, 50, 41, 42, 12, 7, 12, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    12, 22, 38, 40, 27, 4, 27, 43, 23, 12, 50, 45, 26, 40, 20, 17, 42, 61, 26, 19, 38, 39, 50, 27, 49, 38, 59,
+    6, 19, 26, 27, 24, 14, 32, 27, 44, 13, 59, 25, 10, 27, 29, 20, 30, 61, 34, 16, 19, 22, 30, 35, 48, 23, 57,
+    10, 14, 15, 28, 32, 8, 43, 44, 45, 12, 56, 50, 25, 20, 26, 19, 12, 61, 37, 17, 39, 29, 49, 47, 54, 29, 59,
+    9, 18, 31, 13, 11, 18, 33, 16, 34, 17, 52, 22, 31, 38, 18, 25, 43, 54, 37, 13, 12, 27, 30, 39, 37, 25, 33,
+    9, 36, 39, 13, 31, 34, 34, 37, 44, 36, 54, 13, 13, 31, 24, 25, 34, 60, 13, 7, 31, 31, 37, 36, 47, 43, 50,
+    13, 16, 23, 41, 30, 11, 43, 31, 14, 18, 56, 47, 23, 41, 27, 18, 7, 61, 18, 19, 15, 28, 49, 31, 56, 31, 56,
+    5, 35, 47, 39, 42, 35, 45, 45, 46, 14, 59, 50, 36, 45, 39, 38, 44, 61, 38, 14, 36, 6, 50, 50, 56, 48, 59,
+    15, 17, 25, 20, 15, 14, 34, 22, 37, 15, 41, 21, 22, 22, 22, 21, 28, 38, 7, 20, 15, 32, 32, 35, 56, 18, 58,
+    14, 27, 38, 26, 37, 17, 43, 38, 21, 19, 59, 25, 34, 32, 34, 22, 27, 52, 37, 3, 15, 24, 49, 37, 56, 33, 59,
+    17, 22, 44, 23, 41, 8, 36, 38, 18, 6, 41, 49, 27, 30, 31, 17, 42, 61, 23, 22, 17, 21, 40, 38, 55, 33, 40,
+    16, 31, 27, 18, 15, 26, 18, 13, 45, 27, 51, 29, 14, 21, 17, 37, 24, 55, 15, 10, 10, 44, 29, 47, 29, 45, 48,
+    20, 11, 45, 40, 41, 5, 45, 27, 27, 8, 59, 50, 41, 45, 39, 13, 43, 61, 37, 36, 39, 43, 50, 50, 52, 23, 59,
+    9, 9, 21, 25, 39, 15, 17, 45, 31, 15, 50, 21, 17, 43, 12, 20, 42, 61, 24, 17, 25, 43, 48, 46, 52, 21, 59,
+    5, 14, 44, 29, 42, 17, 43, 45, 38, 8, 59, 50, 39, 40, 18, 36, 24, 61, 38, 18, 27, 35, 35, 46, 26, 18, 59,
+    4, 28, 21, 37, 29, 12, 30, 42, 43, 18, 59, 50, 22, 22, 23, 18, 29, 61, 25, 11, 28, 42, 50, 26, 56, 48, 52,
+    19, 9, 43, 42, 17, 10, 42, 45, 46, 8, 59, 41, 38, 45, 39, 13, 44, 61, 36, 35, 40, 21, 50, 50, 56, 17, 17,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    18, 23, 20, 14, 22, 35, 23, 21, 29, 22, 53, 29, 13, 26, 13, 38, 31, 53, 8, 12, 15, 27, 40, 41, 48, 27, 26,
+    12, 25, 40, 18, 40, 11, 44, 44, 43, 9, 45, 49, 17, 40, 38, 13, 43, 61, 27, 18, 37, 28, 47, 18, 56, 10, 55,
+    7, 7, 46, 35, 40, 25, 44, 43, 27, 29, 57, 31, 11, 44, 38, 10, 43, 50, 29, 34, 28, 32, 49, 49, 56, 39, 55,
+    10, 11, 42, 40, 29, 24, 43, 40, 41, 10, 49, 49, 32, 40, 35, 11, 42, 58, 32, 11, 39, 11, 43, 42, 55, 38, 59,
+    16, 15, 41, 21, 16, 10, 29, 20, 23, 26, 57, 33, 14, 35, 17, 37, 36, 49, 8, 14, 18, 42, 27, 42, 39, 33, 49,
+    28, 29, 45, 41, 41, 24, 22, 44, 44, 26, 59, 49, 5, 42, 38, 4, 44, 61, 30, 33, 34, 31, 50, 48, 54, 40, 59,
+    4, 28, 43, 42, 41, 25, 44, 38, 31, 30, 59, 49, 33, 43, 33, 30, 43, 61, 4, 32, 36, 33, 50, 45, 56, 40, 56,
+    11, 24, 41, 40, 40, 23, 43, 45, 44, 26, 59, 48, 35, 39, 35, 3, 42, 54, 32, 34, 31, 11, 49, 44, 56, 37, 52,
+    25, 16, 20, 21, 16, 19, 28, 21, 45, 37, 52, 24, 8, 35, 11, 15, 37, 31, 18, 16, 13, 42, 33, 31, 46, 48, 23,
+    14, 23, 47, 14, 40, 2, 45, 45, 42, 31, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    0, 30, 38, 40, 40, 21, 40, 43, 40, 26, 50, 45, 33, 40, 31, 31, 42, 61, 34, 27, 38, 39, 50, 39, 49, 38, 59,
+    24, 14, 42, 40, 34, 4, 40, 42, 44, 12, 59, 43, 29, 41, 36, 11, 41, 61, 37, 32, 34, 19, 33, 45, 48, 15, 57,
+    12, 12, 33, 38, 41, 15, 43, 44, 45, 4, 56, 50, 38, 34, 37, 27, 28, 61, 37, 16, 39, 33, 49, 16, 54, 40, 59,
+    10, 7, 43, 32, 30, 7, 39, 25, 43, 11, 52, 41, 38, 43, 34, 32, 43, 54, 37, 27, 26, 39, 41, 45, 54, 42, 53,
+    24, 17, 29, 30, 19, 30, 30, 37, 29, 31, 54, 31, 21, 28, 16, 5, 34, 60, 11, 19, 20, 16, 31, 23, 21, 22, 50,
+    5, 8, 43, 41, 40, 24, 43, 42, 33, 30, 56, 47, 29, 41, 38, 26, 32, 61, 8, 31, 31, 33, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    25, 9, 42, 37, 35, 9, 42, 39, 41, 10, 53, 41, 37, 37, 35, 7, 40, 58, 26, 29, 33, 16, 42, 45, 56, 26, 58,
+    8, 32, 44, 12, 25, 7, 43, 44, 24, 16, 59, 43, 37, 39, 36, 15, 36, 52, 37, 23, 12, 34, 49, 42, 56, 42, 59,
+    9, 10, 44, 39, 41, 11, 43, 44, 31, 24, 57, 49, 10, 41, 36, 29, 42, 61, 10, 29, 33, 16, 48, 42, 55, 35, 51,
+    28, 31, 26, 22, 15, 30, 12, 24, 45, 19, 51, 31, 17, 21, 19, 29, 32, 31, 9, 11, 11, 44, 47, 47, 49, 22, 31,
+    31, 26, 45, 40, 41, 18, 45, 43, 44, 0, 59, 50, 41, 45, 39, 29, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    3, 24, 41, 40, 39, 7, 40, 45, 31, 26, 50, 47, 36, 43, 31, 23, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    4, 34, 21, 37, 39, 12, 43, 42, 21, 33, 59, 50, 36, 36, 35, 34, 13, 61, 34, 10, 17, 42, 50, 41, 56, 48, 52,
+    26, 25, 43, 42, 37, 20, 42, 45, 46, 1, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 34, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    20, 32, 22, 25, 20, 32, 23, 28, 35, 25, 53, 30, 11, 18, 13, 38, 19, 53, 9, 12, 11, 23, 34, 35, 48, 33, 46,
+    5, 25, 40, 39, 40, 25, 44, 44, 43, 28, 45, 49, 25, 40, 38, 25, 43, 61, 27, 5, 37, 28, 47, 47, 56, 37, 55,
+    18, 12, 46, 35, 26, 6, 44, 43, 23, 18, 57, 31, 31, 44, 38, 9, 43, 50, 21, 34, 28, 11, 49, 49, 56, 39, 55,
+    7, 31, 15, 40, 15, 24, 43, 40, 41, 25, 49, 49, 32, 40, 15, 15, 42, 58, 32, 15, 15, 11, 43, 42, 55, 15, 59,
+    7, 27, 35, 33, 17, 18, 27, 39, 33, 23, 57, 33, 18, 23, 14, 33, 20, 49, 16, 8, 28, 32, 40, 35, 39, 41, 49,
+    8, 5, 45, 41, 41, 24, 22, 44, 44, 26, 59, 49, 30, 42, 38, 27, 44, 61, 8, 33, 34, 31, 50, 48, 54, 40, 59,
+    11, 12, 43, 42, 41, 11, 44, 38, 12, 12, 59, 49, 33, 43, 33, 30, 43, 61, 26, 32, 36, 8, 50, 45, 56, 40, 56,
+    12, 9, 30, 40, 35, 8, 35, 45, 36, 12, 59, 36, 24, 26, 22, 12, 42, 54, 19, 34, 25, 23, 49, 30, 56, 31, 52,
+    24, 8, 26, 29, 19, 12, 20, 27, 45, 28, 52, 44, 20, 28, 13, 16, 16, 51, 17, 13, 15, 29, 23, 49, 46, 48, 42,
+    1, 23, 47, 39, 40, 21, 45, 45, 42, 31, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    6, 19, 21, 31, 31, 10, 28, 28, 28, 16, 33, 45, 18, 40, 18, 21, 32, 61, 24, 12, 28, 28, 50, 22, 33, 23, 59,
+    22, 6, 42, 30, 34, 10, 40, 42, 44, 11, 59, 43, 29, 41, 36, 11, 41, 61, 37, 32, 34, 13, 43, 45, 48, 26, 57,
+    6, 24, 33, 38, 41, 23, 43, 44, 45, 25, 56, 50, 38, 34, 37, 27, 28, 61, 37, 10, 39, 6, 49, 47, 54, 40, 59,
+    11, 12, 43, 32, 30, 5, 39, 25, 43, 12, 52, 41, 38, 43, 12, 32, 43, 54, 37, 27, 26, 39, 41, 45, 54, 42, 53,
+    24, 25, 36, 26, 24, 29, 23, 29, 33, 31, 54, 36, 16, 10, 8, 13, 24, 60, 11, 22, 24, 16, 24, 30, 39, 37, 50,
+    3, 26, 43, 41, 40, 24, 43, 42, 11, 30, 56, 47, 29, 41, 38, 26, 32, 61, 24, 31, 31, 11, 49, 46, 56, 41, 56,
+    13, 35, 47, 39, 42, 35, 45, 45, 46, 35, 59, 50, 36, 45, 39, 38, 44, 61, 38, 35, 36, 1, 50, 50, 56, 48, 59,
+    24, 13, 42, 37, 35, 6, 42, 39, 41, 12, 53, 41, 37, 31, 35, 7, 40, 58, 26, 29, 33, 20, 42, 45, 56, 24, 58,
+    1, 32, 44, 35, 41, 27, 43, 44, 34, 21, 59, 43, 37, 39, 36, 18, 36, 52, 37, 15, 21, 34, 49, 42, 56, 42, 59,
+    12, 23, 35, 39, 41, 12, 43, 44, 31, 5, 57, 49, 24, 34, 23, 13, 42, 61, 18, 16, 33, 16, 48, 42, 55, 35, 51,
+    28, 27, 22, 26, 19, 23, 18, 34, 45, 23, 51, 45, 11, 18, 23, 37, 20, 55, 7, 10, 13, 31, 47, 47, 49, 45, 48,
+    5, 26, 45, 40, 41, 18, 45, 43, 44, 23, 59, 50, 41, 45, 39, 29, 43, 61, 37, 5, 39, 43, 50, 50, 52, 43, 59,
+    1, 24, 41, 40, 39, 24, 40, 45, 31, 26, 50, 47, 36, 43, 31, 23, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    2, 19, 23, 11, 39, 30, 43, 42, 43, 33, 59, 50, 23, 36, 35, 34, 17, 61, 34, 22, 20, 42, 50, 41, 56, 48, 52,
+    4, 25, 43, 42, 37, 4, 42, 45, 46, 26, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 34, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    12, 27, 22, 31, 19, 27, 20, 22, 28, 23, 53, 27, 16, 15, 13, 38, 19, 32, 12, 18, 12, 22, 21, 31, 48, 14, 46,
+    18, 7, 40, 39, 40, 14, 44, 44, 43, 18, 45, 49, 17, 19, 38, 9, 43, 61, 18, 31, 37, 14, 47, 47, 56, 13, 55,
+    18, 8, 46, 35, 40, 25, 44, 43, 14, 29, 57, 31, 14, 44, 38, 11, 43, 50, 18, 34, 28, 7, 49, 49, 56, 39, 55,
+    15, 16, 34, 40, 29, 8, 43, 40, 20, 9, 49, 49, 10, 30, 31, 18, 42, 58, 20, 26, 39, 20, 43, 42, 55, 17, 59,
+    14, 20, 25, 18, 14, 15, 20, 28, 43, 36, 57, 44, 18, 21, 12, 27, 19, 39, 9, 13, 21, 31, 22, 42, 32, 41, 49,
+    11, 14, 45, 41, 41, 10, 22, 44, 44, 26, 59, 49, 30, 42, 38, 6, 44, 61, 30, 33, 34, 11, 50, 48, 54, 40, 59,
+    17, 17, 43, 42, 41, 2, 44, 38, 31, 21, 59, 49, 33, 22, 33, 30, 43, 61, 15, 32, 36, 33, 50, 22, 56, 40, 56,
+    19, 6, 41, 40, 40, 11, 43, 45, 44, 10, 59, 48, 35, 39, 35, 10, 42, 54, 32, 20, 31, 16, 49, 44, 56, 37, 52,
+    23, 15, 34, 16, 27, 17, 14, 24, 37, 34, 52, 44, 24, 26, 9, 22, 30, 34, 20, 8, 16, 30, 20, 49, 37, 38, 36,
+    14, 13, 47, 39, 40, 21, 45, 45, 42, 14, 39, 50, 41, 42, 38, 9, 37, 61, 37, 35, 40, 4, 44, 50, 56, 48, 59,
+    4, 30, 38, 40, 40, 4, 40, 43, 40, 26, 50, 45, 33, 40, 31, 31, 42, 61, 34, 27, 38, 39, 50, 39, 49, 38, 59,
+    24, 18, 42, 40, 34, 3, 40, 42, 44, 10, 59, 43, 29, 41, 36, 18, 41, 61, 37, 32, 34, 25, 43, 45, 48, 14, 57,
+    14, 8, 33, 38, 41, 13, 43, 44, 45, 4, 56, 50, 38, 34, 37, 16, 28, 61, 37, 31, 39, 33, 49, 47, 54, 40, 59,
+    24, 19, 43, 32, 30, 1, 39, 25, 43, 19, 52, 41, 38, 43, 34, 24, 43, 54, 37, 23, 26, 39, 41, 45, 54, 42, 53,
+    16, 36, 30, 15, 21, 23, 22, 23, 44, 30, 54, 36, 18, 15, 11, 10, 22, 60, 13, 18, 28, 18, 27, 15, 28, 43, 28,
+    11, 11, 43, 41, 40, 11, 43, 42, 15, 11, 56, 47, 11, 41, 38, 26, 32, 61, 11, 31, 31, 33, 49, 46, 56, 41, 56,
+    28, 35, 47, 39, 42, 35, 45, 45, 46, 35, 59, 50, 36, 45, 39, 38, 44, 61, 38, 35, 36, 0, 50, 50, 56, 48, 59,
+    21, 10, 42, 37, 35, 6, 42, 39, 41, 10, 53, 41, 37, 37, 35, 11, 40, 58, 26, 29, 33, 17, 42, 45, 56, 21, 58,
+    2, 28, 44, 22, 41, 21, 43, 44, 18, 26, 59, 43, 26, 24, 36, 20, 29, 52, 37, 22, 17, 34, 49, 30, 56, 42, 59,
+    6, 29, 44, 39, 41, 23, 43, 44, 7, 24, 57, 49, 35, 41, 36, 29, 42, 61, 13, 14, 33, 34, 14, 42, 55, 35, 51,
+    24, 16, 22, 5, 27, 26, 17, 34, 45, 24, 51, 29, 14, 22, 19, 23, 21, 55, 13, 16, 20, 44, 29, 47, 49, 45, 48,
+    19, 9, 45, 40, 16, 6, 45, 43, 44, 8, 59, 50, 41, 45, 39, 15, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    27, 7, 41, 40, 39, 14, 40, 45, 31, 9, 50, 47, 36, 43, 31, 6, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    1, 29, 44, 29, 42, 28, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 46, 26, 38, 59,
+    1, 34, 39, 37, 39, 19, 43, 42, 43, 19, 59, 50, 25, 36, 21, 34, 37, 61, 34, 19, 36, 42, 50, 41, 56, 48, 52,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    19, 32, 23, 16, 12, 32, 21, 24, 35, 32, 53, 21, 15, 20, 16, 38, 25, 53, 10, 11, 12, 25, 24, 30, 48, 37, 46,
+    19, 13, 40, 21, 40, 11, 44, 44, 43, 24, 45, 49, 12, 23, 27, 10, 27, 61, 13, 17, 23, 14, 47, 47, 56, 23, 55,
+    21, 20, 46, 32, 31, 13, 44, 43, 14, 15, 57, 18, 21, 44, 38, 13, 43, 50, 19, 29, 5, 18, 49, 49, 56, 31, 55,
+    2, 28, 31, 37, 18, 19, 35, 28, 36, 16, 49, 49, 22, 33, 21, 27, 42, 58, 27, 22, 37, 24, 43, 31, 55, 28, 59,
+    11, 27, 26, 21, 13, 26, 26, 32, 28, 27, 57, 20, 16, 18, 12, 35, 15, 38, 13, 13, 16, 33, 25, 30, 31, 38, 24,
+    17, 25, 45, 41, 41, 11, 5, 44, 44, 14, 59, 49, 20, 42, 38, 17, 44, 61, 22, 22, 23, 13, 50, 48, 54, 31, 59,
+    15, 10, 43, 42, 41, 13, 44, 18, 26, 11, 59, 49, 18, 25, 21, 17, 43, 61, 10, 22, 36, 15, 50, 45, 56, 23, 56,
+    17, 9, 41, 40, 40, 14, 43, 45, 44, 12, 59, 48, 21, 39, 35, 5, 42, 54, 21, 34, 31, 21, 49, 44, 56, 22, 52,
+    19, 23, 38, 16, 26, 25, 23, 10, 28, 37, 28, 44, 19, 27, 8, 27, 27, 51, 16, 11, 19, 42, 16, 49, 46, 48, 42,
+    30, 7, 47, 39, 40, 3, 45, 45, 42, 31, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    7, 30, 21, 40, 21, 6, 40, 43, 40, 16, 50, 45, 16, 25, 24, 31, 42, 61, 34, 12, 38, 39, 50, 39, 49, 25, 59,
+    12, 14, 29, 30, 20, 9, 21, 36, 32, 13, 59, 34, 11, 30, 36, 18, 25, 61, 32, 17, 24, 30, 27, 27, 48, 13, 39,
+    15, 12, 12, 32, 41, 8, 43, 44, 45, 12, 56, 50, 30, 30, 37, 13, 13, 61, 37, 19, 39, 26, 49, 47, 54, 26, 59,
+    11, 20, 28, 15, 15, 11, 33, 19, 28, 20, 35, 41, 29, 39, 22, 25, 34, 39, 30, 15, 7, 28, 32, 30, 54, 34, 39,
+    11, 36, 39, 14, 31, 34, 12, 17, 44, 36, 54, 36, 18, 23, 13, 25, 16, 60, 14, 20, 20, 11, 24, 36, 47, 43, 50,
+    15, 14, 43, 41, 32, 9, 43, 42, 20, 14, 56, 47, 16, 41, 31, 15, 29, 61, 10, 15, 14, 22, 49, 27, 56, 27, 56,
+    17, 35, 47, 39, 42, 35, 45, 45, 46, 35, 59, 50, 36, 45, 39, 38, 44, 61, 38, 35, 36, 0, 50, 50, 56, 48, 59,
+    8, 18, 27, 22, 29, 15, 27, 26, 31, 16, 36, 36, 24, 23, 22, 21, 28, 45, 10, 11, 20, 32, 23, 31, 56, 24, 45,
+    7, 30, 36, 26, 38, 20, 43, 44, 28, 23, 59, 38, 34, 38, 36, 27, 23, 40, 37, 4, 15, 29, 49, 38, 56, 39, 59,
+    9, 16, 28, 25, 41, 10, 35, 44, 16, 13, 57, 49, 25, 31, 26, 19, 32, 61, 18, 15, 13, 24, 48, 23, 55, 27, 32,
+    19, 31, 34, 32, 32, 22, 22, 23, 45, 32, 51, 45, 29, 16, 18, 37, 23, 55, 5, 12, 12, 44, 47, 16, 49, 45, 48,
+    20, 10, 45, 40, 41, 4, 45, 43, 44, 10, 59, 50, 41, 45, 39, 15, 43, 61, 29, 36, 39, 43, 50, 50, 52, 26, 59,
+    12, 10, 24, 28, 24, 12, 28, 45, 19, 12, 50, 47, 24, 28, 23, 11, 24, 61, 26, 11, 27, 43, 48, 46, 52, 41, 59,
+    14, 14, 44, 12, 42, 17, 43, 45, 22, 15, 59, 50, 31, 27, 37, 27, 7, 61, 38, 36, 11, 19, 35, 46, 23, 30, 59,
+    2, 34, 18, 37, 39, 13, 27, 42, 43, 33, 59, 50, 36, 23, 23, 26, 37, 61, 34, 19, 36, 42, 50, 21, 56, 48, 52,
+    10, 25, 43, 42, 37, 4, 21, 45, 46, 13, 59, 41, 38, 45, 39, 25, 44, 61, 21, 35, 40, 21, 50, 50, 56, 17, 20,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    18, 35, 19, 10, 24, 25, 28, 34, 42, 12, 53, 30, 14, 21, 17, 38, 35, 27, 10, 14, 15, 22, 19, 23, 27, 29, 46,
+    31, 25, 40, 39, 40, 3, 44, 44, 43, 7, 45, 49, 25, 40, 38, 25, 43, 61, 27, 31, 37, 28, 47, 47, 56, 37, 55,
+    5, 26, 46, 9, 40, 25, 44, 43, 27, 29, 57, 31, 31, 44, 38, 8, 43, 50, 29, 34, 28, 32, 49, 49, 56, 39, 55,
+    4, 8, 42, 40, 8, 24, 43, 40, 41, 25, 49, 49, 32, 40, 35, 31, 42, 58, 32, 31, 39, 34, 43, 42, 55, 38, 59,
+    16, 21, 29, 14, 17, 13, 33, 39, 43, 27, 57, 35, 21, 26, 14, 33, 36, 49, 6, 13, 21, 31, 34, 31, 34, 29, 49,
+    11, 18, 38, 41, 36, 7, 22, 44, 34, 10, 59, 38, 15, 26, 27, 17, 44, 61, 19, 17, 32, 20, 50, 48, 54, 25, 59,
+    6, 28, 43, 42, 41, 25, 44, 38, 7, 30, 59, 49, 33, 43, 33, 30, 43, 61, 7, 32, 36, 33, 50, 45, 56, 40, 56,
+    26, 4, 41, 40, 40, 23, 43, 45, 44, 26, 59, 48, 35, 39, 35, 8, 42, 54, 32, 34, 31, 9, 49, 44, 56, 37, 52,
+    23, 22, 26, 8, 19, 13, 26, 19, 45, 37, 35, 44, 16, 35, 9, 26, 37, 51, 14, 17, 17, 42, 30, 49, 20, 48, 34,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    22, 1, 38, 40, 40, 21, 40, 43, 40, 26, 50, 45, 33, 40, 31, 31, 42, 61, 34, 27, 38, 39, 50, 39, 49, 38, 59,
+    22, 13, 42, 40, 34, 6, 40, 42, 44, 12, 59, 43, 29, 41, 36, 9, 41, 61, 37, 32, 34, 13, 43, 45, 48, 18, 57,
+    13, 2, 33, 38, 41, 13, 43, 44, 45, 25, 56, 50, 38, 34, 37, 27, 28, 61, 37, 31, 39, 33, 49, 47, 54, 40, 59,
+    24, 13, 43, 32, 30, 2, 39, 25, 43, 31, 52, 41, 38, 43, 34, 32, 43, 54, 37, 27, 26, 13, 41, 45, 54, 42, 53,
+    26, 30, 39, 18, 25, 34, 34, 31, 44, 36, 54, 36, 16, 31, 19, 8, 34, 60, 5, 19, 26, 16, 37, 36, 27, 43, 50,
+    1, 26, 43, 41, 40, 24, 43, 42, 33, 30, 56, 47, 29, 41, 38, 26, 32, 61, 24, 31, 31, 33, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    22, 8, 42, 37, 35, 5, 42, 39, 41, 12, 53, 41, 37, 37, 35, 14, 40, 58, 26, 29, 33, 20, 42, 45, 56, 35, 58,
+    2, 32, 44, 23, 41, 17, 43, 44, 17, 19, 59, 43, 37, 39, 36, 32, 20, 52, 37, 19, 17, 20, 49, 42, 56, 42, 59,
+    6, 22, 24, 39, 41, 8, 43, 44, 23, 18, 57, 49, 18, 41, 18, 17, 24, 61, 28, 20, 33, 34, 48, 15, 55, 24, 51,
+    27, 31, 34, 22, 27, 24, 31, 25, 45, 27, 26, 45, 5, 27, 13, 37, 32, 55, 13, 9, 22, 44, 47, 47, 49, 45, 26,
+    21, 12, 37, 32, 34, 4, 45, 36, 36, 9, 59, 50, 41, 45, 39, 16, 36, 61, 32, 31, 37, 36, 50, 50, 41, 32, 59,
+    4, 24, 41, 40, 5, 24, 40, 45, 31, 26, 50, 47, 36, 43, 31, 23, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    1, 29, 44, 29, 42, 28, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 46, 26, 38, 59,
+    2, 34, 39, 37, 20, 30, 43, 42, 43, 10, 59, 50, 36, 36, 35, 34, 37, 61, 34, 30, 36, 42, 50, 41, 56, 48, 52,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    19, 21, 25, 26, 24, 29, 23, 19, 42, 20, 53, 40, 17, 19, 11, 38, 25, 53, 10, 14, 9, 25, 31, 41, 48, 27, 23,
+    14, 9, 40, 39, 40, 25, 44, 44, 43, 28, 45, 49, 13, 40, 38, 7, 14, 61, 27, 31, 37, 13, 47, 47, 56, 14, 55,
+    7, 26, 46, 7, 40, 25, 44, 43, 27, 29, 57, 31, 7, 44, 38, 24, 43, 50, 29, 34, 28, 32, 49, 49, 56, 39, 55,
+    8, 8, 42, 40, 29, 24, 43, 40, 41, 25, 49, 49, 32, 40, 35, 8, 9, 58, 32, 31, 39, 34, 43, 42, 55, 38, 59,
+    8, 26, 31, 33, 16, 15, 32, 39, 43, 32, 57, 44, 22, 22, 11, 19, 36, 49, 11, 11, 18, 42, 40, 25, 39, 41, 49,
+    28, 29, 45, 41, 41, 24, 22, 44, 44, 8, 59, 49, 30, 42, 38, 8, 44, 61, 30, 33, 34, 5, 50, 48, 54, 40, 59,
+    15, 17, 43, 42, 41, 5, 44, 38, 31, 12, 59, 49, 21, 43, 33, 30, 43, 61, 8, 21, 36, 33, 50, 45, 56, 40, 56,
+    12, 17, 18, 40, 30, 18, 43, 45, 44, 22, 59, 30, 18, 39, 20, 19, 30, 54, 32, 22, 4, 32, 49, 26, 56, 37, 30,
+    24, 20, 20, 14, 17, 17, 18, 28, 45, 37, 52, 44, 27, 28, 6, 15, 37, 51, 17, 13, 19, 29, 18, 49, 46, 48, 42,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    22, 30, 38, 40, 40, 21, 40, 43, 40, 4, 50, 45, 33, 40, 31, 5, 42, 61, 34, 27, 38, 39, 50, 39, 49, 38, 59,
+    22, 8, 42, 40, 34, 8, 40, 42, 44, 15, 59, 43, 29, 41, 36, 9, 41, 61, 37, 32, 34, 26, 43, 45, 48, 11, 57,
+    12, 12, 12, 13, 41, 6, 43, 44, 45, 25, 56, 50, 38, 34, 37, 27, 28, 61, 37, 31, 13, 33, 49, 47, 54, 40, 59,
+    14, 11, 43, 32, 30, 6, 39, 25, 43, 11, 52, 41, 38, 17, 25, 15, 43, 54, 37, 17, 26, 18, 41, 45, 54, 42, 53,
+    14, 20, 39, 34, 15, 27, 23, 37, 44, 25, 54, 36, 20, 24, 15, 6, 34, 60, 16, 14, 17, 26, 18, 28, 47, 43, 50,
+    5, 26, 43, 41, 40, 24, 43, 42, 33, 30, 56, 47, 11, 41, 38, 26, 32, 61, 24, 7, 31, 33, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    25, 7, 42, 37, 35, 5, 42, 39, 41, 16, 53, 41, 37, 37, 35, 11, 40, 58, 26, 29, 33, 25, 42, 45, 56, 28, 58,
+    1, 32, 44, 35, 41, 27, 43, 44, 34, 29, 59, 26, 37, 26, 36, 32, 36, 52, 37, 23, 14, 25, 49, 26, 56, 42, 59,
+    19, 29, 44, 39, 41, 23, 43, 44, 12, 24, 57, 21, 35, 41, 36, 2, 42, 61, 28, 20, 33, 34, 48, 42, 55, 35, 51,
+    33, 11, 34, 32, 32, 8, 19, 34, 45, 10, 51, 45, 13, 23, 19, 37, 32, 55, 13, 16, 22, 44, 47, 47, 49, 21, 48,
+    21, 12, 37, 32, 34, 4, 45, 36, 36, 9, 59, 50, 41, 45, 39, 16, 36, 61, 32, 31, 37, 36, 50, 50, 41, 32, 59,
+    27, 24, 41, 40, 39, 12, 40, 45, 31, 26, 50, 47, 36, 43, 31, 2, 42, 61, 33, 30, 38, 43, 48, 46, 52, 13, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    1, 34, 39, 37, 39, 30, 43, 42, 43, 33, 59, 50, 36, 19, 35, 34, 15, 61, 34, 30, 36, 42, 50, 41, 56, 48, 52,
+    26, 25, 43, 42, 37, 20, 42, 45, 46, 1, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 34, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    22, 22, 24, 25, 20, 25, 19, 23, 31, 19, 32, 29, 18, 16, 10, 33, 18, 53, 7, 16, 19, 24, 24, 24, 48, 28, 28,
+    16, 25, 40, 39, 40, 16, 44, 44, 43, 28, 45, 49, 25, 40, 38, 3, 43, 61, 10, 31, 37, 28, 47, 47, 56, 17, 55,
+    12, 12, 46, 35, 40, 4, 44, 43, 27, 29, 57, 31, 8, 44, 38, 24, 43, 50, 29, 34, 28, 32, 49, 49, 56, 39, 55,
+    7, 8, 42, 40, 29, 24, 43, 40, 41, 25, 49, 49, 32, 40, 35, 31, 42, 58, 12, 31, 12, 12, 43, 42, 55, 38, 59,
+    19, 12, 32, 25, 15, 10, 24, 34, 36, 23, 57, 36, 17, 20, 16, 27, 29, 37, 9, 13, 24, 42, 30, 30, 39, 29, 49,
+    28, 29, 45, 41, 41, 24, 22, 44, 44, 26, 59, 49, 30, 42, 38, 27, 44, 61, 30, 33, 34, 1, 50, 48, 54, 40, 59,
+    6, 15, 33, 37, 36, 12, 37, 25, 17, 17, 59, 41, 20, 34, 21, 17, 35, 61, 13, 20, 25, 19, 50, 34, 56, 27, 46,
+    7, 24, 41, 40, 40, 23, 43, 45, 44, 26, 59, 48, 35, 39, 35, 3, 42, 54, 32, 34, 31, 32, 49, 44, 56, 37, 52,
+    19, 25, 23, 15, 24, 17, 21, 17, 45, 37, 52, 33, 14, 25, 8, 22, 13, 51, 15, 14, 18, 42, 22, 49, 46, 48, 25,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    7, 30, 38, 40, 40, 6, 40, 43, 40, 7, 50, 45, 33, 40, 31, 31, 42, 61, 34, 27, 38, 39, 50, 39, 49, 38, 59,
+    15, 16, 42, 40, 34, 4, 40, 42, 44, 13, 59, 43, 29, 41, 36, 18, 41, 61, 37, 32, 34, 19, 43, 45, 48, 10, 57,
+    10, 10, 33, 38, 41, 9, 43, 44, 45, 8, 56, 50, 38, 34, 37, 14, 28, 61, 37, 18, 39, 33, 49, 47, 54, 40, 59,
+    16, 30, 43, 32, 30, 4, 39, 25, 43, 11, 52, 41, 38, 43, 21, 13, 43, 54, 37, 15, 26, 39, 41, 45, 54, 42, 53,
+    25, 32, 29, 28, 24, 22, 21, 37, 44, 26, 54, 36, 13, 19, 16, 7, 17, 60, 13, 18, 17, 14, 33, 19, 47, 43, 50,
+    8, 11, 12, 41, 40, 24, 43, 42, 33, 8, 56, 47, 29, 41, 38, 26, 32, 61, 11, 31, 31, 33, 49, 46, 56, 41, 56,
+    5, 35, 47, 39, 42, 35, 45, 45, 46, 35, 59, 50, 36, 45, 39, 38, 44, 61, 38, 35, 36, 4, 50, 50, 56, 48, 59,
+    22, 18, 42, 37, 35, 6, 42, 39, 41, 9, 53, 41, 37, 37, 35, 8, 40, 58, 19, 23, 33, 25, 42, 45, 56, 25, 58,
+    2, 16, 44, 20, 41, 27, 43, 44, 20, 29, 59, 43, 37, 39, 36, 20, 20, 52, 37, 17, 16, 34, 49, 42, 56, 42, 59,
+    6, 29, 28, 39, 28, 10, 22, 44, 22, 17, 57, 49, 15, 22, 16, 22, 42, 61, 24, 12, 26, 34, 48, 28, 55, 23, 51,
+    23, 23, 23, 21, 14, 25, 13, 20, 45, 32, 51, 45, 20, 12, 12, 37, 32, 55, 10, 11, 19, 44, 47, 47, 49, 45, 48,
+    31, 26, 45, 40, 41, 18, 45, 43, 44, 0, 59, 50, 41, 45, 39, 29, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    27, 10, 41, 40, 39, 10, 40, 45, 31, 14, 50, 47, 36, 43, 31, 5, 42, 61, 15, 30, 38, 43, 48, 46, 52, 15, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    5, 22, 22, 37, 16, 30, 43, 22, 43, 33, 59, 50, 36, 36, 22, 34, 14, 61, 34, 10, 12, 22, 50, 41, 56, 48, 52,
+    1, 25, 43, 42, 37, 20, 42, 45, 46, 26, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 34, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    9, 26, 21, 27, 31, 31, 31, 19, 27, 32, 53, 40, 9, 22, 9, 33, 35, 53, 20, 22, 10, 37, 40, 34, 48, 37, 35,
+    26, 19, 25, 39, 40, 13, 44, 44, 43, 9, 45, 49, 6, 28, 38, 19, 43, 61, 15, 23, 37, 12, 47, 47, 56, 37, 55,
+    10, 9, 46, 35, 40, 13, 44, 43, 17, 12, 57, 13, 22, 44, 34, 20, 43, 37, 22, 18, 15, 21, 49, 49, 56, 30, 55,
+    12, 16, 42, 40, 8, 6, 43, 18, 41, 18, 49, 49, 24, 40, 25, 21, 42, 58, 29, 19, 29, 23, 43, 27, 55, 32, 59,
+    11, 32, 35, 28, 13, 25, 20, 34, 43, 36, 57, 44, 18, 32, 13, 37, 36, 49, 12, 7, 20, 35, 21, 20, 39, 41, 49,
+    22, 27, 45, 41, 41, 17, 4, 44, 44, 9, 59, 49, 30, 42, 38, 24, 44, 61, 27, 29, 17, 26, 50, 48, 54, 17, 59,
+    16, 13, 43, 42, 41, 17, 32, 26, 5, 15, 59, 49, 25, 32, 10, 27, 43, 61, 18, 32, 36, 20, 50, 45, 56, 31, 56,
+    26, 24, 41, 40, 40, 12, 43, 45, 44, 26, 59, 48, 12, 39, 35, 2, 42, 54, 32, 34, 31, 32, 49, 44, 56, 37, 52,
+    4, 20, 38, 29, 33, 29, 34, 34, 45, 8, 52, 44, 32, 35, 18, 27, 37, 51, 31, 14, 29, 42, 33, 49, 46, 48, 42,
+    13, 12, 47, 39, 40, 21, 45, 45, 13, 5, 39, 50, 41, 42, 38, 12, 37, 61, 37, 35, 40, 13, 44, 50, 56, 48, 59,
+    18, 20, 38, 40, 40, 3, 40, 43, 40, 8, 50, 45, 33, 40, 31, 20, 42, 61, 34, 19, 38, 39, 50, 39, 49, 38, 59,
+    14, 15, 34, 40, 16, 11, 27, 42, 34, 9, 59, 28, 9, 27, 36, 23, 41, 61, 32, 17, 21, 28, 34, 31, 48, 17, 57,
+    15, 12, 22, 38, 41, 10, 43, 32, 32, 11, 56, 50, 23, 16, 27, 17, 8, 61, 37, 21, 39, 20, 49, 47, 54, 40, 59,
+    16, 18, 35, 21, 18, 12, 26, 5, 40, 20, 37, 26, 30, 40, 25, 26, 38, 37, 36, 18, 14, 29, 25, 35, 54, 36, 53,
+    20, 32, 36, 32, 26, 32, 31, 30, 44, 34, 54, 36, 24, 30, 1, 24, 31, 60, 21, 26, 26, 18, 34, 33, 47, 43, 50,
+    7, 11, 43, 41, 40, 13, 43, 42, 24, 20, 56, 47, 16, 21, 38, 23, 16, 61, 17, 12, 14, 33, 49, 46, 56, 28, 56,
+    28, 35, 47, 39, 42, 35, 45, 45, 46, 15, 59, 50, 36, 45, 39, 38, 44, 61, 38, 35, 36, 1, 50, 50, 56, 48, 59,
+    17, 17, 42, 20, 22, 8, 30, 29, 41, 18, 53, 30, 19, 16, 27, 22, 28, 58, 7, 19, 17, 27, 33, 34, 56, 24, 58,
+    14, 19, 30, 18, 32, 16, 29, 44, 15, 18, 59, 29, 27, 19, 34, 20, 23, 52, 33, 6, 10, 30, 39, 37, 56, 37, 59,
+    13, 14, 44, 25, 41, 11, 33, 44, 19, 10, 57, 49, 23, 29, 23, 18, 42, 61, 23, 18, 17, 18, 48, 36, 55, 10, 29,
+    33, 31, 34, 32, 32, 30, 31, 34, 45, 32, 51, 45, 29, 2, 26, 37, 32, 55, 24, 9, 29, 44, 47, 47, 49, 45, 48,
+    22, 17, 45, 40, 41, 2, 45, 43, 44, 11, 59, 50, 41, 45, 39, 22, 43, 61, 37, 36, 39, 43, 50, 50, 35, 43, 59,
+    27, 4, 41, 40, 39, 4, 40, 45, 31, 26, 50, 47, 36, 43, 31, 23, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    5, 20, 44, 29, 42, 8, 43, 45, 38, 16, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 10, 35, 35, 46, 26, 38, 59,
+    0, 34, 39, 37, 39, 30, 43, 42, 43, 33, 59, 50, 36, 36, 35, 34, 37, 61, 34, 30, 36, 42, 50, 41, 56, 48, 52,
+    16, 8, 43, 42, 37, 4, 42, 45, 46, 16, 59, 41, 38, 45, 39, 14, 44, 61, 25, 35, 40, 34, 50, 50, 56, 35, 19,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    22, 20, 35, 10, 23, 35, 16, 23, 24, 20, 53, 24, 28, 12, 11, 38, 20, 53, 12, 19, 26, 24, 20, 24, 48, 24, 20,
+    20, 11, 27, 30, 33, 11, 38, 38, 34, 14, 31, 43, 10, 29, 32, 11, 35, 61, 13, 20, 28, 14, 38, 38, 56, 24, 44,
+    5, 26, 46, 35, 40, 25, 44, 43, 27, 29, 57, 31, 31, 44, 38, 24, 43, 50, 29, 34, 5, 32, 49, 49, 56, 39, 55,
+    0, 31, 42, 40, 29, 24, 43, 40, 41, 25, 49, 49, 32, 40, 35, 31, 42, 58, 32, 31, 39, 34, 43, 42, 55, 38, 59,
+    19, 21, 41, 10, 23, 10, 12, 39, 43, 36, 57, 44, 21, 35, 18, 37, 36, 49, 15, 11, 19, 26, 40, 15, 39, 41, 26,
+    15, 16, 39, 36, 36, 10, 8, 38, 37, 12, 59, 44, 17, 32, 32, 14, 41, 61, 17, 22, 22, 17, 50, 41, 45, 27, 59,
+    6, 15, 33, 37, 36, 12, 37, 25, 17, 17, 59, 41, 20, 34, 21, 17, 35, 61, 13, 20, 25, 19, 50, 34, 56, 27, 46,
+    26, 24, 41, 40, 40, 23, 43, 45, 44, 26, 59, 48, 35, 39, 35, 1, 42, 54, 32, 34, 31, 32, 49, 44, 56, 37, 52,
+    7, 31, 38, 29, 33, 14, 15, 34, 45, 37, 15, 44, 15, 11, 14, 27, 37, 51, 31, 26, 14, 42, 33, 49, 46, 48, 42,
+    5, 8, 47, 39, 40, 21, 45, 45, 42, 8, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    9, 17, 24, 32, 31, 7, 29, 34, 28, 12, 37, 33, 20, 29, 18, 19, 32, 61, 24, 13, 31, 27, 50, 25, 37, 25, 59,
+    12, 13, 30, 32, 22, 8, 28, 33, 37, 12, 59, 31, 15, 30, 26, 14, 31, 61, 34, 20, 22, 20, 30, 33, 35, 15, 49,
+    1, 24, 33, 38, 41, 23, 43, 44, 45, 25, 56, 50, 38, 34, 37, 27, 28, 61, 37, 31, 39, 33, 49, 47, 54, 40, 59,
+    24, 30, 43, 32, 30, 1, 39, 25, 43, 31, 52, 41, 38, 43, 34, 32, 43, 54, 37, 27, 26, 39, 41, 45, 54, 42, 53,
+    22, 23, 12, 34, 31, 19, 19, 37, 14, 15, 54, 20, 22, 31, 15, 18, 34, 60, 16, 12, 31, 12, 37, 36, 47, 14, 50,
+    8, 8, 43, 41, 40, 8, 43, 9, 33, 30, 56, 47, 29, 41, 38, 26, 32, 61, 24, 31, 31, 33, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    1, 27, 42, 37, 35, 23, 42, 39, 41, 27, 53, 41, 37, 37, 35, 27, 40, 58, 26, 29, 33, 35, 42, 45, 56, 35, 58,
+    0, 32, 44, 35, 41, 27, 43, 44, 34, 29, 59, 43, 37, 39, 36, 32, 36, 52, 37, 23, 28, 34, 49, 42, 56, 42, 59,
+    11, 15, 34, 28, 38, 9, 34, 38, 16, 10, 50, 43, 22, 30, 27, 15, 34, 61, 15, 17, 21, 20, 41, 29, 49, 21, 38,
+    33, 18, 18, 32, 12, 30, 31, 34, 45, 21, 51, 45, 13, 18, 11, 37, 32, 55, 11, 9, 29, 44, 22, 47, 49, 45, 48,
+    31, 26, 45, 1, 41, 18, 45, 43, 44, 23, 59, 50, 41, 45, 39, 29, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    14, 10, 29, 32, 30, 10, 29, 45, 17, 12, 37, 37, 25, 34, 19, 9, 32, 61, 22, 18, 29, 36, 40, 35, 41, 29, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    2, 23, 26, 25, 29, 18, 36, 33, 34, 22, 59, 50, 25, 23, 24, 22, 24, 61, 24, 17, 25, 33, 50, 28, 56, 48, 39,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    12, 20, 24, 31, 18, 35, 20, 18, 42, 20, 53, 20, 19, 23, 14, 38, 20, 53, 11, 15, 11, 20, 40, 41, 48, 20, 24,
+    13, 13, 40, 39, 40, 10, 44, 44, 43, 11, 45, 49, 25, 40, 38, 5, 43, 61, 27, 31, 37, 28, 47, 47, 56, 37, 55,
+    30, 4, 46, 35, 40, 25, 44, 43, 8, 29, 57, 31, 31, 44, 38, 24, 43, 50, 8, 34, 28, 32, 49, 49, 56, 39, 55,
+    21, 6, 42, 40, 29, 9, 43, 40, 41, 25, 49, 49, 32, 40, 35, 6, 42, 58, 32, 31, 39, 34, 43, 42, 55, 38, 59,
+    11, 32, 41, 30, 12, 9, 29, 29, 33, 31, 57, 44, 20, 31, 15, 29, 31, 49, 11, 14, 14, 33, 32, 27, 39, 21, 49,
+    28, 12, 45, 41, 41, 12, 22, 44, 44, 12, 59, 49, 12, 42, 38, 8, 44, 61, 30, 33, 34, 8, 50, 48, 54, 40, 59,
+    6, 28, 43, 42, 41, 25, 44, 38, 31, 30, 59, 49, 33, 43, 33, 30, 43, 61, 3, 32, 36, 33, 50, 45, 56, 40, 56,
+    26, 7, 41, 40, 40, 9, 43, 45, 44, 26, 59, 48, 35, 39, 35, 7, 42, 54, 32, 14, 31, 14, 49, 44, 56, 37, 52,
+    19, 24, 38, 22, 15, 17, 25, 34, 45, 28, 30, 44, 16, 28, 4, 27, 25, 51, 17, 15, 18, 42, 33, 49, 46, 48, 42,
+    30, 4, 47, 39, 40, 4, 45, 45, 42, 31, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    22, 8, 38, 40, 40, 2, 40, 43, 40, 26, 50, 45, 33, 40, 31, 31, 42, 61, 34, 27, 38, 39, 50, 39, 49, 38, 59,
+    26, 9, 42, 40, 34, 5, 40, 42, 44, 11, 59, 43, 29, 41, 36, 16, 41, 61, 37, 32, 34, 34, 43, 45, 48, 12, 57,
+    12, 1, 33, 38, 41, 23, 43, 44, 45, 25, 56, 50, 38, 34, 37, 27, 28, 61, 37, 31, 39, 33, 49, 47, 54, 40, 59,
+    24, 22, 43, 32, 30, 4, 39, 25, 43, 12, 52, 41, 38, 43, 34, 8, 43, 54, 37, 27, 26, 39, 41, 45, 54, 42, 53,
+    18, 36, 39, 34, 22, 34, 13, 19, 44, 22, 54, 22, 22, 22, 13, 8, 22, 60, 16, 16, 22, 14, 23, 22, 47, 43, 50,
+    29, 8, 43, 41, 40, 24, 43, 42, 33, 30, 56, 47, 8, 41, 38, 8, 32, 61, 24, 8, 31, 33, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    16, 8, 42, 37, 35, 15, 42, 39, 41, 13, 53, 41, 37, 37, 35, 7, 40, 58, 26, 29, 33, 11, 42, 45, 56, 35, 58,
+    2, 32, 44, 35, 41, 20, 43, 24, 16, 29, 59, 43, 37, 28, 36, 21, 36, 52, 27, 21, 20, 34, 49, 28, 56, 42, 59,
+    9, 10, 44, 39, 41, 23, 43, 44, 31, 24, 57, 49, 35, 41, 36, 3, 42, 61, 28, 29, 33, 34, 48, 42, 55, 35, 51,
+    15, 31, 34, 32, 32, 30, 31, 34, 45, 32, 51, 45, 29, 32, 26, 37, 4, 55, 10, 14, 29, 44, 47, 15, 49, 45, 48,
+    21, 12, 37, 32, 34, 4, 45, 36, 36, 9, 59, 50, 41, 45, 39, 16, 36, 61, 32, 31, 37, 36, 50, 50, 41, 32, 59,
+    15, 9, 41, 40, 39, 12, 40, 45, 31, 26, 50, 47, 36, 43, 31, 3, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    4, 29, 44, 29, 42, 28, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 4, 61, 38, 36, 27, 35, 35, 46, 26, 38, 59,
+    1, 34, 39, 37, 39, 30, 43, 42, 43, 33, 59, 50, 16, 36, 35, 16, 16, 61, 34, 30, 36, 42, 50, 41, 56, 48, 52,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    20, 28, 21, 17, 19, 32, 28, 23, 29, 18, 53, 27, 27, 21, 11, 32, 27, 53, 13, 10, 10, 23, 29, 21, 29, 18, 31,
+    16, 8, 40, 39, 40, 10, 44, 44, 43, 28, 45, 49, 18, 40, 38, 10, 43, 61, 14, 20, 37, 12, 47, 47, 56, 37, 55,
+    10, 17, 46, 35, 18, 25, 44, 43, 11, 17, 57, 31, 31, 44, 38, 11, 43, 50, 17, 34, 28, 7, 49, 49, 56, 39, 55,
+    5, 23, 42, 28, 29, 10, 43, 40, 24, 14, 49, 49, 20, 40, 19, 17, 42, 58, 19, 15, 39, 34, 43, 25, 55, 38, 59,
+    9, 18, 25, 19, 16, 14, 32, 23, 39, 32, 57, 44, 29, 22, 17, 32, 30, 49, 16, 7, 19, 36, 25, 29, 27, 23, 49,
+    6, 15, 45, 22, 41, 16, 14, 44, 18, 17, 59, 49, 16, 42, 38, 20, 44, 61, 18, 14, 34, 16, 50, 48, 54, 40, 59,
+    14, 7, 43, 42, 41, 10, 44, 38, 16, 10, 59, 49, 33, 43, 33, 10, 43, 61, 26, 32, 36, 33, 50, 45, 56, 40, 56,
+    26, 12, 41, 40, 40, 8, 43, 45, 44, 12, 59, 48, 35, 39, 35, 4, 42, 54, 32, 34, 31, 32, 49, 44, 56, 37, 52,
+    25, 16, 25, 14, 21, 17, 17, 16, 29, 37, 52, 23, 29, 20, 8, 21, 24, 38, 31, 13, 12, 36, 20, 49, 46, 48, 23,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    6, 30, 38, 40, 40, 10, 40, 43, 40, 9, 50, 45, 33, 40, 31, 19, 42, 61, 34, 10, 38, 39, 50, 20, 49, 38, 59,
+    8, 15, 31, 31, 34, 9, 31, 42, 44, 13, 59, 43, 29, 34, 24, 14, 29, 61, 30, 18, 31, 24, 43, 31, 48, 11, 57,
+    9, 6, 33, 38, 41, 9, 43, 44, 45, 18, 56, 50, 38, 34, 20, 15, 28, 61, 37, 16, 39, 33, 49, 20, 54, 40, 59,
+    17, 30, 43, 32, 22, 1, 39, 25, 43, 31, 52, 41, 38, 43, 34, 32, 43, 54, 37, 27, 26, 23, 41, 45, 54, 42, 53,
+    22, 20, 29, 17, 26, 28, 23, 13, 44, 36, 54, 36, 28, 26, 15, 8, 21, 60, 16, 13, 19, 18, 20, 15, 47, 21, 50,
+    13, 15, 43, 41, 40, 14, 15, 42, 10, 15, 56, 47, 13, 41, 38, 15, 32, 61, 14, 19, 19, 33, 49, 19, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    26, 27, 42, 37, 35, 12, 42, 39, 41, 12, 53, 41, 37, 37, 35, 4, 40, 58, 12, 29, 33, 35, 42, 45, 56, 12, 58,
+    2, 27, 44, 28, 41, 15, 43, 44, 28, 23, 59, 43, 37, 39, 36, 19, 36, 52, 37, 17, 23, 34, 49, 42, 56, 42, 59,
+    9, 14, 44, 39, 27, 11, 43, 44, 15, 11, 57, 49, 23, 41, 36, 14, 42, 61, 20, 18, 33, 17, 48, 42, 55, 16, 51,
+    23, 16, 20, 18, 15, 13, 18, 20, 45, 24, 51, 30, 24, 14, 15, 37, 27, 55, 15, 12, 12, 44, 30, 47, 21, 45, 48,
+    31, 12, 45, 40, 20, 3, 45, 43, 44, 11, 59, 50, 41, 45, 39, 19, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    27, 10, 41, 40, 39, 24, 40, 45, 31, 12, 50, 47, 36, 43, 31, 3, 42, 61, 17, 30, 38, 43, 48, 46, 52, 41, 59,
+    24, 29, 44, 29, 42, 28, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 46, 1, 38, 59,
+    1, 31, 33, 30, 33, 27, 43, 42, 34, 25, 59, 50, 36, 24, 25, 34, 32, 61, 27, 21, 32, 42, 50, 27, 56, 48, 31,
+    26, 25, 43, 42, 37, 0, 42, 45, 46, 26, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 34, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    20, 23, 29, 18, 19, 32, 29, 19, 30, 18, 33, 21, 16, 31, 9, 38, 27, 53, 10, 13, 12, 25, 40, 41, 31, 27, 28,
+    17, 11, 40, 39, 28, 10, 44, 44, 28, 13, 45, 49, 8, 40, 22, 14, 43, 61, 19, 21, 37, 22, 47, 47, 56, 37, 28,
+    13, 13, 46, 11, 16, 18, 20, 11, 27, 29, 57, 16, 31, 20, 16, 15, 20, 20, 29, 34, 28, 32, 49, 49, 56, 39, 55,
+    0, 31, 42, 40, 29, 24, 43, 40, 41, 25, 49, 49, 32, 40, 35, 31, 42, 58, 32, 31, 39, 34, 43, 42, 55, 38, 59,
+    13, 19, 35, 26, 13, 17, 37, 30, 36, 29, 57, 31, 19, 22, 7, 31, 33, 49, 12, 13, 17, 42, 40, 28, 32, 29, 49,
+    11, 29, 45, 41, 41, 24, 22, 44, 44, 11, 59, 49, 30, 42, 38, 5, 44, 61, 30, 33, 34, 8, 50, 48, 54, 40, 59,
+    0, 28, 43, 42, 41, 25, 44, 38, 31, 30, 59, 49, 33, 43, 33, 30, 43, 61, 26, 32, 36, 33, 50, 45, 56, 40, 56,
+    7, 24, 41, 40, 40, 23, 43, 45, 44, 26, 59, 48, 35, 39, 35, 7, 42, 54, 32, 34, 31, 32, 49, 44, 56, 37, 7,
+    25, 23, 33, 15, 16, 24, 29, 26, 45, 37, 52, 35, 14, 31, 7, 26, 33, 51, 23, 8, 14, 34, 33, 49, 26, 48, 26,
+    1, 23, 47, 39, 40, 21, 45, 45, 42, 31, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    9, 17, 24, 32, 31, 7, 29, 34, 28, 12, 37, 33, 20, 29, 18, 19, 32, 61, 24, 13, 31, 27, 50, 25, 37, 25, 59,
+    11, 26, 19, 40, 34, 3, 40, 42, 44, 26, 59, 43, 29, 41, 36, 18, 41, 61, 37, 18, 34, 34, 43, 45, 48, 14, 57,
+    21, 12, 33, 38, 41, 9, 43, 44, 45, 6, 56, 50, 38, 34, 37, 13, 28, 61, 37, 31, 39, 11, 49, 47, 54, 23, 59,
+    6, 30, 43, 32, 30, 5, 39, 25, 43, 12, 52, 41, 38, 43, 34, 32, 43, 54, 37, 15, 26, 39, 41, 45, 54, 42, 53,
+    21, 31, 24, 25, 15, 34, 25, 31, 44, 31, 54, 25, 23, 23, 9, 10, 34, 60, 11, 17, 14, 16, 18, 36, 47, 43, 30,
+    18, 11, 32, 41, 40, 15, 43, 32, 19, 17, 56, 47, 10, 41, 31, 13, 32, 61, 11, 19, 14, 18, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    7, 27, 42, 37, 35, 23, 42, 39, 41, 27, 53, 41, 37, 37, 7, 27, 40, 58, 26, 7, 33, 35, 42, 45, 56, 35, 58,
+    2, 32, 27, 35, 27, 21, 43, 21, 23, 21, 59, 43, 37, 39, 26, 25, 36, 52, 37, 19, 15, 26, 49, 42, 56, 42, 59,
+    6, 29, 44, 39, 41, 9, 43, 44, 31, 24, 57, 49, 35, 41, 36, 29, 10, 61, 28, 29, 33, 34, 10, 42, 55, 35, 51,
+    26, 31, 34, 26, 16, 25, 16, 23, 28, 32, 28, 24, 12, 20, 11, 37, 32, 55, 13, 7, 20, 44, 47, 47, 49, 45, 24,
+    1, 26, 45, 40, 41, 18, 45, 43, 44, 23, 59, 50, 41, 45, 39, 29, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    6, 9, 41, 40, 39, 24, 40, 45, 31, 26, 50, 47, 36, 43, 31, 6, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    1, 29, 44, 29, 42, 28, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 46, 26, 38, 59,
+    4, 20, 39, 14, 39, 16, 43, 42, 43, 33, 59, 50, 36, 36, 35, 34, 37, 61, 16, 9, 20, 42, 50, 41, 56, 48, 52,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    13, 25, 21, 21, 24, 31, 28, 21, 31, 21, 53, 29, 9, 18, 17, 38, 25, 53, 12, 16, 10, 27, 22, 29, 36, 33, 46,
+    16, 10, 40, 21, 40, 7, 44, 44, 43, 19, 45, 49, 15, 40, 38, 14, 43, 61, 14, 31, 37, 12, 47, 47, 56, 37, 55,
+    24, 23, 46, 35, 40, 4, 44, 43, 16, 15, 57, 31, 17, 44, 38, 13, 43, 50, 19, 34, 20, 21, 49, 49, 56, 18, 55,
+    9, 15, 30, 30, 24, 9, 35, 40, 32, 12, 33, 49, 18, 27, 23, 17, 32, 58, 17, 14, 34, 22, 43, 29, 55, 23, 59,
+    13, 25, 38, 22, 17, 15, 26, 30, 43, 25, 57, 44, 25, 29, 22, 28, 33, 49, 15, 4, 21, 32, 30, 22, 28, 24, 41,
+    20, 16, 45, 41, 41, 7, 18, 44, 44, 10, 59, 49, 14, 42, 38, 11, 44, 61, 16, 33, 34, 14, 50, 48, 54, 40, 59,
+    2, 23, 32, 38, 35, 16, 34, 38, 27, 23, 59, 37, 21, 43, 29, 27, 43, 61, 21, 17, 21, 28, 50, 35, 56, 40, 56,
+    18, 7, 41, 40, 40, 14, 43, 45, 44, 18, 59, 48, 19, 39, 35, 6, 42, 54, 32, 19, 31, 15, 49, 44, 56, 37, 52,
+    19, 15, 32, 13, 25, 21, 19, 19, 45, 37, 35, 28, 24, 21, 8, 19, 28, 27, 26, 11, 13, 26, 22, 49, 31, 48, 22,
+    14, 13, 47, 39, 40, 12, 45, 45, 42, 31, 39, 50, 41, 42, 38, 7, 37, 61, 37, 35, 40, 6, 44, 50, 56, 48, 59,
+    7, 20, 38, 40, 40, 9, 21, 43, 40, 11, 25, 45, 20, 40, 18, 31, 25, 61, 24, 11, 24, 39, 50, 39, 49, 25, 59,
+    21, 12, 42, 40, 34, 6, 40, 42, 44, 9, 59, 43, 29, 41, 36, 11, 41, 61, 37, 32, 34, 34, 43, 45, 48, 13, 57,
+    16, 10, 33, 38, 41, 2, 43, 44, 45, 25, 56, 50, 38, 34, 37, 27, 28, 61, 37, 31, 39, 33, 49, 47, 54, 40, 59,
+    13, 15, 43, 32, 30, 4, 39, 25, 43, 13, 52, 26, 38, 43, 34, 13, 43, 54, 37, 23, 26, 25, 41, 45, 54, 16, 53,
+    16, 24, 22, 25, 20, 34, 18, 37, 44, 21, 54, 27, 18, 17, 12, 12, 29, 60, 10, 17, 14, 18, 19, 17, 47, 32, 50,
+    17, 26, 43, 41, 40, 12, 43, 42, 33, 30, 56, 47, 9, 41, 38, 16, 32, 61, 5, 31, 31, 13, 49, 46, 56, 41, 56,
+    28, 35, 47, 39, 42, 35, 45, 45, 46, 35, 59, 50, 36, 45, 39, 38, 44, 61, 38, 35, 36, 0, 50, 50, 56, 48, 59,
+    16, 27, 42, 37, 35, 7, 42, 39, 41, 13, 53, 41, 37, 37, 35, 7, 40, 58, 16, 29, 33, 17, 42, 45, 56, 13, 58,
+    4, 25, 32, 21, 41, 15, 26, 26, 22, 14, 59, 43, 27, 25, 36, 21, 24, 52, 37, 19, 15, 18, 38, 26, 56, 33, 59,
+    8, 15, 38, 39, 41, 9, 43, 38, 21, 11, 57, 49, 19, 34, 29, 22, 35, 61, 16, 13, 33, 24, 48, 37, 55, 28, 40,
+    19, 14, 34, 24, 19, 13, 12, 24, 45, 17, 51, 25, 23, 12, 22, 19, 21, 55, 16, 14, 13, 44, 47, 47, 26, 45, 48,
+    23, 11, 45, 40, 41, 4, 45, 43, 44, 8, 59, 50, 41, 45, 39, 14, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    19, 9, 41, 40, 39, 15, 40, 45, 16, 10, 50, 47, 36, 43, 31, 7, 42, 61, 12, 30, 38, 43, 48, 46, 52, 41, 59,
+    24, 29, 44, 29, 42, 28, 43, 45, 38, 3, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 46, 7, 38, 59,
+    2, 22, 23, 23, 39, 30, 43, 42, 43, 22, 59, 50, 19, 19, 35, 17, 37, 61, 34, 22, 19, 42, 50, 19, 56, 48, 52,
+    8, 8, 43, 42, 37, 6, 42, 45, 46, 26, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 34, 50, 50, 56, 12, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    19, 25, 35, 16, 7, 35, 25, 34, 42, 32, 53, 17, 17, 25, 19, 38, 26, 53, 10, 14, 11, 37, 40, 41, 48, 37, 46,
+    13, 14, 16, 39, 40, 13, 44, 44, 43, 14, 17, 49, 13, 40, 38, 13, 43, 61, 27, 10, 19, 21, 20, 47, 56, 26, 55,
+    19, 12, 46, 16, 40, 11, 44, 43, 17, 12, 57, 9, 23, 44, 38, 16, 43, 50, 22, 26, 21, 16, 49, 49, 56, 24, 55,
+    6, 21, 23, 22, 13, 14, 31, 24, 24, 15, 49, 49, 17, 31, 26, 24, 25, 58, 26, 14, 33, 17, 34, 21, 55, 20, 59,
+    12, 14, 41, 23, 13, 21, 14, 39, 43, 36, 57, 23, 15, 15, 18, 37, 36, 49, 18, 9, 15, 42, 40, 42, 39, 23, 49,
+    15, 27, 32, 41, 41, 12, 3, 44, 44, 17, 59, 49, 27, 42, 31, 26, 44, 61, 24, 25, 17, 31, 50, 48, 54, 31, 59,
+    13, 11, 29, 42, 41, 14, 44, 20, 26, 13, 59, 49, 24, 43, 19, 20, 43, 61, 9, 20, 36, 24, 50, 25, 56, 13, 56,
+    9, 13, 41, 40, 40, 13, 43, 45, 44, 13, 59, 48, 35, 14, 7, 16, 42, 54, 32, 34, 31, 32, 49, 44, 56, 37, 52,
+    24, 31, 38, 15, 16, 29, 24, 34, 45, 37, 52, 44, 15, 24, 6, 27, 25, 51, 15, 9, 20, 42, 33, 49, 46, 48, 42,
+    30, 23, 47, 39, 40, 0, 45, 45, 42, 31, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    7, 25, 22, 25, 40, 7, 31, 43, 27, 15, 50, 25, 21, 21, 31, 23, 42, 61, 34, 11, 38, 24, 50, 25, 49, 27, 59,
+    14, 18, 24, 31, 14, 11, 25, 29, 30, 13, 59, 24, 12, 23, 30, 13, 34, 61, 33, 19, 24, 15, 20, 28, 48, 21, 57,
+    11, 14, 20, 38, 41, 10, 27, 44, 45, 13, 56, 50, 29, 12, 33, 19, 9, 61, 37, 16, 33, 33, 49, 47, 54, 25, 59,
+    5, 15, 36, 21, 19, 15, 22, 19, 35, 19, 42, 38, 27, 36, 24, 25, 33, 54, 33, 10, 17, 37, 27, 33, 54, 29, 43,
+    21, 36, 33, 27, 10, 29, 21, 27, 35, 31, 54, 12, 14, 15, 13, 21, 17, 42, 16, 18, 13, 31, 28, 36, 37, 43, 30,
+    12, 26, 32, 41, 40, 7, 28, 42, 18, 16, 56, 27, 20, 23, 38, 18, 14, 61, 16, 14, 16, 24, 49, 32, 56, 22, 33,
+    1, 35, 47, 39, 42, 35, 45, 45, 46, 35, 59, 50, 36, 45, 39, 38, 44, 61, 38, 35, 36, 15, 50, 50, 56, 48, 59,
+    12, 19, 29, 24, 15, 14, 29, 21, 29, 15, 41, 19, 24, 18, 23, 24, 27, 58, 10, 14, 14, 28, 38, 27, 56, 21, 58,
+    12, 23, 44, 29, 41, 12, 43, 35, 25, 17, 59, 43, 33, 31, 32, 28, 22, 52, 37, 4, 12, 23, 49, 42, 56, 35, 59,
+    10, 18, 26, 30, 41, 10, 30, 44, 11, 12, 57, 31, 20, 28, 29, 16, 25, 61, 26, 15, 15, 30, 48, 28, 55, 24, 51,
+    26, 31, 22, 28, 21, 30, 26, 15, 45, 25, 51, 45, 19, 32, 9, 37, 23, 36, 11, 7, 13, 44, 35, 47, 49, 45, 48,
+    24, 14, 45, 40, 41, 2, 45, 43, 44, 12, 59, 50, 41, 45, 39, 29, 43, 61, 37, 36, 39, 43, 50, 50, 52, 30, 59,
+    8, 19, 25, 31, 27, 11, 25, 45, 22, 15, 28, 47, 18, 43, 10, 23, 27, 61, 24, 12, 25, 43, 48, 46, 52, 41, 59,
+    5, 29, 44, 29, 42, 15, 20, 45, 38, 9, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 20, 18, 12, 59,
+    7, 18, 39, 22, 18, 10, 43, 42, 22, 18, 59, 50, 22, 13, 18, 16, 37, 61, 34, 16, 36, 22, 50, 41, 56, 48, 52,
+    13, 13, 43, 42, 37, 4, 42, 45, 46, 13, 59, 41, 38, 45, 39, 13, 44, 61, 36, 35, 40, 34, 50, 50, 56, 17, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    24, 26, 27, 16, 19, 35, 35, 23, 34, 18, 53, 30, 19, 25, 15, 38, 21, 32, 8, 9, 13, 32, 33, 29, 35, 20, 46,
+    14, 13, 14, 39, 40, 7, 44, 44, 43, 28, 45, 49, 25, 40, 38, 6, 43, 61, 27, 31, 37, 28, 47, 47, 56, 37, 55,
+    5, 26, 46, 35, 40, 25, 44, 43, 27, 12, 57, 31, 31, 44, 38, 11, 43, 50, 29, 12, 11, 32, 49, 49, 56, 39, 55,
+    13, 4, 42, 40, 29, 24, 14, 40, 41, 25, 49, 49, 32, 40, 35, 10, 42, 58, 32, 31, 14, 34, 43, 42, 55, 38, 59,
+    16, 17, 41, 14, 16, 12, 31, 29, 43, 33, 57, 44, 20, 35, 13, 28, 33, 49, 6, 17, 18, 35, 40, 42, 34, 41, 36,
+    9, 13, 45, 41, 41, 9, 12, 44, 44, 26, 59, 49, 13, 42, 38, 27, 44, 61, 30, 33, 34, 9, 50, 48, 54, 40, 59,
+    5, 28, 43, 42, 41, 25, 44, 38, 31, 30, 59, 49, 33, 43, 33, 13, 13, 61, 7, 32, 36, 33, 50, 45, 56, 40, 56,
+    15, 11, 41, 40, 28, 11, 43, 45, 44, 10, 59, 48, 23, 39, 35, 9, 24, 54, 23, 21, 31, 32, 49, 44, 56, 13, 52,
+    22, 20, 38, 12, 21, 14, 34, 28, 45, 37, 31, 30, 17, 35, 7, 18, 24, 51, 14, 17, 16, 30, 33, 49, 24, 48, 24,
+    1, 23, 47, 39, 40, 21, 45, 45, 42, 31, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    9, 30, 38, 40, 40, 3, 40, 43, 40, 10, 50, 45, 33, 40, 31, 31, 42, 61, 34, 27, 38, 39, 50, 39, 49, 38, 59,
+    23, 7, 42, 31, 34, 7, 40, 42, 44, 11, 59, 43, 29, 41, 36, 13, 41, 61, 37, 32, 34, 20, 43, 45, 48, 18, 57,
+    9, 11, 33, 16, 41, 4, 43, 44, 45, 15, 56, 50, 38, 34, 37, 27, 28, 61, 37, 31, 39, 33, 49, 47, 54, 40, 59,
+    7, 30, 43, 32, 30, 4, 39, 25, 43, 13, 52, 41, 38, 43, 34, 32, 43, 54, 37, 27, 26, 39, 41, 45, 54, 42, 53,
+    27, 36, 39, 26, 25, 25, 31, 29, 44, 18, 54, 24, 16, 31, 12, 11, 24, 60, 10, 9, 17, 20, 32, 21, 47, 43, 50,
+    23, 19, 43, 28, 40, 9, 43, 42, 27, 14, 56, 47, 11, 29, 38, 11, 32, 61, 7, 26, 31, 33, 49, 46, 56, 23, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    25, 20, 42, 37, 35, 5, 42, 39, 41, 12, 53, 41, 37, 37, 35, 6, 40, 58, 26, 29, 33, 26, 42, 45, 56, 35, 58,
+    2, 23, 44, 35, 41, 18, 43, 44, 19, 18, 59, 21, 37, 39, 36, 19, 27, 52, 37, 18, 22, 34, 49, 42, 56, 21, 59,
+    11, 19, 44, 28, 41, 11, 43, 44, 26, 6, 57, 49, 24, 41, 21, 16, 42, 61, 25, 16, 33, 18, 48, 42, 55, 24, 51,
+    26, 25, 11, 32, 17, 25, 15, 34, 45, 19, 51, 45, 14, 23, 19, 37, 19, 55, 9, 14, 10, 44, 47, 47, 49, 45, 24,
+    31, 26, 45, 1, 41, 18, 45, 43, 44, 23, 59, 50, 41, 45, 39, 29, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    27, 4, 41, 40, 39, 24, 40, 45, 31, 26, 50, 47, 36, 43, 31, 4, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    4, 34, 39, 37, 39, 30, 43, 19, 43, 18, 59, 50, 36, 36, 35, 34, 37, 61, 7, 30, 19, 42, 50, 19, 56, 48, 52,
+    26, 25, 43, 42, 37, 20, 42, 45, 46, 1, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 34, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    29, 35, 35, 31, 5, 35, 35, 34, 42, 32, 53, 40, 28, 34, 26, 38, 35, 53, 25, 26, 4, 37, 40, 41, 48, 37, 46,
+    20, 11, 27, 30, 33, 11, 38, 38, 34, 14, 31, 43, 10, 29, 32, 11, 35, 61, 13, 20, 28, 14, 38, 38, 56, 24, 44,
+    1, 26, 46, 35, 40, 25, 44, 43, 27, 29, 57, 31, 31, 44, 38, 24, 43, 50, 29, 34, 28, 32, 49, 49, 56, 39, 55,
+    7, 18, 31, 31, 15, 10, 34, 28, 29, 11, 36, 43, 19, 29, 24, 18, 34, 49, 20, 19, 34, 21, 30, 29, 48, 24, 59,
+    12, 19, 29, 20, 14, 14, 24, 27, 34, 28, 49, 31, 18, 22, 14, 30, 23, 35, 11, 10, 20, 32, 26, 28, 25, 29, 36,
+    15, 16, 39, 36, 36, 10, 8, 38, 37, 12, 59, 44, 17, 32, 32, 14, 41, 61, 17, 22, 22, 17, 50, 41, 45, 27, 59,
+    6, 15, 33, 37, 36, 12, 37, 25, 17, 17, 59, 41, 20, 34, 21, 17, 35, 61, 13, 20, 25, 19, 50, 34, 56, 27, 46,
+    13, 10, 29, 33, 33, 9, 34, 45, 38, 13, 59, 39, 23, 28, 24, 10, 34, 42, 20, 25, 18, 18, 43, 31, 56, 24, 39,
+    1, 31, 38, 29, 33, 29, 34, 34, 45, 37, 52, 44, 32, 35, 22, 27, 37, 51, 31, 26, 29, 42, 33, 49, 46, 48, 42,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    9, 17, 24, 32, 31, 7, 29, 34, 28, 12, 37, 33, 20, 29, 18, 19, 32, 61, 24, 13, 31, 27, 50, 25, 37, 25, 59,
+    3, 26, 42, 40, 7, 22, 40, 42, 44, 26, 59, 43, 29, 41, 36, 27, 41, 61, 37, 32, 34, 34, 43, 45, 48, 29, 57,
+    13, 10, 19, 27, 38, 9, 34, 40, 38, 11, 46, 50, 28, 20, 30, 13, 14, 61, 35, 19, 34, 19, 44, 37, 46, 27, 59,
+    10, 17, 32, 18, 16, 11, 27, 11, 33, 18, 39, 28, 28, 35, 23, 20, 35, 42, 33, 14, 12, 27, 28, 33, 45, 30, 40,
+    20, 26, 27, 21, 17, 28, 21, 24, 35, 27, 42, 22, 17, 17, 10, 11, 20, 52, 12, 17, 18, 18, 23, 22, 34, 31, 36,
+    16, 12, 33, 34, 32, 11, 33, 33, 19, 17, 46, 37, 16, 31, 32, 12, 18, 61, 10, 19, 18, 20, 44, 36, 56, 28, 46,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    13, 13, 30, 25, 22, 9, 31, 26, 29, 13, 41, 28, 26, 24, 24, 13, 30, 47, 12, 17, 20, 22, 29, 33, 51, 21, 52,
+    0, 32, 44, 35, 41, 27, 43, 44, 34, 29, 59, 43, 37, 39, 36, 32, 36, 52, 37, 23, 28, 34, 49, 42, 56, 42, 59,
+    4, 29, 44, 39, 41, 23, 43, 44, 31, 24, 57, 49, 35, 41, 36, 29, 42, 61, 28, 29, 33, 34, 48, 42, 55, 5, 51,
+    33, 8, 34, 32, 32, 6, 31, 34, 45, 8, 51, 45, 29, 32, 26, 21, 32, 55, 24, 25, 29, 44, 47, 47, 49, 45, 48,
+    21, 12, 37, 32, 34, 4, 45, 36, 36, 9, 59, 50, 41, 45, 39, 16, 36, 61, 32, 31, 37, 36, 50, 50, 41, 32, 59,
+    14, 10, 29, 32, 30, 10, 29, 45, 17, 12, 37, 37, 25, 34, 19, 9, 32, 61, 22, 18, 29, 36, 40, 35, 41, 29, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    2, 23, 26, 25, 29, 18, 36, 33, 34, 22, 59, 50, 25, 23, 24, 22, 24, 61, 24, 17, 25, 33, 50, 28, 56, 48, 39,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    17, 25, 23, 15, 17, 30, 20, 19, 28, 17, 40, 29, 17, 20, 10, 32, 18, 37, 20, 14, 11, 28, 26, 25, 48, 23, 31,
+    16, 9, 40, 39, 40, 11, 44, 44, 43, 11, 45, 49, 20, 40, 38, 9, 43, 61, 14, 23, 37, 22, 47, 47, 56, 17, 55,
+    17, 16, 46, 35, 40, 8, 44, 43, 9, 14, 57, 31, 18, 44, 38, 15, 43, 50, 20, 34, 22, 13, 49, 49, 56, 22, 55,
+    6, 17, 42, 27, 29, 11, 43, 40, 31, 11, 31, 31, 21, 31, 19, 25, 31, 58, 20, 13, 39, 34, 43, 25, 55, 30, 59,
+    14, 15, 30, 17, 14, 11, 22, 23, 33, 28, 41, 36, 17, 22, 16, 32, 19, 30, 22, 9, 21, 33, 24, 29, 35, 30, 43,
+    14, 11, 45, 22, 41, 12, 13, 22, 44, 15, 59, 49, 18, 42, 38, 10, 44, 61, 18, 33, 34, 11, 50, 48, 54, 40, 59,
+    10, 10, 43, 42, 41, 6, 44, 38, 22, 15, 59, 49, 23, 43, 33, 19, 43, 61, 17, 25, 36, 18, 50, 45, 56, 21, 56,
+    17, 10, 41, 40, 40, 9, 43, 45, 44, 14, 59, 48, 35, 39, 35, 6, 42, 54, 32, 34, 31, 20, 49, 44, 56, 15, 52,
+    22, 15, 19, 15, 18, 11, 20, 19, 45, 37, 37, 30, 22, 22, 10, 18, 22, 51, 31, 13, 14, 32, 22, 49, 36, 48, 25,
+    12, 12, 47, 39, 40, 2, 45, 45, 42, 31, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    7, 21, 22, 40, 40, 8, 22, 43, 26, 15, 50, 45, 33, 40, 18, 24, 26, 61, 22, 11, 38, 39, 50, 22, 27, 38, 59,
+    13, 17, 42, 40, 17, 8, 27, 42, 27, 11, 59, 43, 29, 41, 36, 12, 23, 61, 37, 18, 34, 34, 43, 45, 48, 11, 57,
+    10, 7, 33, 38, 41, 11, 29, 44, 45, 9, 56, 50, 22, 34, 29, 18, 28, 61, 37, 15, 39, 24, 49, 26, 54, 29, 59,
+    10, 10, 25, 27, 30, 7, 39, 25, 43, 12, 52, 41, 38, 23, 24, 16, 43, 54, 37, 17, 26, 39, 41, 29, 54, 29, 53,
+    20, 22, 22, 18, 19, 28, 18, 18, 35, 29, 30, 27, 18, 19, 16, 9, 19, 60, 20, 13, 19, 16, 21, 15, 31, 28, 34,
+    14, 12, 43, 41, 40, 18, 43, 24, 20, 30, 56, 47, 16, 24, 24, 8, 32, 61, 9, 17, 20, 23, 49, 46, 56, 41, 56,
+    28, 35, 47, 39, 42, 35, 45, 45, 46, 35, 59, 50, 36, 45, 39, 38, 44, 61, 38, 35, 36, 0, 50, 50, 56, 48, 59,
+    20, 10, 42, 37, 35, 8, 42, 39, 20, 9, 53, 41, 37, 34, 31, 11, 37, 58, 26, 27, 33, 20, 42, 45, 56, 16, 58,
+    2, 26, 32, 27, 35, 15, 36, 44, 20, 20, 59, 36, 33, 31, 36, 20, 28, 52, 37, 20, 18, 26, 49, 35, 56, 35, 59,
+    10, 16, 44, 32, 41, 12, 27, 26, 13, 10, 57, 49, 19, 22, 21, 19, 42, 61, 20, 14, 33, 19, 48, 26, 55, 20, 34,
+    22, 27, 23, 13, 19, 25, 15, 17, 45, 19, 51, 45, 18, 18, 15, 37, 12, 55, 23, 8, 18, 44, 47, 47, 49, 45, 31,
+    23, 9, 45, 40, 41, 5, 45, 43, 44, 7, 59, 50, 41, 45, 39, 18, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    16, 7, 41, 40, 39, 11, 40, 45, 20, 14, 50, 47, 36, 43, 31, 7, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    1, 29, 44, 29, 42, 28, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 46, 26, 38, 59,
+    1, 23, 23, 37, 31, 25, 32, 42, 43, 19, 59, 50, 25, 23, 27, 25, 30, 61, 34, 25, 27, 42, 50, 32, 56, 48, 52,
+    26, 25, 43, 42, 37, 0, 42, 45, 46, 26, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 34, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    13, 19, 22, 19, 17, 27, 21, 17, 34, 17, 53, 27, 14, 19, 12, 33, 26, 53, 14, 15, 17, 20, 22, 27, 48, 18, 46,
+    17, 10, 40, 39, 40, 11, 44, 44, 43, 13, 22, 49, 25, 40, 21, 10, 43, 61, 15, 31, 37, 11, 47, 47, 56, 18, 55,
+    22, 13, 46, 35, 40, 17, 44, 43, 7, 15, 57, 31, 21, 44, 38, 12, 33, 50, 9, 28, 28, 15, 49, 49, 56, 39, 55,
+    9, 5, 42, 40, 29, 11, 43, 40, 41, 15, 49, 49, 32, 40, 35, 12, 42, 58, 32, 31, 39, 34, 43, 42, 55, 38, 59,
+    11, 20, 29, 21, 13, 14, 31, 31, 32, 30, 57, 34, 15, 19, 13, 31, 27, 30, 13, 11, 19, 27, 28, 31, 24, 26, 35,
+    19, 12, 45, 41, 41, 8, 22, 44, 44, 11, 59, 49, 19, 42, 38, 15, 44, 61, 30, 33, 34, 8, 50, 48, 54, 20, 59,
+    12, 16, 43, 42, 41, 25, 44, 38, 31, 13, 59, 49, 33, 43, 33, 13, 43, 61, 4, 13, 36, 33, 50, 45, 56, 40, 56,
+    11, 12, 33, 40, 40, 9, 34, 45, 34, 11, 59, 48, 25, 28, 19, 9, 42, 54, 20, 34, 29, 19, 35, 30, 56, 32, 52,
+    23, 19, 18, 18, 18, 19, 22, 17, 38, 35, 52, 44, 22, 19, 10, 9, 24, 36, 26, 16, 16, 33, 13, 49, 30, 48, 27,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    13, 14, 38, 40, 40, 9, 40, 43, 40, 7, 50, 45, 24, 40, 31, 15, 42, 61, 24, 16, 21, 21, 50, 39, 49, 18, 59,
+    18, 12, 42, 40, 34, 7, 40, 42, 44, 13, 59, 43, 29, 41, 36, 14, 41, 61, 37, 32, 34, 18, 43, 45, 48, 7, 57,
+    8, 8, 33, 38, 41, 12, 43, 44, 45, 10, 56, 50, 38, 34, 37, 12, 28, 61, 37, 20, 28, 24, 49, 47, 54, 40, 59,
+    17, 19, 43, 32, 30, 3, 39, 25, 43, 15, 52, 41, 38, 43, 34, 13, 43, 54, 37, 27, 26, 20, 41, 45, 54, 42, 53,
+    17, 28, 39, 15, 24, 34, 22, 37, 35, 27, 54, 32, 14, 18, 8, 16, 24, 60, 9, 27, 26, 15, 27, 32, 35, 43, 50,
+    22, 11, 29, 41, 40, 7, 43, 42, 25, 14, 56, 47, 21, 41, 28, 7, 32, 61, 15, 26, 31, 23, 49, 46, 56, 26, 56,
+    13, 35, 47, 39, 42, 35, 45, 45, 46, 35, 59, 50, 13, 45, 39, 38, 44, 61, 38, 35, 36, 2, 50, 50, 56, 48, 59,
+    9, 10, 42, 15, 35, 12, 42, 39, 41, 13, 53, 41, 37, 37, 35, 8, 40, 58, 26, 19, 33, 35, 42, 45, 56, 35, 58,
+    6, 16, 31, 30, 41, 10, 33, 44, 29, 10, 59, 35, 23, 26, 25, 16, 31, 52, 30, 22, 27, 21, 49, 29, 56, 26, 59,
+    11, 11, 35, 31, 37, 10, 33, 44, 28, 12, 57, 49, 25, 30, 28, 14, 33, 61, 11, 18, 33, 20, 48, 31, 55, 26, 51,
+    27, 19, 13, 17, 25, 19, 14, 23, 45, 21, 51, 45, 18, 17, 18, 32, 13, 55, 8, 15, 25, 44, 47, 47, 49, 45, 35,
+    8, 8, 45, 40, 41, 8, 45, 43, 44, 8, 59, 50, 41, 45, 39, 29, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    21, 12, 41, 40, 26, 5, 40, 45, 31, 10, 50, 47, 36, 43, 31, 9, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    4, 34, 39, 17, 25, 30, 43, 42, 43, 33, 59, 50, 21, 13, 13, 34, 25, 61, 16, 16, 36, 42, 50, 41, 56, 48, 52,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    15, 22, 15, 20, 27, 35, 20, 20, 27, 15, 53, 22, 12, 24, 12, 38, 27, 53, 13, 20, 10, 26, 40, 35, 28, 27, 46,
+    17, 5, 40, 39, 40, 25, 44, 44, 43, 28, 45, 49, 25, 40, 38, 6, 43, 61, 11, 31, 37, 28, 47, 47, 56, 37, 55,
+    17, 15, 46, 35, 24, 25, 44, 43, 3, 29, 57, 31, 13, 44, 38, 17, 24, 50, 29, 34, 28, 23, 49, 49, 56, 24, 55,
+    4, 31, 42, 40, 29, 24, 43, 40, 41, 25, 49, 49, 32, 40, 35, 7, 42, 58, 11, 31, 39, 34, 43, 42, 55, 38, 59,
+    12, 23, 36, 22, 11, 13, 30, 28, 39, 30, 57, 35, 20, 23, 15, 34, 27, 49, 7, 14, 33, 36, 33, 31, 28, 41, 49,
+    16, 13, 45, 41, 41, 12, 22, 44, 44, 26, 59, 49, 30, 42, 38, 7, 44, 61, 30, 33, 17, 7, 50, 48, 54, 40, 59,
+    20, 3, 43, 42, 41, 25, 44, 38, 31, 30, 59, 49, 33, 43, 33, 12, 13, 61, 12, 32, 36, 33, 50, 45, 56, 40, 56,
+    10, 16, 27, 24, 31, 8, 27, 45, 44, 13, 59, 48, 18, 23, 28, 12, 29, 36, 15, 20, 31, 19, 49, 27, 56, 23, 52,
+    26, 18, 28, 15, 28, 18, 21, 26, 45, 37, 52, 44, 24, 20, 10, 6, 30, 36, 24, 19, 19, 34, 13, 49, 46, 48, 35,
+    30, 23, 47, 39, 40, 0, 45, 45, 42, 31, 39, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    7, 7, 7, 40, 40, 21, 40, 43, 40, 26, 50, 45, 33, 40, 31, 31, 42, 61, 34, 27, 38, 39, 50, 39, 49, 38, 59,
+    23, 17, 42, 40, 34, 4, 40, 42, 44, 14, 59, 43, 29, 41, 36, 21, 41, 61, 37, 32, 34, 34, 43, 45, 48, 8, 57,
+    16, 10, 33, 38, 41, 4, 43, 44, 45, 19, 56, 50, 17, 34, 37, 14, 20, 61, 37, 31, 39, 21, 49, 47, 54, 40, 59,
+    20, 20, 43, 32, 30, 1, 39, 25, 43, 23, 52, 41, 38, 43, 34, 20, 43, 54, 37, 27, 26, 39, 41, 45, 54, 42, 53,
+    18, 36, 28, 20, 23, 27, 22, 24, 44, 29, 54, 30, 24, 16, 11, 11, 20, 60, 7, 21, 26, 20, 37, 21, 36, 28, 36,
+    9, 9, 43, 41, 40, 24, 43, 42, 33, 30, 56, 47, 13, 41, 38, 13, 32, 61, 8, 31, 31, 13, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    24, 6, 42, 37, 35, 9, 42, 39, 41, 11, 53, 41, 33, 37, 35, 12, 40, 58, 26, 29, 33, 14, 42, 45, 56, 21, 58,
+    1, 32, 31, 24, 41, 22, 43, 44, 29, 25, 59, 43, 32, 33, 36, 26, 31, 52, 37, 22, 21, 27, 49, 30, 56, 42, 59,
+    11, 15, 44, 39, 41, 6, 43, 44, 25, 12, 57, 49, 15, 41, 36, 15, 31, 61, 16, 22, 33, 34, 48, 30, 55, 23, 51,
+    28, 14, 23, 24, 15, 23, 16, 26, 45, 25, 51, 45, 24, 20, 16, 30, 23, 55, 5, 16, 15, 44, 47, 47, 49, 45, 48,
+    4, 26, 45, 40, 41, 18, 45, 43, 44, 9, 59, 50, 41, 45, 39, 29, 43, 61, 37, 10, 39, 43, 50, 50, 52, 43, 59,
+    27, 13, 41, 40, 39, 7, 40, 45, 19, 10, 50, 47, 36, 43, 31, 6, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    1, 29, 44, 29, 42, 28, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 46, 26, 38, 59,
+    1, 34, 39, 26, 39, 30, 43, 42, 30, 33, 59, 50, 18, 36, 35, 34, 17, 61, 24, 26, 26, 42, 50, 41, 56, 48, 52,
+    4, 25, 43, 42, 37, 9, 42, 45, 46, 26, 59, 10, 38, 45, 39, 25, 44, 61, 36, 35, 40, 14, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    19, 35, 27, 31, 18, 35, 35, 21, 42, 26, 53, 28, 6, 27, 15, 38, 35, 53, 8, 24, 10, 37, 40, 41, 48, 24, 46,
+    16, 15, 19, 18, 27, 17, 23, 44, 43, 17, 21, 49, 8, 14, 38, 25, 27, 61, 27, 10, 16, 19, 47, 47, 56, 26, 55,
+    25, 17, 46, 12, 40, 11, 44, 43, 15, 16, 57, 11, 26, 44, 38, 20, 43, 50, 29, 27, 7, 32, 49, 49, 56, 28, 55,
+    14, 20, 42, 40, 5, 10, 28, 19, 41, 12, 49, 49, 23, 40, 27, 31, 42, 58, 32, 23, 39, 34, 43, 42, 55, 20, 59,
+    9, 32, 21, 33, 18, 11, 37, 23, 43, 36, 57, 44, 19, 35, 11, 37, 36, 49, 12, 9, 22, 29, 40, 42, 39, 41, 49,
+    25, 20, 45, 41, 41, 24, 1, 44, 44, 24, 59, 49, 30, 42, 38, 27, 44, 61, 30, 33, 28, 31, 50, 48, 54, 40, 59,
+    13, 15, 27, 42, 41, 15, 44, 13, 5, 20, 59, 49, 18, 43, 25, 22, 43, 61, 23, 20, 36, 20, 50, 45, 56, 40, 56,
+    4, 4, 41, 40, 40, 23, 43, 45, 44, 26, 59, 48, 35, 39, 35, 24, 42, 54, 32, 34, 31, 32, 49, 44, 56, 37, 52,
+    17, 31, 38, 17, 15, 22, 34, 34, 45, 37, 52, 44, 13, 35, 13, 27, 24, 51, 11, 10, 9, 42, 26, 49, 46, 48, 24,
+    30, 6, 47, 39, 40, 21, 45, 45, 42, 6, 10, 50, 41, 42, 38, 23, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    13, 30, 38, 40, 40, 5, 40, 43, 14, 14, 50, 14, 33, 40, 31, 14, 42, 61, 14, 27, 38, 39, 50, 39, 49, 38, 59,
+    12, 10, 28, 40, 21, 15, 28, 26, 44, 18, 59, 32, 13, 28, 11, 24, 26, 61, 37, 22, 10, 25, 43, 45, 48, 24, 57,
+    7, 16, 13, 38, 41, 10, 43, 44, 45, 17, 56, 50, 38, 21, 20, 21, 14, 61, 37, 15, 39, 27, 49, 47, 54, 40, 59,
+    19, 18, 34, 15, 9, 16, 23, 16, 26, 14, 52, 22, 22, 34, 21, 21, 25, 54, 24, 16, 11, 33, 41, 26, 54, 42, 53,
+    15, 36, 39, 34, 31, 34, 34, 37, 44, 36, 54, 36, 31, 31, 14, 25, 34, 60, 26, 29, 7, 4, 37, 36, 47, 43, 50,
+    12, 19, 43, 25, 21, 12, 29, 23, 33, 21, 56, 29, 17, 41, 38, 20, 8, 61, 16, 16, 10, 33, 49, 46, 56, 41, 56,
+    28, 35, 47, 39, 42, 35, 45, 45, 46, 35, 59, 50, 36, 45, 39, 38, 44, 61, 38, 35, 36, 0, 50, 50, 56, 48, 59,
+    17, 16, 26, 22, 27, 9, 26, 19, 30, 16, 53, 30, 26, 33, 16, 22, 21, 58, 8, 17, 20, 29, 24, 30, 56, 22, 58,
+    9, 23, 32, 26, 30, 12, 43, 35, 20, 14, 59, 27, 23, 39, 16, 32, 25, 52, 33, 7, 13, 28, 49, 42, 56, 32, 59,
+    12, 20, 44, 21, 28, 10, 43, 32, 13, 9, 57, 49, 19, 41, 36, 14, 28, 61, 22, 16, 15, 23, 48, 42, 55, 25, 51,
+    33, 31, 34, 32, 32, 30, 31, 34, 45, 32, 51, 45, 29, 1, 26, 37, 32, 55, 24, 25, 29, 44, 47, 47, 49, 45, 48,
+    11, 26, 45, 40, 41, 4, 45, 43, 44, 10, 59, 50, 41, 45, 39, 11, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    4, 10, 41, 40, 39, 7, 40, 45, 31, 26, 50, 47, 36, 43, 31, 23, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    5, 29, 44, 29, 42, 5, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 12, 35, 46, 26, 38, 59,
+    7, 12, 39, 37, 39, 8, 43, 42, 43, 12, 59, 50, 36, 36, 35, 34, 37, 61, 34, 8, 36, 42, 50, 41, 56, 48, 52,
+    13, 13, 14, 42, 37, 12, 42, 45, 46, 26, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 14, 50, 50, 56, 35, 5,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    18, 23, 22, 18, 24, 35, 35, 23, 42, 23, 30, 26, 8, 28, 12, 38, 35, 53, 12, 12, 11, 29, 40, 41, 48, 37, 30,
+    5, 25, 40, 39, 40, 25, 44, 44, 43, 28, 45, 49, 25, 40, 38, 25, 43, 61, 27, 31, 37, 5, 47, 47, 56, 37, 55,
+    2, 26, 46, 35, 40, 25, 44, 43, 27, 29, 57, 31, 31, 44, 38, 24, 43, 50, 8, 34, 28, 32, 49, 49, 56, 39, 55,
+    3, 31, 42, 40, 29, 24, 43, 40, 41, 25, 49, 49, 32, 40, 35, 31, 42, 58, 32, 7, 39, 34, 43, 42, 55, 38, 59,
+    8, 28, 41, 30, 18, 22, 37, 28, 33, 33, 57, 44, 14, 25, 10, 37, 36, 49, 7, 13, 26, 42, 40, 42, 39, 31, 49,
+    15, 16, 39, 36, 36, 10, 8, 38, 37, 12, 59, 44, 17, 32, 32, 14, 41, 61, 17, 22, 22, 17, 50, 41, 45, 27, 59,
+    0, 28, 43, 42, 41, 25, 44, 38, 31, 30, 59, 49, 33, 43, 33, 30, 43, 61, 26, 32, 36, 33, 50, 45, 56, 40, 56,
+    26, 24, 41, 40, 40, 23, 43, 45, 44, 26, 59, 48, 35, 39, 35, 24, 42, 54, 5, 5, 31, 32, 49, 44, 56, 37, 52,
+    22, 19, 28, 15, 14, 14, 34, 25, 45, 21, 52, 32, 15, 35, 11, 16, 28, 51, 18, 10, 12, 42, 23, 49, 46, 48, 42,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    9, 17, 24, 32, 31, 7, 29, 34, 28, 12, 37, 33, 20, 29, 18, 19, 32, 61, 24, 13, 31, 27, 50, 25, 37, 25, 59,
+    12, 13, 30, 32, 22, 8, 28, 33, 37, 12, 59, 31, 15, 30, 26, 14, 31, 61, 34, 20, 22, 20, 30, 33, 35, 15, 49,
+    13, 10, 19, 27, 38, 9, 34, 40, 38, 11, 46, 50, 28, 20, 30, 13, 14, 61, 35, 19, 34, 19, 44, 37, 46, 27, 59,
+    10, 17, 32, 18, 16, 11, 27, 11, 33, 18, 39, 28, 28, 35, 23, 20, 35, 42, 33, 14, 12, 27, 28, 33, 45, 30, 40,
+    24, 36, 39, 14, 31, 34, 34, 37, 44, 13, 54, 36, 7, 31, 18, 15, 34, 60, 11, 24, 14, 17, 37, 36, 47, 20, 50,
+    5, 26, 43, 41, 40, 24, 43, 42, 33, 30, 56, 47, 29, 41, 5, 26, 32, 61, 24, 31, 31, 33, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    26, 27, 42, 37, 35, 7, 42, 39, 41, 27, 53, 41, 37, 37, 35, 3, 40, 58, 26, 29, 33, 35, 42, 45, 56, 35, 58,
+    2, 32, 44, 35, 41, 27, 43, 44, 34, 29, 59, 43, 37, 39, 9, 32, 36, 52, 37, 23, 28, 34, 49, 42, 56, 42, 59,
+    1, 29, 44, 39, 41, 23, 43, 44, 31, 24, 57, 49, 35, 41, 36, 29, 42, 61, 28, 29, 33, 34, 48, 42, 55, 35, 51,
+    33, 31, 34, 32, 32, 30, 31, 34, 45, 32, 51, 45, 1, 32, 26, 37, 32, 55, 24, 25, 29, 44, 47, 47, 49, 45, 48,
+    21, 12, 37, 32, 34, 4, 45, 36, 36, 9, 59, 50, 41, 45, 39, 16, 36, 61, 32, 31, 37, 36, 50, 50, 41, 32, 59,
+    14, 10, 29, 32, 30, 10, 29, 45, 17, 12, 37, 37, 25, 34, 19, 9, 32, 61, 22, 18, 29, 36, 40, 35, 41, 29, 59,
+    1, 29, 44, 29, 42, 28, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 46, 26, 38, 59,
+    0, 34, 39, 37, 39, 30, 43, 42, 43, 33, 59, 50, 36, 36, 35, 34, 37, 61, 34, 30, 36, 42, 50, 41, 56, 48, 52,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    22, 27, 29, 31, 17, 35, 23, 21, 31, 19, 53, 27, 13, 29, 16, 38, 29, 53, 7, 15, 13, 30, 24, 41, 28, 13, 32,
+    12, 12, 40, 39, 40, 6, 44, 44, 43, 12, 45, 49, 25, 40, 38, 8, 43, 61, 27, 31, 37, 28, 47, 47, 56, 37, 55,
+    8, 4, 46, 35, 40, 25, 44, 43, 27, 29, 57, 31, 31, 44, 38, 24, 43, 50, 8, 34, 28, 32, 49, 49, 56, 39, 55,
+    4, 31, 42, 40, 29, 10, 43, 40, 41, 25, 49, 49, 32, 40, 7, 31, 42, 58, 32, 31, 39, 34, 43, 42, 55, 38, 59,
+    22, 16, 17, 33, 14, 6, 37, 30, 43, 17, 57, 44, 16, 29, 19, 37, 36, 49, 12, 13, 26, 42, 31, 42, 39, 41, 49,
+    28, 29, 45, 41, 41, 24, 22, 44, 44, 26, 59, 49, 30, 42, 38, 6, 44, 61, 30, 33, 34, 3, 50, 48, 54, 40, 59,
+    6, 15, 33, 37, 36, 12, 37, 25, 17, 17, 59, 41, 20, 34, 21, 17, 35, 61, 13, 20, 25, 19, 50, 34, 56, 27, 46,
+    26, 15, 41, 40, 40, 4, 43, 45, 44, 11, 59, 48, 35, 39, 35, 10, 42, 54, 32, 34, 31, 32, 49, 44, 56, 25, 52,
+    24, 31, 38, 18, 14, 29, 22, 34, 45, 37, 52, 22, 12, 25, 7, 27, 29, 51, 19, 10, 12, 42, 25, 49, 46, 48, 30,
+    30, 23, 47, 39, 40, 21, 45, 45, 42, 31, 39, 50, 41, 42, 38, 0, 37, 61, 37, 35, 40, 25, 44, 50, 56, 48, 59,
+    4, 30, 38, 40, 40, 21, 40, 43, 40, 26, 50, 45, 33, 40, 31, 31, 42, 61, 34, 27, 38, 39, 50, 5, 49, 38, 59,
+    16, 26, 42, 40, 34, 3, 40, 42, 44, 12, 59, 43, 29, 41, 36, 16, 41, 61, 37, 32, 34, 34, 43, 45, 48, 10, 57,
+    7, 3, 33, 38, 41, 23, 43, 44, 45, 25, 56, 50, 38, 34, 37, 27, 28, 61, 37, 31, 39, 33, 49, 47, 54, 40, 59,
+    5, 30, 43, 32, 30, 7, 39, 25, 43, 31, 52, 41, 13, 43, 34, 32, 43, 54, 37, 16, 16, 39, 41, 45, 54, 42, 53,
+    28, 36, 39, 34, 31, 34, 30, 37, 44, 36, 54, 36, 24, 21, 20, 3, 34, 60, 7, 29, 31, 26, 37, 30, 47, 43, 50,
+    10, 10, 43, 41, 40, 9, 43, 42, 33, 10, 56, 47, 29, 41, 38, 10, 32, 61, 24, 31, 31, 33, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    18, 12, 42, 37, 35, 13, 42, 39, 41, 4, 53, 41, 37, 37, 35, 9, 40, 58, 26, 29, 33, 35, 42, 45, 56, 35, 58,
+    3, 32, 44, 35, 41, 16, 43, 44, 19, 19, 59, 43, 19, 23, 36, 22, 19, 52, 16, 17, 28, 23, 49, 23, 56, 42, 59,
+    11, 29, 44, 39, 41, 23, 43, 44, 12, 11, 57, 49, 35, 41, 36, 4, 42, 61, 28, 29, 33, 34, 48, 42, 55, 35, 51,
+    5, 31, 34, 32, 32, 30, 31, 34, 45, 32, 51, 45, 5, 32, 26, 37, 32, 55, 24, 25, 29, 44, 47, 47, 49, 45, 48,
+    1, 26, 45, 40, 41, 18, 45, 43, 44, 23, 59, 50, 41, 45, 39, 29, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    3, 24, 41, 40, 39, 24, 40, 45, 31, 26, 50, 47, 36, 43, 31, 23, 42, 61, 33, 30, 38, 43, 48, 7, 52, 41, 59,
+    1, 29, 44, 29, 42, 28, 43, 45, 38, 26, 59, 50, 39, 40, 37, 36, 24, 61, 38, 36, 27, 35, 35, 46, 26, 38, 59,
+    8, 34, 39, 9, 39, 9, 43, 42, 43, 33, 59, 50, 36, 36, 13, 13, 37, 61, 34, 13, 36, 42, 50, 41, 56, 48, 52,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    29, 35, 35, 13, 31, 35, 35, 34, 42, 32, 53, 40, 28, 3, 13, 38, 35, 53, 25, 18, 13, 37, 40, 41, 48, 37, 46,
+    31, 25, 40, 39, 40, 25, 44, 44, 43, 28, 45, 49, 25, 40, 38, 1, 43, 61, 27, 31, 37, 28, 47, 47, 56, 37, 55,
+    30, 19, 46, 35, 40, 4, 44, 43, 16, 14, 57, 31, 9, 44, 38, 24, 43, 50, 29, 34, 28, 20, 49, 49, 56, 39, 55,
+    7, 18, 31, 31, 15, 10, 34, 28, 29, 11, 36, 43, 19, 29, 24, 18, 34, 49, 20, 19, 34, 21, 30, 29, 48, 24, 59,
+    18, 32, 41, 10, 9, 27, 37, 39, 43, 36, 57, 44, 16, 14, 27, 37, 36, 49, 10, 9, 33, 42, 40, 42, 39, 41, 49,
+    28, 29, 45, 41, 41, 24, 22, 44, 44, 26, 59, 49, 30, 42, 38, 1, 44, 61, 30, 33, 34, 31, 50, 48, 54, 40, 59,
+    6, 15, 33, 37, 36, 12, 37, 25, 17, 17, 59, 41, 20, 34, 21, 17, 35, 61, 13, 20, 25, 19, 50, 34, 56, 27, 46,
+    26, 10, 41, 40, 40, 23, 43, 45, 44, 3, 59, 48, 35, 39, 35, 24, 42, 54, 32, 34, 11, 32, 49, 44, 56, 37, 52,
+    13, 22, 19, 16, 22, 21, 34, 22, 45, 11, 52, 44, 22, 15, 13, 27, 37, 51, 31, 11, 18, 42, 16, 49, 17, 48, 42,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    9, 17, 24, 32, 31, 7, 29, 34, 28, 12, 37, 33, 20, 29, 18, 19, 32, 61, 24, 13, 31, 27, 50, 25, 37, 25, 59,
+    4, 26, 42, 40, 34, 4, 40, 42, 44, 26, 59, 43, 29, 41, 36, 27, 41, 61, 37, 32, 34, 34, 43, 45, 48, 29, 57,
+    26, 7, 33, 38, 41, 23, 43, 44, 45, 25, 56, 50, 7, 34, 37, 7, 28, 61, 37, 31, 39, 33, 49, 47, 54, 40, 59,
+    24, 30, 43, 32, 30, 4, 39, 25, 43, 31, 52, 41, 38, 43, 34, 32, 43, 54, 37, 27, 26, 39, 41, 45, 5, 42, 53,
+    7, 36, 39, 34, 31, 34, 34, 37, 44, 36, 54, 36, 31, 31, 7, 25, 34, 60, 26, 29, 7, 31, 37, 36, 47, 43, 50,
+    20, 15, 43, 41, 40, 7, 43, 42, 33, 17, 56, 47, 11, 41, 38, 16, 32, 61, 7, 31, 31, 33, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    13, 13, 30, 25, 22, 9, 31, 26, 29, 13, 41, 28, 26, 24, 24, 13, 30, 47, 12, 17, 20, 22, 29, 33, 51, 21, 52,
+    21, 32, 44, 35, 41, 1, 43, 44, 34, 29, 59, 43, 37, 39, 36, 32, 36, 52, 37, 23, 28, 34, 49, 42, 56, 42, 59,
+    13, 29, 18, 39, 41, 6, 43, 44, 21, 16, 57, 49, 35, 41, 36, 29, 42, 61, 8, 21, 33, 15, 48, 42, 55, 35, 51,
+    33, 2, 34, 32, 32, 30, 31, 34, 45, 32, 51, 45, 29, 32, 26, 37, 32, 55, 13, 13, 29, 44, 47, 47, 49, 45, 48,
+    10, 26, 45, 40, 41, 18, 45, 43, 44, 2, 59, 50, 41, 45, 39, 29, 43, 61, 37, 36, 39, 43, 50, 50, 52, 43, 59,
+    27, 24, 41, 40, 39, 24, 40, 45, 31, 26, 50, 47, 36, 43, 31, 0, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    11, 29, 44, 29, 42, 28, 43, 45, 38, 7, 59, 50, 39, 40, 37, 23, 24, 61, 38, 36, 27, 35, 10, 46, 7, 38, 59,
+    4, 34, 39, 37, 39, 30, 43, 5, 43, 33, 59, 50, 36, 36, 35, 34, 37, 61, 34, 30, 36, 42, 50, 41, 56, 48, 52,
+    13, 11, 32, 42, 26, 6, 32, 45, 46, 13, 59, 28, 28, 45, 39, 11, 44, 61, 27, 30, 40, 20, 50, 50, 56, 21, 18,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    16, 20, 20, 20, 31, 35, 35, 34, 17, 32, 53, 40, 14, 20, 7, 38, 35, 53, 8, 16, 19, 37, 40, 41, 48, 37, 21,
+    31, 11, 40, 39, 40, 7, 44, 44, 43, 28, 45, 49, 25, 40, 38, 6, 43, 61, 11, 31, 37, 28, 47, 47, 56, 37, 55,
+    18, 18, 46, 35, 40, 14, 44, 43, 12, 29, 57, 18, 6, 44, 38, 8, 43, 50, 29, 34, 28, 32, 49, 49, 56, 39, 55,
+    9, 10, 42, 40, 10, 24, 43, 40, 41, 25, 49, 49, 32, 40, 14, 31, 42, 58, 7, 31, 39, 34, 43, 42, 55, 38, 59,
+    15, 12, 41, 33, 12, 15, 37, 19, 43, 36, 57, 44, 16, 25, 22, 37, 36, 49, 8, 12, 21, 22, 40, 22, 39, 41, 49,
+    28, 29, 45, 41, 41, 24, 22, 44, 44, 26, 59, 49, 30, 42, 38, 7, 44, 61, 30, 33, 34, 3, 50, 48, 54, 40, 59,
+    20, 28, 43, 42, 41, 3, 44, 38, 31, 10, 59, 49, 10, 43, 33, 30, 43, 61, 26, 32, 36, 33, 50, 45, 56, 40, 56,
+    26, 24, 41, 40, 40, 23, 43, 45, 44, 26, 59, 48, 35, 39, 35, 1, 42, 54, 32, 34, 31, 32, 49, 44, 56, 37, 52,
+    32, 31, 38, 29, 21, 17, 34, 34, 45, 37, 52, 44, 32, 35, 1, 27, 37, 51, 31, 17, 29, 42, 33, 49, 46, 48, 42,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    9, 17, 24, 32, 31, 7, 29, 34, 28, 12, 37, 33, 20, 29, 18, 19, 32, 61, 24, 13, 31, 27, 50, 25, 37, 25, 59,
+    14, 14, 42, 40, 34, 6, 40, 42, 44, 8, 59, 43, 29, 41, 36, 14, 41, 61, 37, 32, 34, 15, 19, 45, 48, 29, 57,
+    19, 9, 17, 38, 41, 7, 43, 44, 45, 25, 56, 50, 38, 34, 37, 10, 9, 61, 37, 31, 39, 33, 49, 47, 54, 40, 59,
+    10, 15, 20, 19, 13, 8, 39, 18, 43, 31, 52, 41, 38, 43, 19, 13, 43, 54, 37, 27, 13, 39, 41, 45, 54, 42, 53,
+    13, 36, 39, 34, 31, 34, 11, 21, 44, 36, 54, 36, 31, 20, 12, 19, 34, 60, 14, 17, 20, 7, 17, 36, 47, 43, 50,
+    29, 10, 43, 41, 40, 8, 43, 42, 14, 12, 56, 47, 29, 41, 38, 13, 32, 61, 13, 31, 13, 33, 49, 46, 56, 41, 56,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    18, 18, 42, 37, 35, 14, 42, 39, 41, 6, 53, 41, 37, 37, 35, 12, 40, 58, 8, 18, 19, 35, 42, 45, 56, 35, 58,
+    5, 32, 44, 35, 41, 16, 43, 44, 34, 13, 59, 43, 25, 26, 36, 32, 25, 26, 37, 10, 12, 34, 49, 42, 56, 42, 59,
+    25, 29, 44, 39, 41, 9, 43, 44, 4, 13, 57, 49, 35, 41, 36, 13, 42, 61, 28, 29, 33, 34, 48, 42, 55, 35, 51,
+    10, 31, 34, 32, 32, 10, 31, 10, 45, 32, 51, 10, 29, 32, 10, 37, 32, 55, 24, 25, 29, 44, 47, 47, 49, 45, 48,
+    21, 12, 37, 32, 34, 4, 45, 36, 36, 9, 59, 50, 41, 45, 39, 16, 36, 61, 32, 31, 37, 36, 50, 50, 41, 32, 59,
+    27, 9, 41, 40, 39, 24, 40, 45, 9, 26, 50, 47, 36, 43, 31, 4, 42, 61, 33, 30, 38, 43, 48, 46, 52, 41, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    2, 23, 26, 25, 29, 18, 36, 33, 34, 22, 59, 50, 25, 23, 24, 22, 24, 61, 24, 17, 25, 33, 50, 28, 56, 48, 39,
+    26, 25, 43, 42, 37, 0, 42, 45, 46, 26, 59, 41, 38, 45, 39, 25, 44, 61, 36, 35, 40, 34, 50, 50, 56, 35, 33,
+    35, 16, 16, 14, 17, 18, 18, 19, 19, 19, 26, 25, 19, 17, 22, 21, 15, 31, 17, 13, 17, 24, 24, 20, 37, 32, 34,
+    11, 13, 21, 20, 31, 21, 21, 34, 42, 32, 53, 21, 20, 17, 19, 38, 35, 53, 10, 19, 8, 37, 40, 41, 48, 37, 46,
+    31, 25, 40, 39, 40, 1, 44, 44, 43, 28, 45, 49, 25, 40, 38, 25, 43, 61, 27, 31, 37, 28, 47, 47, 56, 37, 55,
+    18, 12, 42, 23, 33, 12, 39, 36, 13, 16, 48, 17, 18, 38, 34, 10, 37, 36, 16, 26, 14, 18, 43, 45, 56, 26, 45,
+    21, 7, 42, 40, 29, 24, 43, 40, 41, 25, 49, 49, 32, 40, 7, 7, 42, 58, 32, 31, 39, 34, 43, 42, 55, 38, 59,
+    7, 25, 22, 25, 9, 15, 37, 39, 43, 22, 57, 44, 24, 35, 15, 37, 36, 49, 14, 12, 20, 42, 40, 42, 39, 41, 49,
+    28, 1, 45, 41, 41, 24, 22, 44, 44, 26, 59, 49, 30, 42, 38, 27, 44, 61, 30, 33, 34, 31, 50, 48, 54, 40, 59,
+    6, 15, 33, 37, 36, 12, 37, 25, 17, 17, 59, 41, 20, 34, 21, 17, 35, 61, 13, 20, 25, 19, 50, 34, 56, 27, 46,
+    13, 10, 29, 33, 33, 9, 34, 45, 38, 13, 59, 39, 23, 28, 24, 10, 34, 42, 20, 25, 18, 18, 43, 31, 56, 24, 39,
+    20, 31, 38, 29, 33, 29, 20, 34, 45, 37, 52, 44, 10, 20, 4, 15, 16, 51, 31, 26, 29, 42, 33, 49, 46, 48, 42,
+    18, 9, 47, 28, 32, 7, 45, 45, 32, 18, 25, 50, 41, 32, 31, 9, 25, 61, 31, 30, 40, 11, 32, 50, 56, 48, 59,
+    22, 30, 38, 40, 40, 21, 40, 43, 40, 26, 50, 45, 33, 40, 31, 31, 42, 61, 34, 27, 38, 39, 50, 39, 49, 1, 59,
+    26, 26, 42, 40, 34, 0, 40, 42, 44, 26, 59, 43, 29, 41, 36, 27, 41, 61, 37, 32, 34, 34, 43, 45, 48, 29, 57,
+    13, 10, 19, 27, 38, 9, 34, 40, 38, 11, 46, 50, 28, 20, 30, 13, 14, 61, 35, 19, 34, 19, 44, 37, 46, 27, 59,
+    10, 17, 32, 18, 16, 11, 27, 11, 33, 18, 39, 28, 28, 35, 23, 20, 35, 42, 33, 14, 12, 27, 28, 33, 45, 30, 40,
+    21, 21, 39, 34, 31, 34, 34, 37, 44, 36, 54, 36, 21, 31, 8, 3, 21, 60, 19, 29, 31, 31, 37, 36, 47, 43, 50,
+    16, 12, 33, 34, 32, 11, 33, 33, 19, 17, 46, 37, 16, 31, 32, 12, 18, 61, 10, 19, 18, 20, 44, 36, 56, 28, 46,
+    15, 25, 47, 29, 42, 35, 45, 45, 46, 25, 59, 50, 24, 45, 39, 38, 44, 61, 38, 28, 26, 1, 50, 50, 56, 48, 59,
+    26, 4, 42, 37, 35, 4, 42, 39, 41, 27, 53, 41, 37, 37, 35, 27, 40, 58, 26, 29, 33, 35, 42, 45, 56, 35, 58,
+    21, 32, 44, 35, 41, 27, 43, 44, 1, 29, 59, 43, 37, 39, 36, 32, 36, 52, 37, 23, 28, 34, 49, 42, 56, 42, 59,
+    11, 15, 34, 28, 38, 9, 34, 38, 16, 10, 50, 43, 22, 30, 27, 15, 34, 61, 15, 17, 21, 20, 41, 29, 49, 21, 38,
+    12, 31, 34, 32, 32, 12, 31, 34, 45, 32, 51, 13, 29, 12, 12, 37, 32, 55, 12, 8, 29, 44, 47, 47, 49, 45, 48,
+    21, 12, 37, 32, 34, 4, 45, 36, 36, 9, 59, 50, 41, 45, 39, 16, 36, 61, 32, 31, 37, 36, 50, 50, 41, 32, 59,
+    14, 10, 29, 32, 30, 10, 29, 45, 17, 12, 37, 37, 25, 34, 19, 9, 32, 61, 22, 18, 29, 36, 40, 35, 41, 29, 59,
+    10, 16, 34, 15, 42, 16, 34, 45, 25, 13, 59, 50, 30, 29, 30, 28, 10, 61, 38, 32, 14, 22, 21, 35, 12, 25, 59,
+    1, 34, 39, 37, 39, 30, 43, 42, 43, 33, 59, 50, 36, 12, 35, 34, 37, 61, 34, 30, 36, 42, 50, 41, 56, 48, 52,
+    10, 10, 43, 42, 37, 13, 42, 45, 46, 10, 59, 41, 10, 45, 39, 14, 44, 61, 36, 35, 40, 34, 50, 50, 56, 14, 33,
+];
+
+#[rustfmt::skip]
+pub static VIETNAMESE: [u8; 19683] = [
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    22, 8, 50, 17, 44, 40, 23, 41, 39, 17, 23, 50, 49, 17, 11, 16, 23, 55, 23, 23, 17, 22, 53, 11, 23, 22, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    33, 11, 50, 43, 44, 40, 43, 41, 3, 41, 26, 50, 49, 44, 38, 12, 48, 55, 42, 24, 42, 12, 53, 41, 46, 50, 83,
+    33, 11, 50, 43, 4, 19, 43, 41, 39, 15, 42, 50, 49, 44, 38, 13, 48, 55, 42, 40, 42, 13, 53, 41, 46, 50, 83,
+    20, 37, 50, 20, 44, 6, 20, 41, 39, 41, 20, 50, 49, 16, 12, 10, 17, 55, 35, 20, 16, 30, 53, 41, 38, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    24, 10, 50, 43, 44, 32, 26, 41, 15, 5, 30, 50, 49, 44, 38, 11, 48, 55, 29, 27, 42, 15, 53, 41, 31, 50, 83,
+    29, 8, 50, 43, 44, 13, 34, 41, 39, 14, 32, 50, 49, 44, 38, 6, 48, 55, 35, 31, 42, 9, 53, 41, 40, 50, 83,
+    16, 17, 50, 13, 44, 23, 17, 41, 39, 41, 17, 50, 49, 13, 8, 32, 40, 55, 17, 17, 13, 17, 53, 41, 17, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    23, 7, 50, 43, 44, 14, 25, 41, 39, 14, 28, 50, 49, 44, 38, 8, 48, 55, 29, 25, 42, 8, 53, 41, 31, 32, 83,
+    26, 14, 50, 43, 44, 19, 29, 6, 6, 20, 31, 50, 49, 44, 38, 15, 48, 55, 33, 30, 42, 17, 53, 41, 36, 50, 83,
+    24, 8, 50, 21, 44, 18, 25, 41, 39, 16, 25, 50, 49, 19, 16, 8, 22, 55, 35, 25, 21, 40, 53, 11, 38, 50, 83,
+    32, 24, 50, 43, 44, 29, 43, 41, 1, 26, 23, 50, 49, 44, 38, 29, 48, 55, 42, 21, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    17, 7, 50, 43, 44, 13, 43, 41, 39, 13, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    15, 7, 50, 43, 44, 13, 43, 41, 39, 14, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    32, 13, 50, 43, 44, 19, 43, 41, 6, 16, 24, 50, 49, 44, 38, 14, 48, 55, 9, 23, 42, 14, 53, 41, 46, 50, 83,
+    21, 14, 50, 19, 44, 19, 22, 41, 39, 19, 23, 50, 49, 19, 17, 14, 23, 55, 23, 22, 19, 40, 53, 6, 37, 12, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    16, 9, 50, 43, 44, 13, 43, 41, 39, 11, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    14, 36, 50, 38, 44, 2, 27, 41, 39, 41, 28, 50, 49, 44, 33, 37, 48, 55, 14, 14, 35, 40, 53, 41, 31, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    30, 24, 50, 21, 44, 40, 35, 41, 39, 30, 36, 50, 49, 10, 8, 31, 15, 55, 35, 33, 14, 11, 53, 26, 38, 10, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    33, 27, 50, 43, 44, 40, 43, 41, 5, 41, 9, 50, 49, 44, 38, 29, 48, 55, 42, 6, 42, 29, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    19, 37, 50, 21, 44, 6, 20, 41, 39, 41, 23, 50, 49, 17, 10, 16, 20, 55, 22, 20, 16, 17, 53, 41, 24, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    10, 28, 50, 35, 44, 23, 11, 41, 39, 41, 12, 50, 49, 35, 28, 32, 40, 55, 10, 9, 34, 31, 53, 41, 13, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    9, 27, 50, 43, 44, 33, 10, 41, 39, 33, 10, 50, 49, 44, 38, 28, 48, 55, 12, 9, 42, 29, 53, 41, 15, 47, 83,
+    13, 32, 50, 43, 35, 36, 14, 7, 9, 37, 15, 50, 49, 44, 38, 33, 48, 55, 15, 14, 42, 35, 53, 41, 19, 50, 83,
+    9, 27, 50, 33, 44, 35, 9, 41, 39, 29, 11, 50, 49, 32, 25, 23, 38, 55, 12, 10, 33, 40, 53, 24, 16, 50, 83,
+    24, 35, 50, 43, 44, 39, 43, 41, 19, 39, 4, 50, 49, 44, 38, 37, 48, 55, 42, 4, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    22, 29, 50, 43, 44, 34, 43, 41, 23, 32, 5, 50, 49, 44, 38, 24, 48, 55, 25, 4, 42, 30, 53, 41, 46, 50, 83,
+    8, 27, 50, 35, 44, 34, 10, 41, 39, 31, 12, 50, 49, 34, 28, 28, 39, 55, 13, 9, 35, 40, 53, 22, 17, 30, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    30, 30, 50, 13, 44, 40, 34, 41, 39, 29, 36, 50, 49, 10, 4, 25, 17, 55, 35, 33, 12, 34, 53, 41, 37, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    8, 36, 50, 38, 44, 24, 11, 41, 39, 41, 12, 50, 49, 44, 33, 37, 48, 55, 10, 9, 35, 40, 53, 41, 15, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    24, 7, 50, 18, 44, 40, 25, 41, 39, 16, 25, 50, 49, 24, 11, 16, 35, 55, 25, 25, 22, 19, 53, 10, 26, 19, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    21, 37, 50, 34, 44, 5, 22, 41, 39, 41, 22, 50, 49, 18, 11, 12, 18, 55, 22, 22, 18, 30, 53, 41, 22, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    20, 15, 50, 18, 44, 7, 20, 41, 39, 41, 21, 50, 49, 18, 11, 32, 22, 55, 20, 20, 18, 13, 53, 41, 21, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 22, 50, 43, 44, 27, 43, 41, 39, 26, 42, 50, 49, 44, 38, 0, 48, 55, 42, 40, 42, 22, 53, 41, 46, 42, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    23, 27, 50, 24, 44, 35, 24, 41, 39, 20, 25, 50, 49, 17, 12, 6, 21, 55, 25, 24, 21, 40, 53, 8, 25, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    22, 14, 50, 24, 44, 34, 23, 41, 39, 20, 23, 50, 49, 18, 13, 11, 24, 55, 24, 31, 20, 40, 53, 5, 37, 23, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    24, 8, 50, 18, 44, 40, 25, 41, 39, 17, 26, 50, 49, 18, 11, 17, 22, 55, 25, 25, 25, 17, 53, 10, 38, 18, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    19, 37, 50, 21, 44, 6, 20, 41, 39, 41, 23, 50, 49, 17, 10, 16, 20, 55, 22, 20, 16, 17, 53, 41, 24, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    28, 9, 50, 43, 44, 14, 34, 41, 39, 14, 13, 50, 49, 44, 38, 10, 48, 55, 31, 12, 42, 10, 53, 41, 40, 50, 83,
+    13, 14, 50, 22, 44, 9, 14, 41, 39, 41, 16, 50, 49, 21, 15, 20, 27, 55, 15, 14, 21, 18, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    23, 27, 50, 20, 44, 35, 24, 41, 39, 15, 24, 50, 49, 20, 11, 6, 21, 55, 24, 24, 20, 40, 53, 9, 38, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    22, 19, 50, 21, 44, 34, 24, 41, 39, 15, 24, 50, 49, 19, 12, 11, 21, 55, 24, 23, 21, 40, 53, 5, 25, 30, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    24, 7, 50, 19, 44, 40, 25, 41, 39, 16, 28, 50, 49, 18, 11, 17, 25, 55, 25, 25, 21, 22, 53, 10, 25, 16, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    33, 7, 50, 43, 20, 13, 43, 41, 39, 13, 42, 50, 49, 44, 38, 7, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    19, 37, 50, 34, 44, 4, 19, 41, 39, 41, 35, 50, 49, 22, 14, 13, 17, 55, 19, 23, 17, 30, 53, 41, 19, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    19, 15, 50, 19, 44, 5, 20, 41, 39, 41, 23, 50, 49, 19, 12, 32, 21, 55, 23, 22, 21, 16, 53, 41, 20, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    23, 11, 50, 21, 44, 35, 24, 41, 39, 16, 27, 50, 49, 17, 13, 7, 28, 55, 24, 24, 24, 40, 53, 9, 38, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    21, 15, 50, 21, 44, 34, 22, 41, 39, 15, 22, 50, 49, 19, 11, 15, 23, 55, 26, 22, 26, 40, 53, 5, 37, 20, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    21, 10, 50, 19, 44, 40, 22, 41, 39, 17, 23, 50, 49, 16, 10, 19, 21, 55, 22, 21, 19, 17, 53, 12, 25, 16, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    33, 27, 50, 43, 44, 40, 43, 41, 3, 41, 14, 50, 49, 44, 38, 29, 48, 55, 42, 7, 42, 29, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    18, 37, 50, 16, 44, 20, 19, 41, 39, 41, 20, 50, 49, 14, 6, 29, 19, 55, 21, 20, 14, 11, 53, 41, 24, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    13, 14, 50, 22, 44, 9, 14, 41, 39, 41, 16, 50, 49, 21, 15, 20, 27, 55, 15, 14, 21, 18, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    7, 27, 50, 43, 44, 33, 9, 41, 39, 33, 12, 50, 49, 44, 38, 28, 48, 55, 14, 9, 42, 29, 53, 41, 16, 47, 83,
+    9, 32, 50, 43, 44, 36, 11, 10, 11, 37, 13, 50, 49, 44, 38, 33, 48, 55, 16, 13, 42, 35, 53, 41, 17, 50, 83,
+    9, 27, 50, 33, 44, 35, 9, 41, 39, 29, 10, 50, 49, 32, 25, 23, 38, 55, 14, 10, 33, 40, 53, 24, 16, 50, 83,
+    32, 35, 50, 43, 44, 39, 43, 41, 19, 39, 6, 50, 49, 44, 38, 37, 48, 55, 42, 3, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    25, 29, 50, 43, 44, 34, 43, 41, 23, 32, 5, 50, 49, 44, 38, 30, 48, 55, 25, 4, 42, 30, 53, 41, 46, 50, 83,
+    8, 27, 50, 35, 44, 34, 9, 41, 39, 31, 13, 50, 49, 34, 28, 28, 39, 55, 13, 9, 35, 40, 53, 22, 16, 30, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    21, 10, 50, 19, 44, 40, 22, 41, 39, 17, 23, 50, 49, 16, 10, 19, 21, 55, 22, 21, 19, 17, 53, 12, 25, 16, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    19, 37, 50, 21, 44, 6, 20, 41, 39, 41, 23, 50, 49, 17, 10, 16, 20, 55, 22, 20, 16, 17, 53, 41, 24, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    13, 14, 50, 22, 44, 9, 14, 41, 39, 41, 16, 50, 49, 21, 15, 20, 27, 55, 15, 14, 21, 18, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    19, 14, 50, 20, 44, 23, 20, 41, 39, 15, 22, 50, 49, 18, 12, 9, 25, 55, 22, 21, 20, 40, 53, 10, 25, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    16, 14, 50, 22, 44, 21, 18, 41, 39, 18, 19, 50, 49, 20, 14, 15, 26, 55, 21, 17, 23, 37, 53, 7, 23, 16, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    23, 7, 50, 17, 44, 40, 24, 41, 39, 16, 36, 50, 49, 20, 11, 17, 24, 55, 27, 27, 22, 21, 53, 10, 28, 17, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    29, 37, 50, 34, 44, 20, 33, 41, 39, 41, 35, 50, 49, 30, 1, 29, 34, 55, 35, 33, 30, 30, 53, 41, 38, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    29, 23, 50, 43, 44, 4, 34, 41, 39, 6, 32, 50, 49, 44, 38, 23, 48, 55, 35, 31, 42, 24, 53, 41, 40, 50, 83,
+    26, 5, 50, 35, 44, 12, 25, 41, 39, 41, 30, 50, 49, 35, 25, 8, 40, 55, 25, 24, 34, 13, 53, 41, 27, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    25, 9, 50, 22, 44, 19, 24, 41, 39, 17, 27, 50, 49, 20, 13, 8, 28, 55, 27, 27, 20, 40, 53, 10, 24, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    19, 19, 50, 21, 44, 34, 20, 41, 39, 17, 20, 50, 49, 20, 19, 13, 25, 55, 24, 31, 24, 40, 53, 5, 24, 13, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    23, 8, 50, 18, 44, 40, 24, 41, 39, 16, 29, 50, 49, 18, 11, 16, 22, 55, 24, 25, 22, 20, 53, 10, 30, 18, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    18, 37, 50, 26, 44, 5, 19, 41, 39, 41, 24, 50, 49, 16, 12, 13, 18, 55, 21, 20, 16, 30, 53, 41, 24, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    18, 16, 50, 19, 44, 6, 19, 41, 39, 41, 21, 50, 49, 17, 11, 32, 22, 55, 19, 19, 18, 16, 53, 41, 26, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    23, 9, 50, 21, 44, 17, 25, 41, 39, 18, 27, 50, 49, 18, 14, 8, 26, 55, 27, 25, 22, 40, 53, 10, 33, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    21, 15, 50, 19, 44, 19, 24, 41, 39, 17, 23, 50, 49, 19, 12, 15, 27, 55, 24, 22, 20, 32, 53, 7, 27, 13, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    15, 11, 50, 26, 44, 40, 15, 41, 39, 18, 16, 50, 49, 19, 12, 19, 26, 55, 15, 16, 23, 22, 53, 13, 16, 22, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    33, 27, 50, 43, 44, 40, 43, 41, 0, 41, 26, 50, 49, 44, 38, 29, 48, 55, 42, 24, 42, 29, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    29, 37, 50, 34, 44, 1, 33, 41, 39, 41, 35, 50, 49, 28, 23, 24, 30, 55, 30, 29, 30, 30, 53, 41, 38, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    13, 14, 50, 22, 44, 9, 14, 41, 39, 41, 16, 50, 49, 21, 15, 20, 27, 55, 15, 14, 21, 18, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    6, 27, 50, 43, 44, 33, 8, 41, 39, 33, 19, 50, 49, 44, 38, 28, 48, 55, 14, 8, 42, 29, 53, 41, 20, 47, 83,
+    14, 32, 50, 43, 44, 36, 17, 19, 2, 37, 19, 50, 49, 44, 38, 33, 48, 55, 26, 16, 42, 35, 53, 41, 26, 50, 83,
+    20, 27, 50, 21, 44, 35, 21, 41, 39, 12, 21, 50, 49, 32, 10, 7, 38, 55, 21, 21, 21, 40, 53, 10, 38, 50, 83,
+    32, 35, 50, 43, 44, 39, 43, 41, 19, 39, 7, 50, 49, 44, 38, 37, 48, 55, 42, 2, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    32, 29, 50, 43, 44, 34, 43, 41, 23, 32, 4, 50, 49, 44, 38, 30, 48, 55, 25, 4, 42, 30, 53, 41, 46, 50, 83,
+    11, 17, 50, 35, 44, 34, 13, 41, 39, 19, 10, 50, 49, 23, 22, 28, 39, 55, 16, 10, 35, 40, 53, 12, 18, 30, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    21, 10, 50, 19, 44, 40, 22, 41, 39, 17, 23, 50, 49, 16, 10, 19, 21, 55, 22, 21, 19, 17, 53, 12, 25, 16, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    19, 37, 50, 21, 44, 6, 20, 41, 39, 41, 23, 50, 49, 17, 10, 16, 20, 55, 22, 20, 16, 17, 53, 41, 24, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    13, 14, 50, 22, 44, 9, 14, 41, 39, 41, 16, 50, 49, 21, 15, 20, 27, 55, 15, 14, 21, 18, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    19, 14, 50, 20, 44, 23, 20, 41, 39, 15, 22, 50, 49, 18, 12, 9, 25, 55, 22, 21, 20, 40, 53, 10, 25, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    16, 14, 50, 22, 44, 21, 18, 41, 39, 18, 19, 50, 49, 20, 14, 15, 26, 55, 21, 17, 23, 37, 53, 7, 23, 16, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    21, 10, 50, 19, 44, 40, 22, 41, 39, 17, 23, 50, 49, 16, 10, 19, 21, 55, 22, 21, 19, 17, 53, 12, 25, 16, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    21, 37, 50, 22, 44, 5, 22, 41, 39, 41, 22, 50, 49, 14, 12, 12, 18, 55, 22, 22, 18, 30, 53, 41, 22, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    29, 7, 50, 43, 44, 13, 34, 41, 39, 12, 32, 50, 49, 44, 38, 8, 48, 55, 35, 31, 42, 9, 53, 41, 40, 50, 83,
+    20, 17, 50, 15, 44, 4, 20, 41, 39, 41, 21, 50, 49, 18, 12, 32, 18, 55, 29, 20, 21, 31, 53, 41, 32, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    19, 14, 50, 20, 44, 23, 20, 41, 39, 15, 22, 50, 49, 18, 12, 9, 25, 55, 22, 21, 20, 40, 53, 10, 25, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    16, 14, 50, 22, 44, 21, 18, 41, 39, 18, 19, 50, 49, 20, 14, 15, 26, 55, 21, 17, 23, 37, 53, 7, 23, 16, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    10, 36, 50, 38, 44, 24, 11, 41, 39, 41, 11, 50, 49, 44, 33, 37, 48, 55, 11, 10, 35, 40, 53, 41, 11, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    24, 8, 50, 18, 44, 40, 25, 41, 39, 16, 26, 50, 49, 18, 11, 17, 22, 55, 25, 25, 20, 19, 53, 10, 26, 18, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    20, 37, 50, 34, 44, 6, 20, 41, 39, 41, 20, 50, 49, 16, 11, 10, 20, 55, 20, 20, 16, 30, 53, 41, 21, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    20, 20, 50, 22, 44, 5, 20, 41, 39, 41, 21, 50, 49, 16, 11, 32, 22, 55, 29, 20, 21, 14, 53, 41, 32, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    24, 10, 50, 22, 44, 17, 25, 41, 39, 16, 25, 50, 49, 18, 13, 8, 38, 55, 35, 25, 22, 40, 53, 9, 38, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    23, 14, 50, 22, 44, 34, 32, 41, 39, 17, 32, 50, 49, 18, 13, 13, 22, 55, 34, 31, 22, 40, 53, 5, 25, 18, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    4, 36, 50, 38, 44, 24, 27, 41, 39, 41, 28, 50, 49, 44, 33, 37, 48, 55, 26, 4, 35, 40, 53, 41, 31, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    23, 8, 50, 17, 44, 40, 24, 41, 39, 15, 25, 50, 49, 30, 9, 18, 24, 55, 24, 24, 21, 18, 53, 12, 25, 18, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    18, 37, 50, 34, 44, 6, 18, 41, 39, 41, 19, 50, 49, 18, 14, 11, 19, 55, 19, 18, 18, 30, 53, 41, 19, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    18, 12, 50, 19, 44, 6, 18, 41, 39, 41, 18, 50, 49, 15, 12, 32, 19, 55, 18, 27, 15, 31, 53, 41, 32, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    22, 27, 50, 20, 44, 35, 23, 41, 39, 15, 34, 50, 49, 18, 11, 6, 24, 55, 24, 23, 20, 40, 53, 9, 24, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    22, 16, 50, 20, 44, 34, 23, 41, 39, 14, 23, 50, 49, 16, 14, 9, 24, 55, 23, 31, 20, 40, 53, 7, 24, 30, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    23, 36, 50, 38, 44, 24, 27, 41, 39, 41, 28, 50, 49, 44, 33, 37, 48, 55, 26, 25, 35, 40, 53, 41, 1, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    24, 8, 50, 21, 44, 40, 25, 41, 39, 16, 25, 50, 49, 19, 11, 16, 25, 55, 25, 24, 21, 21, 53, 10, 25, 15, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    1, 23, 50, 43, 20, 29, 43, 41, 39, 28, 42, 50, 49, 44, 38, 24, 48, 55, 42, 40, 42, 26, 53, 41, 46, 50, 83,
+    19, 37, 50, 34, 44, 4, 20, 41, 39, 41, 35, 50, 49, 16, 18, 12, 16, 55, 20, 20, 16, 30, 53, 41, 38, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    10, 15, 50, 43, 44, 40, 11, 41, 17, 27, 14, 50, 49, 44, 38, 14, 48, 55, 14, 12, 42, 17, 53, 41, 16, 50, 83,
+    12, 10, 50, 43, 44, 16, 14, 41, 39, 16, 17, 50, 49, 44, 38, 11, 48, 55, 15, 16, 42, 13, 53, 41, 20, 50, 83,
+    18, 15, 50, 16, 44, 6, 19, 41, 39, 41, 30, 50, 49, 35, 10, 32, 20, 55, 19, 27, 16, 13, 53, 41, 32, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    22, 22, 50, 20, 44, 35, 23, 41, 39, 16, 23, 50, 49, 20, 12, 6, 24, 55, 23, 23, 20, 40, 53, 10, 24, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    20, 20, 50, 18, 44, 34, 32, 41, 39, 17, 21, 50, 49, 21, 14, 11, 22, 55, 34, 31, 21, 40, 53, 4, 37, 30, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    19, 24, 50, 18, 44, 40, 19, 41, 39, 11, 21, 50, 49, 22, 7, 27, 29, 55, 19, 17, 17, 31, 53, 12, 23, 18, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    33, 27, 50, 43, 44, 40, 43, 41, 19, 41, 6, 50, 49, 44, 38, 29, 48, 55, 42, 3, 42, 29, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    11, 37, 50, 34, 44, 20, 11, 41, 39, 41, 16, 50, 49, 30, 13, 13, 34, 55, 18, 11, 10, 30, 53, 41, 38, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    9, 28, 50, 35, 44, 23, 10, 41, 39, 41, 12, 50, 49, 35, 28, 32, 40, 55, 11, 9, 34, 31, 53, 41, 15, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    8, 27, 50, 43, 44, 33, 8, 41, 39, 33, 13, 50, 49, 44, 38, 28, 48, 55, 13, 11, 42, 29, 53, 41, 14, 47, 83,
+    13, 32, 50, 43, 44, 36, 14, 3, 24, 37, 16, 50, 49, 44, 38, 33, 48, 55, 19, 15, 42, 35, 53, 41, 25, 50, 83,
+    18, 27, 50, 15, 44, 35, 21, 41, 39, 9, 23, 50, 49, 15, 6, 23, 20, 55, 20, 21, 15, 40, 53, 24, 22, 50, 83,
+    32, 35, 50, 43, 44, 39, 43, 41, 19, 39, 5, 50, 49, 44, 38, 37, 48, 55, 42, 4, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    32, 29, 50, 43, 44, 34, 43, 41, 23, 32, 5, 50, 49, 44, 38, 30, 48, 55, 25, 4, 42, 30, 53, 41, 46, 50, 83,
+    16, 14, 50, 22, 44, 21, 18, 41, 39, 18, 19, 50, 49, 20, 14, 15, 26, 55, 21, 17, 23, 37, 53, 7, 23, 16, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    17, 30, 50, 17, 44, 40, 18, 41, 39, 11, 20, 50, 49, 13, 7, 25, 18, 55, 19, 19, 14, 25, 53, 41, 22, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    7, 24, 50, 32, 44, 40, 35, 41, 39, 30, 36, 50, 49, 30, 7, 7, 35, 55, 35, 33, 32, 31, 53, 26, 38, 30, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    29, 37, 50, 34, 44, 0, 33, 41, 39, 41, 35, 50, 49, 30, 24, 29, 34, 55, 35, 33, 30, 30, 53, 41, 38, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    29, 7, 50, 43, 44, 12, 34, 41, 39, 11, 32, 50, 49, 44, 38, 9, 48, 55, 35, 31, 42, 10, 53, 41, 40, 50, 83,
+    4, 28, 50, 35, 44, 23, 28, 41, 39, 41, 30, 50, 49, 35, 5, 32, 40, 55, 29, 27, 34, 31, 53, 41, 32, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    29, 27, 50, 33, 44, 35, 33, 41, 39, 29, 34, 50, 49, 32, 25, 0, 38, 55, 35, 33, 33, 40, 53, 24, 38, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    16, 14, 50, 22, 44, 21, 18, 41, 39, 18, 19, 50, 49, 20, 14, 15, 26, 55, 21, 17, 23, 37, 53, 7, 23, 16, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    21, 10, 50, 19, 44, 40, 22, 41, 39, 17, 23, 50, 49, 16, 10, 19, 21, 55, 22, 21, 19, 17, 53, 12, 25, 16, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    19, 37, 50, 21, 44, 6, 20, 41, 39, 41, 23, 50, 49, 17, 10, 16, 20, 55, 22, 20, 16, 17, 53, 41, 24, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    13, 14, 50, 22, 44, 9, 14, 41, 39, 41, 16, 50, 49, 21, 15, 20, 27, 55, 15, 14, 21, 18, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    19, 14, 50, 20, 44, 23, 20, 41, 39, 15, 22, 50, 49, 18, 12, 9, 25, 55, 22, 21, 20, 40, 53, 10, 25, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    28, 4, 50, 35, 44, 10, 32, 41, 39, 16, 32, 50, 49, 34, 28, 19, 39, 55, 34, 31, 35, 40, 53, 22, 37, 11, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    23, 8, 50, 17, 44, 40, 24, 41, 39, 15, 27, 50, 49, 17, 11, 16, 22, 55, 24, 24, 22, 21, 53, 10, 28, 19, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    18, 37, 50, 34, 44, 4, 22, 41, 39, 41, 35, 50, 49, 18, 14, 10, 34, 55, 19, 22, 18, 30, 53, 41, 23, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    18, 13, 50, 15, 44, 8, 18, 41, 39, 41, 18, 50, 49, 22, 10, 32, 40, 55, 21, 18, 19, 15, 53, 41, 19, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    22, 27, 50, 19, 44, 35, 22, 41, 39, 16, 23, 50, 49, 19, 14, 6, 38, 55, 23, 26, 20, 40, 53, 7, 27, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    21, 16, 50, 19, 44, 34, 22, 41, 39, 19, 25, 50, 49, 19, 12, 13, 27, 55, 26, 22, 21, 40, 53, 5, 26, 19, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    24, 8, 50, 19, 44, 40, 25, 41, 39, 16, 25, 50, 49, 17, 11, 16, 19, 55, 25, 25, 19, 21, 53, 10, 25, 21, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    19, 37, 50, 20, 44, 4, 20, 41, 39, 41, 20, 50, 49, 16, 15, 13, 34, 55, 20, 33, 19, 30, 53, 41, 20, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    17, 14, 50, 18, 44, 8, 18, 41, 39, 41, 18, 50, 49, 18, 9, 32, 40, 55, 18, 18, 15, 31, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    22, 13, 50, 20, 44, 35, 23, 41, 39, 15, 24, 50, 49, 20, 14, 7, 38, 55, 24, 33, 20, 40, 53, 8, 38, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    1, 29, 50, 43, 44, 34, 43, 41, 23, 32, 24, 50, 49, 44, 38, 30, 48, 55, 25, 23, 42, 30, 53, 41, 46, 50, 83,
+    22, 17, 50, 20, 44, 34, 23, 41, 39, 17, 23, 50, 49, 17, 12, 14, 24, 55, 34, 31, 20, 40, 53, 5, 37, 16, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    24, 8, 50, 17, 44, 40, 25, 41, 39, 15, 25, 50, 49, 19, 10, 16, 21, 55, 25, 24, 21, 24, 53, 11, 25, 19, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    19, 37, 50, 34, 44, 5, 19, 41, 39, 41, 35, 50, 49, 15, 13, 13, 19, 55, 19, 19, 15, 30, 53, 41, 20, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    29, 8, 50, 43, 44, 13, 34, 41, 39, 11, 32, 50, 49, 44, 38, 9, 48, 55, 35, 31, 42, 8, 53, 41, 40, 50, 83,
+    20, 16, 50, 18, 44, 5, 21, 41, 39, 41, 21, 50, 49, 16, 10, 32, 40, 55, 21, 21, 18, 18, 53, 41, 32, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    22, 9, 50, 23, 44, 16, 23, 41, 39, 17, 34, 50, 49, 16, 17, 7, 24, 55, 24, 33, 20, 40, 53, 12, 38, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    17, 7, 50, 43, 44, 14, 43, 41, 39, 13, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    22, 14, 50, 21, 44, 17, 23, 41, 39, 20, 24, 50, 49, 16, 14, 13, 39, 55, 24, 23, 35, 40, 53, 7, 37, 11, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    14, 7, 50, 21, 44, 40, 16, 41, 39, 19, 17, 50, 49, 30, 13, 26, 35, 55, 18, 15, 24, 24, 53, 13, 23, 22, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    33, 27, 50, 43, 44, 40, 43, 41, 19, 41, 4, 50, 49, 44, 38, 29, 48, 55, 42, 5, 42, 29, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    21, 37, 50, 34, 44, 3, 21, 41, 39, 41, 35, 50, 49, 30, 16, 14, 34, 55, 21, 21, 17, 30, 53, 41, 22, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    10, 28, 50, 35, 44, 23, 9, 41, 39, 41, 11, 50, 49, 35, 28, 32, 40, 55, 11, 11, 21, 31, 53, 41, 13, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 27, 50, 43, 44, 33, 8, 41, 39, 33, 11, 50, 49, 44, 38, 28, 48, 55, 13, 9, 42, 29, 53, 41, 14, 47, 83,
+    13, 32, 50, 43, 44, 36, 14, 3, 24, 37, 19, 50, 49, 44, 38, 33, 48, 55, 16, 19, 42, 35, 53, 41, 20, 50, 83,
+    29, 27, 50, 33, 44, 35, 33, 41, 39, 29, 34, 50, 49, 32, 25, 1, 38, 55, 35, 33, 33, 40, 53, 18, 38, 50, 83,
+    32, 35, 50, 43, 44, 39, 43, 41, 19, 39, 4, 50, 49, 44, 38, 37, 48, 55, 42, 5, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 26, 33, 53, 41, 46, 50, 83,
+    32, 29, 50, 43, 44, 34, 43, 41, 23, 32, 5, 50, 49, 44, 38, 30, 48, 55, 25, 4, 42, 30, 53, 41, 46, 50, 83,
+    28, 27, 50, 35, 44, 34, 32, 41, 39, 31, 32, 50, 49, 34, 28, 28, 39, 55, 34, 31, 35, 40, 53, 22, 1, 30, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    19, 12, 50, 15, 44, 40, 20, 41, 39, 25, 23, 50, 49, 28, 11, 6, 34, 55, 22, 19, 19, 18, 53, 41, 24, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    12, 26, 50, 21, 44, 4, 18, 41, 39, 41, 16, 50, 49, 44, 16, 37, 48, 55, 14, 17, 17, 40, 53, 41, 22, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    23, 8, 50, 16, 44, 40, 24, 41, 39, 14, 24, 50, 49, 23, 10, 20, 35, 55, 24, 24, 20, 31, 53, 11, 24, 16, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    17, 37, 50, 34, 44, 5, 18, 41, 39, 41, 35, 50, 49, 30, 13, 11, 34, 55, 18, 17, 14, 30, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    18, 12, 50, 35, 44, 6, 18, 41, 39, 41, 18, 50, 49, 35, 11, 32, 40, 55, 18, 18, 15, 18, 53, 41, 19, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    21, 27, 50, 21, 44, 35, 21, 41, 39, 17, 22, 50, 49, 21, 12, 7, 38, 55, 22, 21, 18, 40, 53, 7, 22, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    19, 19, 50, 35, 44, 34, 20, 41, 39, 16, 20, 50, 49, 34, 10, 16, 39, 55, 34, 20, 17, 40, 53, 5, 21, 30, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    10, 24, 50, 32, 44, 40, 13, 41, 39, 30, 12, 50, 49, 30, 24, 31, 35, 55, 11, 9, 32, 31, 53, 26, 12, 30, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    25, 27, 50, 43, 44, 40, 43, 41, 19, 41, 5, 50, 49, 44, 38, 29, 48, 55, 42, 4, 42, 29, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    19, 37, 50, 21, 44, 6, 20, 41, 39, 41, 23, 50, 49, 17, 10, 16, 20, 55, 22, 20, 16, 17, 53, 41, 24, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    8, 28, 50, 35, 44, 23, 9, 41, 39, 41, 13, 50, 49, 35, 23, 32, 40, 55, 11, 9, 34, 31, 53, 41, 19, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    7, 27, 50, 43, 44, 33, 9, 41, 39, 33, 11, 50, 49, 44, 38, 28, 48, 55, 17, 9, 42, 29, 53, 41, 19, 47, 83,
+    14, 32, 50, 43, 44, 36, 13, 3, 24, 37, 16, 50, 49, 44, 38, 33, 48, 55, 20, 15, 42, 35, 53, 41, 20, 50, 83,
+    29, 27, 50, 33, 44, 35, 33, 41, 39, 29, 34, 50, 49, 32, 25, 23, 38, 55, 35, 33, 33, 40, 53, 1, 38, 50, 83,
+    32, 35, 50, 43, 44, 39, 43, 41, 19, 39, 6, 50, 49, 44, 38, 37, 48, 55, 42, 3, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    32, 29, 50, 43, 44, 34, 43, 41, 23, 32, 6, 50, 49, 44, 38, 30, 48, 55, 25, 3, 42, 30, 53, 41, 46, 50, 83,
+    6, 27, 50, 35, 44, 34, 15, 41, 39, 31, 10, 50, 49, 34, 28, 28, 39, 55, 13, 11, 35, 40, 53, 22, 15, 30, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    20, 9, 50, 14, 44, 40, 20, 41, 39, 20, 21, 50, 49, 20, 15, 11, 35, 55, 20, 20, 20, 31, 53, 11, 21, 16, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    16, 37, 50, 34, 44, 8, 33, 41, 39, 41, 35, 50, 49, 16, 12, 9, 13, 55, 17, 17, 16, 30, 53, 41, 38, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    17, 18, 50, 18, 44, 7, 18, 41, 39, 41, 18, 50, 49, 35, 12, 32, 40, 55, 18, 18, 15, 11, 53, 41, 32, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    21, 8, 50, 22, 44, 18, 33, 41, 39, 21, 34, 50, 49, 22, 15, 8, 22, 55, 22, 22, 22, 40, 53, 10, 38, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    19, 13, 50, 21, 44, 20, 20, 41, 39, 17, 32, 50, 49, 21, 20, 13, 21, 55, 34, 20, 35, 40, 53, 6, 37, 12, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    1, 24, 50, 32, 44, 40, 35, 41, 39, 30, 36, 50, 49, 30, 24, 31, 35, 55, 35, 33, 32, 31, 53, 26, 38, 30, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    33, 27, 50, 43, 44, 40, 43, 41, 0, 41, 26, 50, 49, 44, 38, 29, 48, 55, 42, 24, 42, 29, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    29, 37, 50, 34, 44, 0, 33, 41, 39, 41, 35, 50, 49, 30, 24, 29, 34, 55, 35, 33, 30, 30, 53, 41, 38, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    13, 14, 50, 22, 44, 9, 14, 41, 39, 41, 16, 50, 49, 21, 15, 20, 27, 55, 15, 14, 21, 18, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    26, 32, 50, 43, 44, 36, 29, 20, 1, 37, 31, 50, 49, 44, 38, 33, 48, 55, 33, 30, 42, 35, 53, 41, 36, 50, 83,
+    19, 14, 50, 20, 44, 23, 20, 41, 39, 15, 22, 50, 49, 18, 12, 9, 25, 55, 22, 21, 20, 40, 53, 10, 25, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    0, 26, 50, 43, 44, 31, 43, 41, 39, 31, 42, 50, 49, 44, 38, 27, 48, 55, 42, 40, 42, 27, 53, 41, 46, 50, 83,
+    0, 30, 50, 43, 44, 35, 43, 41, 39, 36, 42, 50, 49, 44, 38, 31, 48, 55, 42, 40, 42, 33, 53, 41, 46, 50, 83,
+    32, 29, 50, 43, 44, 34, 43, 41, 23, 32, 10, 50, 49, 44, 38, 30, 48, 55, 25, 2, 42, 30, 53, 41, 46, 50, 83,
+    16, 14, 50, 22, 44, 21, 18, 41, 39, 18, 19, 50, 49, 20, 14, 15, 26, 55, 21, 17, 23, 37, 53, 7, 23, 16, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    0, 29, 50, 43, 44, 33, 43, 41, 39, 32, 42, 50, 49, 44, 38, 28, 48, 55, 42, 40, 42, 30, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+    33, 26, 17, 14, 14, 29, 43, 17, 17, 32, 42, 17, 16, 18, 12, 24, 20, 22, 18, 18, 12, 26, 20, 41, 21, 35, 83,
+    21, 10, 50, 19, 44, 40, 22, 41, 39, 17, 23, 50, 49, 16, 10, 19, 21, 55, 22, 21, 19, 17, 53, 12, 25, 16, 83,
+    33, 7, 50, 43, 44, 11, 43, 41, 39, 12, 42, 50, 33, 44, 38, 8, 48, 55, 42, 40, 42, 9, 53, 41, 46, 50, 83,
+    31, 14, 50, 43, 44, 40, 43, 41, 5, 41, 12, 50, 49, 44, 38, 15, 48, 55, 42, 10, 42, 15, 53, 41, 46, 50, 83,
+    31, 9, 50, 43, 6, 16, 43, 41, 39, 14, 42, 50, 49, 44, 38, 10, 48, 55, 42, 40, 42, 12, 53, 41, 46, 50, 83,
+    19, 37, 50, 21, 44, 6, 20, 41, 39, 41, 23, 50, 49, 17, 10, 16, 20, 55, 22, 20, 16, 17, 53, 41, 24, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    11, 13, 50, 43, 44, 37, 12, 41, 16, 13, 16, 50, 49, 44, 38, 13, 48, 55, 15, 13, 42, 17, 53, 41, 17, 50, 83,
+    19, 9, 50, 43, 44, 13, 21, 41, 39, 13, 19, 50, 49, 44, 38, 9, 48, 55, 23, 18, 42, 10, 53, 41, 27, 50, 83,
+    13, 14, 50, 22, 44, 9, 14, 41, 39, 41, 16, 50, 49, 21, 15, 20, 27, 55, 15, 14, 21, 18, 53, 41, 18, 50, 83,
+    0, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 11, 43, 41, 2, 12, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 40, 53, 41, 46, 23, 83,
+    33, 8, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 8, 48, 55, 42, 40, 42, 8, 53, 41, 46, 30, 83,
+    10, 14, 50, 43, 44, 20, 11, 41, 39, 20, 14, 50, 49, 44, 38, 15, 48, 55, 15, 11, 42, 15, 53, 41, 17, 38, 83,
+    14, 20, 50, 43, 41, 25, 15, 6, 9, 26, 17, 50, 49, 44, 38, 21, 48, 55, 19, 16, 42, 24, 53, 41, 22, 50, 83,
+    19, 14, 50, 20, 44, 23, 20, 41, 39, 15, 22, 50, 49, 18, 12, 9, 25, 55, 22, 21, 20, 40, 53, 10, 25, 50, 83,
+    30, 27, 50, 43, 44, 33, 43, 41, 5, 30, 8, 50, 49, 44, 38, 32, 48, 55, 42, 7, 42, 40, 53, 41, 46, 50, 83,
+    33, 37, 50, 43, 44, 40, 43, 41, 39, 41, 42, 50, 49, 44, 38, 37, 48, 55, 42, 40, 42, 0, 53, 41, 46, 50, 83,
+    3, 12, 50, 43, 44, 18, 43, 41, 39, 18, 42, 50, 49, 44, 38, 14, 48, 55, 42, 40, 42, 14, 53, 41, 46, 50, 83,
+    1, 18, 50, 43, 44, 24, 43, 41, 39, 25, 42, 50, 49, 44, 38, 19, 48, 55, 42, 40, 39, 20, 53, 41, 46, 50, 83,
+    29, 16, 50, 43, 44, 21, 43, 41, 9, 19, 10, 50, 49, 44, 38, 17, 48, 55, 11, 9, 42, 16, 53, 41, 46, 50, 83,
+    16, 14, 50, 22, 44, 21, 18, 41, 39, 18, 19, 50, 49, 20, 14, 15, 26, 55, 21, 17, 23, 37, 53, 7, 23, 16, 83,
+    33, 6, 50, 43, 44, 13, 43, 41, 39, 12, 42, 50, 49, 44, 38, 9, 48, 55, 42, 40, 42, 10, 53, 41, 46, 50, 83,
+    20, 18, 50, 15, 44, 40, 21, 41, 39, 16, 23, 50, 49, 14, 7, 11, 20, 55, 22, 21, 15, 22, 53, 41, 24, 50, 83,
+    2, 16, 50, 43, 44, 20, 43, 41, 39, 18, 42, 50, 49, 44, 38, 15, 48, 55, 42, 40, 42, 16, 53, 41, 46, 50, 83,
+    9, 31, 50, 26, 44, 9, 13, 41, 39, 41, 14, 50, 49, 44, 21, 37, 48, 55, 12, 11, 22, 40, 53, 41, 17, 50, 83,
+    10, 15, 27, 21, 21, 17, 21, 19, 16, 19, 20, 27, 26, 21, 15, 15, 25, 32, 20, 17, 19, 18, 30, 19, 24, 27, 60,
+];
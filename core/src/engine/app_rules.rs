@@ -0,0 +1,201 @@
+//! Per-Application Rules - Settings overrides evaluated in the core
+//!
+//! Frontends report the focused application via `Engine::set_app` and the
+//! engine picks the first matching rule. A rule overrides any subset of
+//! `EngineSettings`, for example:
+//! - KeePass: `{"enabled": false}`
+//! - Excel: `{"method": 1}` (VNI)
+//! - Terminals: `{"auto_capitalize": false}`
+//! - IDE search box (matched by window title): `{"enabled": false}`
+//!
+//! Patterns are case-insensitive globs (`*` = any run, `?` = one char),
+//! matched against the whole app id (process name, bundle id or WM_CLASS)
+//! and optionally the window title.
+
+use super::json::{self, Value};
+use super::settings::EngineSettings;
+
+/// A single per-application rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppRule {
+    /// Display name (reported to the tray UI when matched)
+    pub name: String,
+    /// Glob pattern for the app id
+    pub app: String,
+    /// Optional glob pattern for the window title (None = any title)
+    pub title: Option<String>,
+    /// Partial settings object applied on top of the user's settings
+    pub overrides: Value,
+}
+
+impl AppRule {
+    /// Create a rule. `overrides` is a partial settings object.
+    pub fn new(name: &str, app: &str, overrides: Value) -> Self {
+        Self {
+            name: name.to_string(),
+            app: app.to_string(),
+            title: None,
+            overrides,
+        }
+    }
+
+    /// Also require the window title to match a glob pattern
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Check if rule matches the given app id and window title
+    pub fn matches(&self, app_id: &str, window_title: &str) -> bool {
+        glob_match(&self.app, app_id)
+            && self
+                .title
+                .as_ref()
+                .is_none_or(|t| glob_match(t, window_title))
+    }
+
+    /// Apply this rule's overrides to base settings
+    pub fn apply(&self, base: &EngineSettings) -> Option<EngineSettings> {
+        base.with_overrides(&self.overrides)
+    }
+
+    fn to_value(&self) -> Value {
+        let mut fields = vec![
+            ("name".to_string(), Value::String(self.name.clone())),
+            ("app".to_string(), Value::String(self.app.clone())),
+        ];
+        if let Some(title) = &self.title {
+            fields.push(("title".to_string(), Value::String(title.clone())));
+        }
+        fields.push(("settings".to_string(), self.overrides.clone()));
+        Value::Object(fields)
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let app = value.get("app")?.as_str()?;
+        let name = match value.get("name") {
+            Some(n) => n.as_str()?,
+            None => app,
+        };
+        let title = match value.get("title") {
+            None | Some(Value::Null) => None,
+            Some(t) => Some(t.as_str()?.to_string()),
+        };
+        let overrides = value
+            .get("settings")
+            .cloned()
+            .unwrap_or(Value::Object(vec![]));
+        let rule = Self {
+            name: name.to_string(),
+            app: app.to_string(),
+            title,
+            overrides,
+        };
+        // Validate overrides up front so bad rules are rejected on import
+        rule.apply(&EngineSettings::default())?;
+        Some(rule)
+    }
+}
+
+/// Export rules as a JSON array (priority order)
+pub fn rules_to_json(rules: &[AppRule]) -> String {
+    Value::Array(rules.iter().map(|r| r.to_value()).collect()).to_json()
+}
+
+/// Import rules from a JSON array:
+/// `[{"name":"KeePass","app":"keepass*","title":"*","settings":{"enabled":false}}]`
+///
+/// `name` defaults to the app pattern, `title` is optional.
+/// Returns None if any rule is invalid.
+pub fn rules_from_json(input: &str) -> Option<Vec<AppRule>> {
+    json::parse(input)?
+        .as_array()?
+        .iter()
+        .map(AppRule::from_value)
+        .collect()
+}
+
+/// Case-insensitive glob match: `*` matches any run, `?` matches one char
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.to_lowercase().chars().collect();
+    let t: Vec<char> = text.to_lowercase().chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    // Position after the last '*' and the text position it matched up to
+    let mut star: Option<(usize, usize)> = None;
+
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi + 1, ti));
+            pi += 1;
+        } else if let Some((star_p, star_t)) = star {
+            // Backtrack: let the last '*' swallow one more char
+            pi = star_p;
+            ti = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("keepass*", "KeePass.exe"));
+        assert!(glob_match("*excel*", "com.microsoft.Excel"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("te?m", "Term"));
+        assert!(glob_match("*term*", "gnome-terminal-server"));
+        assert!(glob_match("a*b*c", "aXXbYYc"));
+        assert!(!glob_match("a*b*c", "aXXbYY"));
+        assert!(!glob_match("code", "vscode"));
+        assert!(!glob_match("", "x"));
+    }
+
+    #[test]
+    fn test_rule_matches_title() {
+        let rule =
+            AppRule::new("IDE search", "code*", Value::Object(vec![])).with_title("*Search*");
+        assert!(rule.matches("Code.exe", "Search - fkey"));
+        assert!(!rule.matches("Code.exe", "main.rs - fkey"));
+        assert!(!rule.matches("slack", "Search"));
+    }
+
+    #[test]
+    fn test_rules_json_roundtrip() {
+        let input = r#"[{"name":"KeePass","app":"keepass*","settings":{"enabled":false}},{"app":"*excel*","title":"*.xlsx","settings":{"method":1}}]"#;
+        let rules = rules_from_json(input).unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[1].name, "*excel*");
+        assert_eq!(rules_from_json(&rules_to_json(&rules)), Some(rules.clone()));
+
+        let vni = rules[1].apply(&EngineSettings::default()).unwrap();
+        assert_eq!(vni.method, 1);
+    }
+
+    #[test]
+    fn test_numeric_overrides() {
+        let input = r#"[{"app":"mstsc*","settings":{"output_mode":2}},{"app":"code*","settings":{"auto_restore_level":3}}]"#;
+        let rules = rules_from_json(input).unwrap();
+        let base = EngineSettings::default();
+        assert_eq!(rules[0].apply(&base).unwrap().output_mode, 2);
+        assert_eq!(rules[1].apply(&base).unwrap().auto_restore_level, 3);
+    }
+
+    #[test]
+    fn test_invalid_rules_rejected() {
+        assert_eq!(rules_from_json(r#"{"app":"x"}"#), None);
+        assert_eq!(rules_from_json(r#"[{"name":"no app"}]"#), None);
+        assert_eq!(
+            rules_from_json(r#"[{"app":"x","settings":{"enabled":"no"}}]"#),
+            None
+        );
+    }
+}
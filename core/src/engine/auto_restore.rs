@@ -0,0 +1,117 @@
+//! Auto-Restore Levels - How eagerly English words are restored
+//!
+//! At a word boundary the engine decides whether the Vietnamese it built
+//! (`tẽt`) goes back to the keys typed (`text`). Each level enables a
+//! wider set of decision points:
+//!
+//! - `Off`: never restore
+//! - `Dictionary`: word end only, and only to a word of the English
+//!   dictionary or the Telex doubles whitelist (`text`, `poor`)
+//! - `Balanced`: invalid Vietnamese typed as English-looking keys
+//!   (`is_buffer_invalid_vietnamese` + `is_raw_input_valid_english`),
+//!   mid-word English patterns and the n-gram classifier
+//! - `Aggressive`: also dictionary words that are valid Vietnamese when
+//!   the keys look more English (`bus` over `bú`), except the common
+//!   words of the `COMMON_*` tables (`à`, `ồ`, `bê`)
+
+use super::buffer::Char;
+use crate::data::chars::tone;
+use crate::data::{constants, english_dict, keys, telex_doubles};
+
+/// English auto-restore level
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AutoRestoreLevel {
+    /// Never restore
+    Off = 0,
+    /// Restore only to dictionary or whitelist words
+    Dictionary = 1,
+    /// Restore invalid Vietnamese that looks English (the former `true`)
+    Balanced = 2,
+    /// Prefer English dictionary words over valid Vietnamese
+    Aggressive = 3,
+}
+
+impl AutoRestoreLevel {
+    pub fn from_u8(level: u8) -> Option<Self> {
+        match level {
+            0 => Some(Self::Off),
+            1 => Some(Self::Dictionary),
+            2 => Some(Self::Balanced),
+            3 => Some(Self::Aggressive),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `raw` is a known English word (dictionary or Telex doubles whitelist)
+pub(crate) fn is_known_english(raw: &[char]) -> bool {
+    let word: String = raw.iter().collect::<String>().to_lowercase();
+    english_dict::is_english_word(&word) || telex_doubles::contains(&word)
+}
+
+/// Whether `word` is one of the common Vietnamese words never restored,
+/// even by `Aggressive`: interjections (à, ý), circumflex + mark (ồ, ế)
+/// and consonant + circumflex (bê, mê)
+pub(crate) fn is_common_vietnamese(word: &[Char]) -> bool {
+    match word {
+        [v] if v.tone == tone::NONE => {
+            constants::COMMON_SINGLE_VOWEL_WORDS.contains(&(v.key, v.mark))
+        }
+        [v] => constants::COMMON_CIRCUMFLEX_VOWEL_WITH_MARK.contains(&(v.key, v.tone, v.mark)),
+        [c, v] => {
+            keys::is_consonant(c.key)
+                && v.tone == tone::CIRCUMFLEX
+                && v.mark == 0
+                && constants::COMMON_CIRCUMFLEX_NO_FINAL.contains(&c.key)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::chars::parse_char;
+
+    fn word(s: &str) -> Vec<Char> {
+        s.chars()
+            .filter_map(parse_char)
+            .map(|p| {
+                let mut c = Char::new(p.key, p.caps);
+                c.tone = p.tone;
+                c.mark = p.mark;
+                c.stroke = p.stroke;
+                c
+            })
+            .collect()
+    }
+
+    #[test]
+    fn levels() {
+        assert_eq!(
+            AutoRestoreLevel::from_u8(2),
+            Some(AutoRestoreLevel::Balanced)
+        );
+        assert_eq!(AutoRestoreLevel::from_u8(4), None);
+        assert!(AutoRestoreLevel::Dictionary < AutoRestoreLevel::Balanced);
+    }
+
+    #[test]
+    fn common_words() {
+        for w in ["à", "ý", "ồ", "ế", "bê", "đê"] {
+            assert!(is_common_vietnamese(&word(w)), "{w}");
+        }
+        for w in ["ó", "bít", "sê", "bế"] {
+            assert!(!is_common_vietnamese(&word(w)), "{w}");
+        }
+    }
+
+    #[test]
+    fn known_english() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert!(is_known_english(&chars("Text")));
+        assert!(is_known_english(&chars("poor")));
+        assert!(!is_known_english(&chars("vieejt")));
+    }
+}
@@ -2,6 +2,7 @@
 
 pub const MAX: usize = 256;
 
+use super::wipe::wipe_slice;
 use crate::utils;
 
 /// Single character in buffer
@@ -44,6 +45,8 @@ impl Char {
 pub struct Buffer {
     data: [Char; MAX],
     len: usize,
+    /// Caret index inside the word (None = at the end, follows pushes)
+    caret: Option<usize>,
 }
 
 impl Default for Buffer {
@@ -57,6 +60,7 @@ impl Buffer {
         Self {
             data: [Char::default(); MAX],
             len: 0,
+            caret: None,
         }
     }
 
@@ -78,6 +82,13 @@ impl Buffer {
 
     pub fn clear(&mut self) {
         self.len = 0;
+        self.caret = None;
+    }
+
+    /// Clear and overwrite all slots, including chars past `len`
+    pub fn wipe(&mut self) {
+        wipe_slice(&mut self.data, Char::default());
+        self.clear();
     }
 
     pub fn len(&self) -> usize {
@@ -123,6 +134,32 @@ impl Buffer {
         }
     }
 
+    /// Insert element at index, shifting subsequent elements right
+    pub fn insert(&mut self, index: usize, c: Char) {
+        if index <= self.len && self.len < MAX {
+            for i in (index..self.len).rev() {
+                self.data[i + 1] = self.data[i];
+            }
+            self.data[index] = c;
+            self.len += 1;
+        }
+    }
+
+    /// Caret index (0..=len); equals len when the caret is at the end
+    pub fn caret(&self) -> usize {
+        self.caret.map_or(self.len, |c| c.min(self.len))
+    }
+
+    /// Check if caret is inside the word (not at the end)
+    pub fn caret_inside(&self) -> bool {
+        self.caret() < self.len
+    }
+
+    /// Move caret to index (clamped); moving to len puts it back at the end
+    pub fn set_caret(&mut self, index: usize) {
+        self.caret = (index < self.len).then_some(index);
+    }
+
     /// Find indices of vowels in buffer
     pub fn find_vowels(&self) -> Vec<usize> {
         use crate::data::keys;
@@ -204,4 +241,36 @@ mod tests {
         buf.clear();
         assert!(buf.is_empty());
     }
+
+    #[test]
+    fn test_caret() {
+        let mut buf = Buffer::new();
+        buf.push(Char::new(0, false));
+        buf.push(Char::new(1, false));
+        assert_eq!(buf.caret(), 2);
+        assert!(!buf.caret_inside());
+
+        buf.set_caret(1);
+        buf.insert(1, Char::new(2, false));
+        assert_eq!(buf.iter().map(|c| c.key).collect::<Vec<_>>(), [0, 2, 1]);
+        assert!(buf.caret_inside());
+
+        buf.set_caret(3);
+        buf.push(Char::new(3, false));
+        assert_eq!(buf.caret(), 4, "Caret at the end follows pushes");
+    }
+
+    #[test]
+    fn test_wipe() {
+        let mut buf = Buffer::new();
+        buf.push(Char::new(7, true));
+        buf.push(Char::new(8, false));
+        buf.pop();
+        buf.wipe();
+        assert!(buf.is_empty());
+        assert!(
+            buf.data.iter().all(|c| c.key == 0 && !c.caps),
+            "Popped chars too"
+        );
+    }
 }
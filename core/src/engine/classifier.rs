@@ -0,0 +1,145 @@
+//! Word Classifier - Character trigram scores for English vs Vietnamese
+//!
+//! Two trigram models over keystrokes (a-z plus a word boundary): one
+//! trained on English words as typed, one on Vietnamese syllables spelled
+//! in canonical Telex (`việt` → `vieetj`: modifier keys right after their
+//! letter, the mark key last). Scoring the raw keystrokes with the first
+//! and the buffer's canonical Telex with the second tells how much more
+//! English than Vietnamese a word looks.
+//!
+//! The tables (`data::ngram`) are generated offline from the English
+//! dictionary and the Vietnamese 22k word list by the ignored `train_tables` test
+//! in `tests/classifier_test.rs`. Entries are -log2(p) in quarter bits.
+
+use super::buffer::Char;
+use crate::data::chars::{mark, tone};
+use crate::data::{keys, ngram};
+use crate::utils;
+
+/// Symbols per position: word boundary (0) and a..z (1..=26)
+pub const SYMBOLS: usize = 27;
+
+/// Table entries per bit of -log2(p)
+pub const COST_SCALE: f32 = 4.0;
+
+/// Margin (bits per key) above which auto-restore trusts the classifier
+///
+/// Tuned on the corpus tests: at 1.5 no Vietnamese 22k syllable scores
+/// above it; at 1.25 Vietnamese-first engine tests start to restore.
+pub const RESTORE_MARGIN: f32 = 1.5;
+
+/// Shortest word (in keys) the classifier decides: fewer trigrams are noise
+pub const MIN_KEYS: usize = 4;
+
+/// Model symbol for a letter key (None for digits, punctuation, ...)
+pub fn symbol(key: u16) -> Option<u8> {
+    let c = utils::key_to_char(key, false)?;
+    c.is_ascii_lowercase().then(|| c as u8 - b'a' + 1)
+}
+
+/// Index of `c` following `a`, `b` in a trigram table
+pub fn trigram_index(a: u8, b: u8, c: u8) -> usize {
+    (a as usize * SYMBOLS + b as usize) * SYMBOLS + c as usize
+}
+
+/// Canonical Telex keys for a Vietnamese word: `đường` → `dduwowngf`
+///
+/// Each letter is followed by its modifier key (dd, aa/ee/oo, w), and the
+/// mark key (s/f/r/x/j) comes last, as the Vietnamese model was trained.
+pub fn telex_keys<'a>(chars: impl IntoIterator<Item = &'a Char>) -> Vec<u16> {
+    let mut out = Vec::new();
+    let mut mark_key = None;
+    for c in chars {
+        out.push(c.key);
+        if c.stroke {
+            out.push(keys::D);
+        }
+        match c.tone {
+            tone::CIRCUMFLEX => out.push(c.key),
+            tone::HORN => out.push(keys::W),
+            _ => {}
+        }
+        mark_key = match c.mark {
+            mark::SAC => Some(keys::S),
+            mark::HUYEN => Some(keys::F),
+            mark::HOI => Some(keys::R),
+            mark::NGA => Some(keys::X),
+            mark::NANG => Some(keys::J),
+            _ => mark_key,
+        };
+    }
+    out.extend(mark_key);
+    out
+}
+
+/// Average cost per symbol of `keys` (boundaries included) in bits
+///
+/// None if a key is not a letter.
+pub fn cost(table: &[u8], keys: &[u16]) -> Option<f32> {
+    let (mut a, mut b) = (0, 0);
+    let mut total = 0u32;
+    for &key in keys {
+        let c = symbol(key)?;
+        total += table[trigram_index(a, b, c)] as u32;
+        (a, b) = (b, c);
+    }
+    total += table[trigram_index(a, b, 0)] as u32;
+    Some(total as f32 / COST_SCALE / (keys.len() + 1) as f32)
+}
+
+/// How much more English than Vietnamese a word looks, in bits per key
+///
+/// Scores `raw` (keystrokes) with the English model and the Telex
+/// spelling of `word` (what the buffer shows) with the Vietnamese model.
+/// Positive = English is more likely. None for words with non-letters.
+pub fn english_margin<'a>(raw: &[u16], word: impl IntoIterator<Item = &'a Char>) -> Option<f32> {
+    let english = cost(&ngram::ENGLISH, raw)?;
+    let vietnamese = cost(&ngram::VIETNAMESE, &telex_keys(word))?;
+    Some(vietnamese - english)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::chars::parse_char;
+
+    fn word(s: &str) -> Vec<Char> {
+        s.chars()
+            .filter_map(parse_char)
+            .map(|p| {
+                let mut c = Char::new(p.key, p.caps);
+                c.tone = p.tone;
+                c.mark = p.mark;
+                c.stroke = p.stroke;
+                c
+            })
+            .collect()
+    }
+
+    fn keys_of(s: &str) -> Vec<u16> {
+        s.chars().map(utils::char_to_key).collect()
+    }
+
+    #[test]
+    fn canonical_telex() {
+        let telex = |s: &str| -> String {
+            telex_keys(&word(s))
+                .into_iter()
+                .filter_map(|k| utils::key_to_char(k, false))
+                .collect()
+        };
+        assert_eq!(telex("đường"), "dduwowngf");
+        assert_eq!(telex("việt"), "vieetj");
+        assert_eq!(telex("năm"), "nawm");
+    }
+
+    #[test]
+    fn margins() {
+        let margin = |raw: &str, shown: &str| english_margin(&keys_of(raw), &word(shown)).unwrap();
+        assert!(margin("text", "tẽt") > 0.0);
+        assert!(margin("street", "strêt") > 0.0);
+        assert!(margin("vieejt", "việt") < 0.0);
+        assert!(margin("dduwowngf", "đường") < 0.0);
+        assert_eq!(english_margin(&keys_of("a1"), &word("a1")), None);
+    }
+}
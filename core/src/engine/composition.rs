@@ -0,0 +1,296 @@
+//! Composition Output - Preedit + commit instead of backspace diffs
+//!
+//! The default output is a diff against the screen: `Result.backspace`
+//! deletes followed by `Result.chars`. Injecting backspaces is fragile in
+//! Electron apps, terminals and games, while IME frameworks (IBus, Fcitx,
+//! TSF) prefer a preedit string shown inline plus a commit at the end.
+//!
+//! `Preedit` translates each diff into that shape. It mirrors the text the
+//! diff output would have put on screen for the current word, so
+//! committed text + preedit always equals what the diff mode would show.
+//! At a word boundary (the engine buffer is empty after the key) the
+//! preedit is committed, including shortcut expansions and auto-restored
+//! words.
+
+use super::buffer::MAX;
+use super::wipe::wipe_vec;
+use super::{Action, Result, FLAG_KEY_CONSUMED};
+
+/// How key results are delivered to the frontend
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputMode {
+    /// Backspace + replacement chars (`ime_key_ext`)
+    Diff = 0,
+    /// Preedit string + commit text (`ime_key_composed`)
+    Preedit = 1,
+    /// Whole words only, sent at word boundaries (`ime_key_ext`).
+    /// For apps that drop or reorder synthetic backspaces.
+    CommitOnly = 2,
+}
+
+impl OutputMode {
+    pub fn from_u8(mode: u8) -> Option<Self> {
+        match mode {
+            0 => Some(Self::Diff),
+            1 => Some(Self::Preedit),
+            2 => Some(Self::CommitOnly),
+            _ => None,
+        }
+    }
+}
+
+/// Composition result for FFI
+#[repr(C)]
+pub struct Composition {
+    /// Current composed word (shown inline, not yet in the document)
+    pub preedit: [u32; MAX],
+    /// Text to insert into the document before updating the preedit
+    pub commit: [u32; MAX],
+    pub preedit_len: u8,
+    /// Caret position inside the preedit (in chars)
+    pub caret: u8,
+    pub commit_len: u8,
+    /// Chars of already-committed text to delete before the commit
+    /// (e.g. shortcut triggers typed outside the preedit, backspace-after-space)
+    pub delete_before: u8,
+    /// Flags byte:
+    /// - bit 0 (0x01): key_consumed - if set, the key should NOT be passed through
+    pub flags: u8,
+}
+
+impl Composition {
+    fn new(
+        preedit: &[char],
+        caret: usize,
+        commit: &[char],
+        delete_before: u8,
+        consumed: bool,
+    ) -> Self {
+        let mut result = Self {
+            preedit: [0; MAX],
+            commit: [0; MAX],
+            preedit_len: preedit.len().min(MAX) as u8,
+            caret: caret.min(MAX) as u8,
+            commit_len: commit.len().min(MAX) as u8,
+            delete_before,
+            flags: if consumed { FLAG_KEY_CONSUMED } else { 0 },
+        };
+        for (i, &c) in preedit.iter().take(MAX).enumerate() {
+            result.preedit[i] = c as u32;
+        }
+        for (i, &c) in commit.iter().take(MAX).enumerate() {
+            result.commit[i] = c as u32;
+        }
+        result
+    }
+
+    /// Check if key was consumed (should not be passed through)
+    pub fn key_consumed(&self) -> bool {
+        self.flags & FLAG_KEY_CONSUMED != 0
+    }
+
+    pub fn preedit_string(&self) -> String {
+        to_string(&self.preedit[..self.preedit_len as usize])
+    }
+
+    pub fn commit_string(&self) -> String {
+        to_string(&self.commit[..self.commit_len as usize])
+    }
+
+    /// Commit `text` before this composition's own commit
+    pub(crate) fn prepend_commit(&mut self, text: &[char]) {
+        let mut commit: Vec<char> = text.to_vec();
+        commit.extend(self.commit_string().chars());
+        self.commit = [0; MAX];
+        for (i, &c) in commit.iter().take(MAX).enumerate() {
+            self.commit[i] = c as u32;
+        }
+        self.commit_len = commit.len().min(MAX) as u8;
+    }
+}
+
+fn to_string(chars: &[u32]) -> String {
+    chars.iter().filter_map(|&c| char::from_u32(c)).collect()
+}
+
+/// How the host would have handled the key in diff mode
+pub(crate) struct KeyInfo {
+    /// Char the key types when passed through (None for Enter, Tab, arrows, ...)
+    pub typed: Option<char>,
+    /// Key is passed through to the app after the diff is applied
+    pub passes_through: bool,
+    /// Key is Backspace
+    pub is_delete: bool,
+    /// Engine still holds a word after the key (false = word boundary)
+    pub composing: bool,
+    /// Engine caret inside the word after the key
+    pub caret: usize,
+}
+
+/// Preedit text mirrored from diff results
+#[derive(Debug, Default)]
+pub(crate) struct Preedit {
+    text: Vec<char>,
+    caret: usize,
+}
+
+impl Preedit {
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.caret = 0;
+    }
+
+    /// Clear, overwriting the mirrored text first
+    pub fn wipe(&mut self) {
+        wipe_vec(&mut self.text, '\0');
+        self.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Take the mirrored text, leaving the preedit empty
+    pub fn take(&mut self) -> Vec<char> {
+        self.caret = 0;
+        std::mem::take(&mut self.text)
+    }
+
+    /// Hold `text` again, caret at the end (word reopened after a commit)
+    pub fn reopen(&mut self, text: &str) {
+        self.text = text.chars().collect();
+        self.caret = self.text.len();
+    }
+
+    /// Translate a diff result into a composition
+    pub fn apply(&mut self, r: &Result, key: KeyInfo) -> Composition {
+        let mut delete_before: u8 = 0;
+        if r.action == Action::Send as u8 {
+            for _ in 0..r.backspace {
+                // Backspaces past the preedit delete committed text
+                if !self.delete_before_caret() {
+                    delete_before = delete_before.saturating_add(1);
+                }
+            }
+            for _ in 0..r.delete_after {
+                if self.caret < self.text.len() {
+                    self.text.remove(self.caret);
+                }
+            }
+            for c in r.chars[..r.count as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+            {
+                self.text.insert(self.caret, c);
+                self.caret += 1;
+            }
+            self.caret = self.caret.saturating_sub(r.caret_back as usize);
+        }
+
+        let mut consumed = !key.passes_through;
+        if key.passes_through {
+            if key.is_delete {
+                // Backspace edits the preedit; on an empty preedit the app handles it
+                consumed = self.delete_before_caret();
+            } else if key.composing {
+                if let Some(c) = key.typed {
+                    self.text.insert(self.caret, c);
+                    self.caret += 1;
+                }
+                // Other keys that keep the word (LEFT/RIGHT) move the preedit caret
+                consumed = true;
+            }
+        }
+
+        if key.composing {
+            self.caret = key.caret.min(self.text.len());
+            Composition::new(&self.text, self.caret, &[], delete_before, consumed)
+        } else {
+            let commit = std::mem::take(&mut self.text);
+            self.caret = 0;
+            Composition::new(&[], 0, &commit, delete_before, consumed)
+        }
+    }
+
+    /// Delete the char before the caret; false if the caret is at the start
+    fn delete_before_caret(&mut self) -> bool {
+        if self.caret == 0 {
+            return false;
+        }
+        self.caret -= 1;
+        self.text.remove(self.caret);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letter(c: char) -> KeyInfo {
+        KeyInfo {
+            typed: Some(c),
+            passes_through: true,
+            is_delete: false,
+            composing: true,
+            caret: 1,
+        }
+    }
+
+    #[test]
+    fn test_letters_build_preedit() {
+        let mut p = Preedit::default();
+        p.apply(&Result::none(), letter('a'));
+        let c = p.apply(
+            &Result::send(1, &['á']),
+            KeyInfo {
+                passes_through: false,
+                ..letter('s')
+            },
+        );
+        assert_eq!(c.preedit_string(), "á");
+        assert_eq!(c.caret, 1);
+        assert_eq!(c.commit_len, 0);
+    }
+
+    #[test]
+    fn test_boundary_commits() {
+        let mut p = Preedit::default();
+        p.apply(&Result::none(), letter('a'));
+        let c = p.apply(
+            &Result::none(),
+            KeyInfo {
+                typed: Some(' '),
+                passes_through: true,
+                is_delete: false,
+                composing: false,
+                caret: 0,
+            },
+        );
+        assert_eq!(c.commit_string(), "a");
+        assert_eq!(c.preedit_len, 0);
+        assert!(
+            !c.key_consumed(),
+            "Space is typed by the app after the commit"
+        );
+    }
+
+    #[test]
+    fn test_backspace_past_preedit() {
+        let mut p = Preedit::default();
+        let c = p.apply(
+            &Result::send_consumed(1, &['→']),
+            KeyInfo {
+                typed: Some('>'),
+                passes_through: false,
+                is_delete: false,
+                composing: false,
+                caret: 0,
+            },
+        );
+        assert_eq!(c.delete_before, 1);
+        assert_eq!(c.commit_string(), "→");
+        assert!(c.key_consumed());
+    }
+}
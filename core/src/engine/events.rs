@@ -0,0 +1,244 @@
+//! Engine Events - What a keystroke did, beyond the screen diff
+//!
+//! A `Result` only says which chars to delete and type. Events say why:
+//! a tone was added, a word was auto-restored, a shortcut expanded. The
+//! engine queues them as it handles keys; frontends poll the queue
+//! (`Engine::poll_event`) to show feedback or collect metrics.
+//!
+//! The queue is bounded: if nobody polls, the oldest events are dropped.
+
+use std::collections::VecDeque;
+
+use super::buffer::{Buffer, Char};
+use super::json::Value;
+use super::wipe::wipe_string;
+
+/// Maximum queued events (oldest dropped first)
+const QUEUE_CAPACITY: usize = 64;
+
+/// Kind of diacritic a transform added
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransformKind {
+    /// Syllable mark: sắc, huyền, hỏi, ngã, nặng
+    Mark,
+    /// Vowel tone: circumflex, horn, breve (â, ơ, ă, w → ư)
+    Tone,
+    /// d → đ
+    Stroke,
+}
+
+impl TransformKind {
+    fn name(self) -> &'static str {
+        match self {
+            TransformKind::Mark => "mark",
+            TransformKind::Tone => "tone",
+            TransformKind::Stroke => "stroke",
+        }
+    }
+}
+
+/// Something the engine did while handling a key
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// A key added a diacritic to the word
+    TransformApplied { kind: TransformKind },
+    /// A key removed a diacritic (double-key revert, undo)
+    TransformReverted,
+    /// A word was restored to its keystrokes (English auto-restore)
+    AutoRestored { raw: String, vietnamese: String },
+    /// A shortcut trigger was replaced by its expansion
+    ShortcutExpanded { trigger: String },
+    /// The first letter of a sentence was uppercased
+    AutoCapitalized,
+    /// A word was committed by space or punctuation (`text` as shown)
+    WordCommitted { text: String },
+    /// Backspace after space brought the previous word back for editing
+    HistoryRestored,
+}
+
+impl Event {
+    /// Export as JSON: `{"type":"auto_restored","raw":"text","vietnamese":"tẽt"}`
+    pub fn to_json(&self) -> String {
+        let string = |s: &str| Value::String(s.to_string());
+        let (name, mut fields) = match self {
+            Event::TransformApplied { kind } => {
+                ("transform_applied", vec![("kind", string(kind.name()))])
+            }
+            Event::TransformReverted => ("transform_reverted", vec![]),
+            Event::AutoRestored { raw, vietnamese } => (
+                "auto_restored",
+                vec![("raw", string(raw)), ("vietnamese", string(vietnamese))],
+            ),
+            Event::ShortcutExpanded { trigger } => {
+                ("shortcut_expanded", vec![("trigger", string(trigger))])
+            }
+            Event::AutoCapitalized => ("auto_capitalized", vec![]),
+            Event::WordCommitted { text } => ("word_committed", vec![("text", string(text))]),
+            Event::HistoryRestored => ("history_restored", vec![]),
+        };
+        fields.insert(0, ("type", string(name)));
+        Value::Object(
+            fields
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect(),
+        )
+        .to_json()
+    }
+}
+
+/// Bounded FIFO of events waiting to be polled
+#[derive(Debug, Default)]
+pub(crate) struct EventQueue {
+    events: VecDeque<Event>,
+    /// Events pushed so far (dropped ones included)
+    pushed: u64,
+    /// Drop every event on push (incognito: events carry typed text)
+    muted: bool,
+}
+
+impl EventQueue {
+    pub fn push(&mut self, event: Event) {
+        self.pushed += 1;
+        if self.muted {
+            return;
+        }
+        if self.events.len() == QUEUE_CAPACITY {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    pub fn set_muted(&mut self, muted: bool) {
+        self.muted = muted;
+    }
+
+    /// Drop queued events, overwriting the text they carry
+    pub fn wipe(&mut self) {
+        for event in self.events.iter_mut() {
+            match event {
+                Event::AutoRestored { raw, vietnamese } => {
+                    wipe_string(raw);
+                    wipe_string(vietnamese);
+                }
+                Event::ShortcutExpanded { trigger: text } | Event::WordCommitted { text } => {
+                    wipe_string(text)
+                }
+                _ => {}
+            }
+        }
+        self.events.clear();
+    }
+
+    /// Count of events ever pushed: compare before/after a step to tell if
+    /// it reported anything
+    pub fn pushed(&self) -> u64 {
+        self.pushed
+    }
+
+    pub fn pop(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    pub fn peek(&self) -> Option<&Event> {
+        self.events.front()
+    }
+}
+
+/// Classify what a key did to the word's diacritics (`old` → `new`)
+///
+/// Removing any diacritic is a revert, even if the key also added one
+/// (`ass` → `as`). A moved mark (tone repositioning) is not an event.
+pub(crate) fn transform_event(old: &Buffer, new: &Buffer) -> Option<Event> {
+    let mark = |buf: &Buffer| buf.iter().map(|c| c.mark).find(|&m| m > 0).unwrap_or(0);
+    let reverted = old
+        .iter()
+        .zip(new.iter())
+        .any(|(a, b)| (a.has_tone() && !b.has_tone()) || (a.stroke && !b.stroke))
+        || (mark(old) > 0 && mark(new) == 0);
+    if reverted {
+        return Some(Event::TransformReverted);
+    }
+
+    let added = |f: fn(&Char) -> u8| {
+        new.iter()
+            .enumerate()
+            .any(|(i, b)| f(b) > 0 && old.get(i).map_or(0, f) != f(b))
+    };
+    let kind = if added(|c| c.stroke as u8) {
+        TransformKind::Stroke
+    } else if added(|c| c.tone) {
+        TransformKind::Tone
+    } else if mark(new) > 0 && mark(new) != mark(old) {
+        TransformKind::Mark
+    } else {
+        return None;
+    };
+    Some(Event::TransformApplied { kind })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{chars::mark, chars::tone, keys};
+
+    fn buf(chars: &[(u16, u8, u8)]) -> Buffer {
+        let mut b = Buffer::new();
+        for &(key, tone, mark) in chars {
+            let mut c = Char::new(key, false);
+            c.tone = tone;
+            c.mark = mark;
+            b.push(c);
+        }
+        b
+    }
+
+    #[test]
+    fn classify_transforms() {
+        let a = buf(&[(keys::A, 0, 0)]);
+        let a_circ = buf(&[(keys::A, tone::CIRCUMFLEX, 0)]);
+        let a_sac = buf(&[(keys::A, 0, mark::SAC)]);
+        let a_s = buf(&[(keys::A, 0, 0), (keys::S, 0, 0)]);
+        let tone_applied = Event::TransformApplied {
+            kind: TransformKind::Tone,
+        };
+        assert_eq!(transform_event(&a, &a_circ), Some(tone_applied));
+        let mark_applied = Event::TransformApplied {
+            kind: TransformKind::Mark,
+        };
+        assert_eq!(transform_event(&a, &a_sac), Some(mark_applied));
+        assert_eq!(
+            transform_event(&a_sac, &a_s),
+            Some(Event::TransformReverted)
+        );
+        assert_eq!(transform_event(&a, &a_s), None, "plain letter");
+    }
+
+    #[test]
+    fn json_format() {
+        let e = Event::AutoRestored {
+            raw: "text".to_string(),
+            vietnamese: "tẽt".to_string(),
+        };
+        assert_eq!(
+            e.to_json(),
+            r#"{"type":"auto_restored","raw":"text","vietnamese":"tẽt"}"#
+        );
+        assert_eq!(
+            Event::AutoCapitalized.to_json(),
+            r#"{"type":"auto_capitalized"}"#
+        );
+    }
+
+    #[test]
+    fn queue_drops_oldest() {
+        let mut q = EventQueue::default();
+        for _ in 0..QUEUE_CAPACITY {
+            q.push(Event::TransformReverted);
+        }
+        q.push(Event::AutoCapitalized);
+        assert_eq!(q.pop(), Some(Event::TransformReverted));
+        assert_eq!(q.events.len(), QUEUE_CAPACITY - 1);
+        assert_eq!(q.events.back(), Some(&Event::AutoCapitalized));
+    }
+}
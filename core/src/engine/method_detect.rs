@@ -0,0 +1,190 @@
+//! Method Detection - Guess Telex vs VNI from the first words typed
+//!
+//! On a shared machine nobody knows which method is set. With auto-detect
+//! on, the engine watches the first `DETECT_WORDS` words and replays each
+//! one through every input method (`input::get`): a word votes for a
+//! method if that method's modifier keys turn it into a valid syllable
+//! (`vieejt` for Telex, `vie65t` for VNI). Words that fit several methods,
+//! or none, don't vote, and neither do English words (`bus` is not `bú`).
+//!
+//! Hysteresis: a method is detected once it leads every other method by
+//! `SWITCH_MARGIN` votes, so one odd word never flips the method back and
+//! forth. The engine applies a switch only between words.
+
+use super::validation;
+use crate::data::english_dict::is_english_word;
+use crate::data::keys;
+use crate::input::{self, Method, METHOD_COUNT};
+use crate::utils;
+
+/// Words watched before detection stops
+const DETECT_WORDS: u16 = 20;
+
+/// Votes a method must lead by to be detected
+const SWITCH_MARGIN: u16 = 2;
+
+/// Longest word considered (longer tokens are URLs, hashes, ...)
+const MAX_WORD: usize = 16;
+
+/// Watches typed words and votes for the method they were typed with
+#[derive(Debug, Default)]
+pub(crate) struct MethodDetector {
+    /// Keys of the word being typed (letters and unshifted digits)
+    word: Vec<u16>,
+    /// Votes per method id
+    votes: [u16; METHOD_COUNT as usize],
+    /// Words seen so far (voting or not)
+    words: u16,
+    detected: Option<u8>,
+    /// Detected method not applied yet (waiting for a word boundary)
+    pending: Option<u8>,
+}
+
+impl MethodDetector {
+    /// Feed a key, before the engine handles it
+    ///
+    /// Returns the method to switch to, only when no word is in progress:
+    /// `composing` = the engine still holds a word.
+    pub fn observe(&mut self, key: u16, shift: bool, composing: bool) -> Option<u8> {
+        let switch = if composing { None } else { self.pending.take() };
+        if self.words >= DETECT_WORDS {
+            return switch;
+        }
+
+        if keys::is_letter(key) || (keys::is_number(key) && !shift) {
+            self.word.push(key);
+        } else if key == keys::DELETE {
+            self.word.pop();
+        } else if matches!(
+            key,
+            keys::ESC | keys::LEFT | keys::RIGHT | keys::UP | keys::DOWN
+        ) {
+            // Caret moved or word cancelled: not a finished word
+            self.word.clear();
+        } else if keys::is_break_ext(key, shift) {
+            self.end_word();
+        } else {
+            self.word.clear();
+        }
+        switch
+    }
+
+    /// Method detected so far (None until one leads by `SWITCH_MARGIN`)
+    pub fn detected(&self) -> Option<u8> {
+        self.detected
+    }
+
+    /// Forget the word in progress (incognito)
+    pub fn clear_word(&mut self) {
+        super::wipe::wipe_vec(&mut self.word, 0);
+    }
+
+    fn end_word(&mut self) {
+        let word = std::mem::take(&mut self.word);
+        if word.is_empty() {
+            return;
+        }
+        self.words += 1;
+        if let Some(id) = vote(&word) {
+            self.votes[id as usize] += 1;
+        }
+
+        let (leader, &top) = self
+            .votes
+            .iter()
+            .enumerate()
+            .max_by_key(|&(_, v)| v)
+            .unwrap_or((0, &0));
+        let leads = self
+            .votes
+            .iter()
+            .enumerate()
+            .all(|(id, &v)| id == leader || top >= v + SWITCH_MARGIN);
+        let leader = leader as u8;
+        if leads && top >= SWITCH_MARGIN && self.detected != Some(leader) {
+            self.detected = Some(leader);
+            self.pending = Some(leader);
+        }
+    }
+}
+
+/// The only method that turns `word` into a valid Vietnamese syllable
+fn vote(word: &[u16]) -> Option<u8> {
+    if word.len() > MAX_WORD {
+        return None;
+    }
+    let letters: Option<String> = word.iter().map(|&k| utils::key_to_char(k, false)).collect();
+    if letters.is_some_and(|w| is_english_word(&w)) {
+        return None;
+    }
+    let mut fits = (0..METHOD_COUNT).filter(|&id| modifier_count(input::get(id), word) > 0);
+    match (fits.next(), fits.next()) {
+        (Some(id), None) => Some(id),
+        _ => None,
+    }
+}
+
+/// Modifier keys `method` would apply in `word`, or 0 if the letters left
+/// after removing them don't form a valid syllable
+fn modifier_count(method: &dyn Method, word: &[u16]) -> usize {
+    let mut base: Vec<u16> = Vec::with_capacity(word.len());
+    let mut count = 0;
+    for &key in word {
+        let has_vowel = base.iter().any(|&k| keys::is_vowel(k));
+        let modifies = if method.mark(key).is_some() || method.remove(key) {
+            has_vowel
+        } else if method.tone(key).is_some() {
+            method.tone_targets(key).iter().any(|t| base.contains(t))
+        } else {
+            method.stroke(key) && base.contains(&keys::D)
+        };
+        if modifies {
+            count += 1;
+        } else {
+            base.push(key);
+        }
+    }
+    if base.iter().all(|&k| keys::is_letter(k)) && validation::is_valid(&base) {
+        count
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys_of(s: &str) -> Vec<u16> {
+        s.chars().map(utils::char_to_key).collect()
+    }
+
+    #[test]
+    fn votes() {
+        assert_eq!(vote(&keys_of("vieejt")), Some(0));
+        assert_eq!(vote(&keys_of("ddi")), Some(0));
+        assert_eq!(vote(&keys_of("vie65t")), Some(1));
+        assert_eq!(vote(&keys_of("d9i")), Some(1));
+        assert_eq!(vote(&keys_of("nam")), None, "no modifiers");
+        assert_eq!(vote(&keys_of("bus")), None, "English word");
+    }
+
+    #[test]
+    fn hysteresis() {
+        let mut d = MethodDetector::default();
+        let type_word = |d: &mut MethodDetector, s: &str| {
+            for key in keys_of(s) {
+                d.observe(key, false, true);
+            }
+            d.observe(keys::SPACE, false, true);
+        };
+        type_word(&mut d, "vie65t");
+        assert_eq!(d.detected(), None, "one word is not enough");
+        type_word(&mut d, "nam8");
+        assert_eq!(d.detected(), Some(1));
+        type_word(&mut d, "vieejt");
+        assert_eq!(d.detected(), Some(1), "one Telex word doesn't flip back");
+        assert_eq!(d.observe(keys::A, false, true), None, "not mid-word");
+        assert_eq!(d.observe(keys::A, false, false), Some(1));
+    }
+}
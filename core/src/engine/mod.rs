@@ -10,11 +10,23 @@
 //! 3. **Shortcut Support**: User-defined abbreviations with priority
 //! 4. **Longest-Match-First**: For diacritic placement
 
+pub mod app_rules;
+pub mod auto_restore;
 pub mod buffer;
+pub mod classifier;
+pub mod composition;
+pub mod events;
+pub mod json;
+pub mod method_detect;
+pub mod profile;
+pub mod settings;
 pub mod shortcut;
 pub mod syllable;
 pub mod transform;
+pub mod undo;
 pub mod validation;
+pub mod wipe;
+pub mod word_state;
 
 use crate::data::{
     chars::{self, mark, tone},
@@ -23,12 +35,21 @@ use crate::data::{
 };
 use crate::input::{self, ToneType};
 use crate::utils;
+use app_rules::AppRule;
+use auto_restore::AutoRestoreLevel;
 use buffer::{Buffer, Char, MAX};
-use shortcut::{InputMethod, ShortcutTable};
+use composition::{Composition, KeyInfo, OutputMode, Preedit};
+use events::{Event, EventQueue};
+use method_detect::MethodDetector;
+use profile::Profile;
+use settings::EngineSettings;
+use shortcut::{InputMethod, ShortcutMatch, ShortcutTable};
+use undo::UndoHistory;
 use validation::{
-    is_foreign_word_pattern, is_valid, is_valid_for_transform_with_foreign,
-    is_valid_with_foreign, is_valid_with_tones, is_valid_with_tones_and_foreign,
+    is_foreign_word_pattern, is_valid, is_valid_with_tones, BufferSnapshot, ValidationResult,
 };
+use wipe::wipe_string;
+use word_state::{WordSnapshot, WordState};
 
 /// Engine action result
 #[repr(u8)]
@@ -50,6 +71,11 @@ pub struct Result {
     /// - bit 0 (0x01): key_consumed - if set, the trigger key should NOT be passed through
     ///   Used for shortcuts where the trigger key is part of the replacement
     pub flags: u8,
+    /// Chars after the caret to delete (forward) before sending `chars`
+    /// Non-zero only for edits with the caret inside the word
+    pub delete_after: u8,
+    /// Move the caret left by this many chars after sending `chars`
+    pub caret_back: u8,
 }
 
 /// Flag: key was consumed by shortcut, don't pass through
@@ -63,6 +89,8 @@ impl Result {
             backspace: 0,
             count: 0,
             flags: 0,
+            delete_after: 0,
+            caret_back: 0,
         }
     }
 
@@ -73,6 +101,8 @@ impl Result {
             backspace,
             count: chars.len().min(MAX) as u8,
             flags: 0,
+            delete_after: 0,
+            caret_back: 0,
         };
         for (i, &c) in chars.iter().take(MAX).enumerate() {
             result.chars[i] = c as u32;
@@ -156,6 +186,14 @@ impl WordHistory {
         self.len = 0;
         self.head = 0;
     }
+
+    /// Clear, overwriting every stored word
+    fn wipe(&mut self) {
+        for buf in self.data.iter_mut() {
+            buf.wipe();
+        }
+        self.clear();
+    }
 }
 
 /// Check if key is sentence-ending punctuation (. ! ?) but NOT Enter
@@ -194,6 +232,133 @@ fn should_reset_pending_capitalize(key: u16, shift: bool) -> bool {
     !is_neutral
 }
 
+/// Letter case of a word, for `Engine::cycle_case`
+#[derive(Clone, Copy)]
+enum Case {
+    Lower,
+    Title,
+    Upper,
+}
+
+impl Case {
+    fn caps_at(self, i: usize) -> bool {
+        match self {
+            Case::Lower => false,
+            Case::Title => i == 0,
+            Case::Upper => true,
+        }
+    }
+
+    fn apply(self, word: &str) -> String {
+        word.chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if self.caps_at(i) {
+                    c.to_uppercase().next().unwrap_or(c)
+                } else {
+                    c.to_lowercase().next().unwrap_or(c)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Next case in the cycle lower → Title → UPPER → lower (mixed → lower)
+fn next_case(word: &Buffer) -> Case {
+    let first = word.get(0).is_some_and(|c| c.caps);
+    let rest_upper = word.iter().skip(1).filter(|c| c.caps).count();
+    let rest = word.len().saturating_sub(1);
+    match (first, rest_upper) {
+        (false, 0) => Case::Title,
+        (true, 0) if rest > 0 => Case::Upper,
+        _ => Case::Lower,
+    }
+}
+
+/// Parse a word back into buffer chars (chars that are not letters are skipped)
+fn word_buffer(word: &str) -> Buffer {
+    let mut buf = Buffer::new();
+    for parsed in word.chars().filter_map(chars::parse_char) {
+        let mut c = Char::new(parsed.key, parsed.caps);
+        c.tone = parsed.tone;
+        c.mark = parsed.mark;
+        c.stroke = parsed.stroke;
+        buf.push(c);
+    }
+    buf
+}
+
+/// Diff turning `old` into `new` on screen, relative to the caret
+///
+/// Backspaces to the first changed char before the caret; if the text
+/// after the caret changed too, it is deleted forward and retyped
+/// (`delete_after`), then the caret steps back (`caret_back`).
+///
+/// `key` is the key being handled and the char it types, if any: when the
+/// edit is exactly what the key does on its own (that char inserted at the
+/// caret, or one char deleted by DELETE), the key is passed through.
+fn edit_result(
+    old: &[char],
+    old_caret: usize,
+    new: &[char],
+    new_caret: usize,
+    key: Option<(u16, Option<char>)>,
+) -> Result {
+    let prefix = old[..old_caret]
+        .iter()
+        .zip(&new[..new_caret])
+        .take_while(|(a, b)| a == b)
+        .count();
+    let backspace = (old_caret - prefix) as u8;
+
+    if old[old_caret..] != new[new_caret..] {
+        let mut result = Result::send(backspace, &new[prefix..]);
+        result.delete_after = (old.len() - old_caret) as u8;
+        result.caret_back = (new.len() - new_caret) as u8;
+        return result;
+    }
+    let inserted = &new[prefix..new_caret];
+    let key_alone = key.is_some_and(|(key, typed)| match typed {
+        Some(c) => backspace == 0 && inserted == [c],
+        None => key == keys::DELETE && backspace == 1 && inserted.is_empty(),
+    });
+    if key_alone {
+        return Result::none();
+    }
+    Result::send(backspace, inserted)
+}
+
+/// Char a key types when passed through to the app (None for Enter, Tab, arrows, ...)
+fn typed_char(key: u16, caps: bool, ctrl: bool, shift: bool) -> Option<char> {
+    match key {
+        _ if ctrl => None,
+        keys::SPACE => Some(' '),
+        _ => utils::key_to_char_ext(key, caps, shift).or_else(|| break_key_to_char(key, shift)),
+    }
+}
+
+/// Check if a token (chars since the last whitespace) is a URL, email,
+/// path or code identifier
+///
+/// Examples: "https:/", "www.", "a@", "~/", "./", "a/b/", "user_", "std::",
+/// "getU" (camelCase hump)
+fn is_raw_token(token: &str) -> bool {
+    let lower = token.to_ascii_lowercase();
+    let chars: Vec<char> = token.chars().collect();
+    lower.contains(":/")
+        || lower.starts_with("www.")
+        || token.contains('@')
+        || token.contains('_')
+        || token.contains("::")
+        || ["/", "~/", "./", "../"]
+            .iter()
+            .any(|p| token.starts_with(p))
+        || token.matches('/').count() >= 2
+        || chars
+            .windows(2)
+            .any(|w| w[0].is_ascii_lowercase() && w[1].is_ascii_uppercase())
+}
+
 /// Convert break key to its character representation
 /// Handles both shifted and unshifted break characters for shortcut matching.
 /// Examples: MINUS → '-', Shift+DOT → '>', Shift+MINUS → '_'
@@ -248,13 +413,11 @@ pub struct Engine {
     buf: Buffer,
     method: u8,
     enabled: bool,
-    last_transform: Option<Transform>,
+    /// Per-word flags (deferred breve, reverted stroke, ...), reset by `clear`
+    word: WordState,
     shortcuts: ShortcutTable,
     /// Raw keystroke history for ESC restore (key, caps, shift)
     raw_input: Vec<(u16, bool, bool)>,
-    /// True if current word has non-letter characters before letters
-    /// Used to prevent false shortcut matches (e.g., "149k" should not match "k")
-    has_non_letter_prefix: bool,
     /// Skip w→ư shortcut in Telex mode (user preference)
     /// When true, typing 'w' at word start stays as 'w' instead of converting to 'ư'
     skip_w_shortcut: bool,
@@ -270,73 +433,23 @@ pub struct Engine {
     /// When true: oà, uý (tone on second vowel)
     /// When false: òa, úy (tone on first vowel - traditional)
     modern_tone: bool,
-    /// Enable English auto-restore (experimental)
-    /// When true, automatically restores English words that were transformed
+    /// English auto-restore level (experimental)
+    /// Above Off, automatically restores English words that were transformed
     /// e.g., "tẽt" → "text", "ễpct" → "expect"
-    english_auto_restore: bool,
+    auto_restore: AutoRestoreLevel,
     /// Word history for backspace-after-space feature
     word_history: WordHistory,
     /// Number of spaces typed after committing a word (for backspace tracking)
     /// When this reaches 0 on backspace, we restore the committed word
     spaces_after_commit: u8,
-    /// Pending breve position: position of 'a' that has deferred breve
-    /// Breve on 'a' in open syllables (like "raw") is invalid Vietnamese
-    /// We defer applying breve until a valid final consonant is typed
-    pending_breve_pos: Option<usize>,
-    /// Issue #133: Pending horn position on 'u' in "uơ" pattern
-    /// When "uo" + 'w' is typed at end of syllable, only 'o' gets horn initially.
-    /// If a final consonant/vowel is added, also apply horn to 'u'.
-    /// Examples: "huow" → "huơ" (stays), "duow" + "c" → "dược" (u gets horn)
-    pending_u_horn_pos: Option<usize>,
-    /// Tracks if stroke was reverted in current word (ddd → dd)
-    /// When true, subsequent 'd' keys are treated as normal letters, not stroke triggers
-    /// This prevents "ddddd" from oscillating between đ and dd states
-    stroke_reverted: bool,
-    /// Tracks if a mark was reverted in current word
-    /// Used by auto-restore to detect words like "issue", "bass" that need restoration
-    had_mark_revert: bool,
-    /// Pending pop from raw_input after mark revert
-    /// When true, the NEXT consonant key will trigger a pop to remove the consumed modifier
-    /// This differentiates: "tesst" → "test" (consonant after) vs "issue" → "issue" (vowel after)
-    pending_mark_revert_pop: bool,
-    /// Tracks if ANY Vietnamese transform was ever applied during this word
-    /// (marks, tones, or stroke). Used to prevent false auto-restore for words
-    /// with numbers/symbols that never had Vietnamese transforms applied.
-    /// Example: "nhatkha1407@gmail.com" has no transforms, so shouldn't restore.
-    had_any_transform: bool,
-    /// Tracks if circumflex was applied from V+C+V pattern by vowel trigger (not mark key)
-    /// Example: "toto" → "tôt" (second 'o' triggers circumflex on first 'o')
-    /// Used for auto-restore: if no mark follows, restore on space (e.g., "toto " → "toto ")
-    had_vowel_triggered_circumflex: bool,
-    /// Tracks if circumflex was REVERTED by third vowel (aa→â, aaa→aa)
-    /// Example: "dataa" → "dât" (after 4th key), typing 5th 'a' reverts to "data"
-    /// Used in build_raw_chars to collapse double vowel at end for restore
-    had_circumflex_revert: bool,
-    /// Issue #211: Tracks which vowel key triggered circumflex revert (extended vowel mode)
-    /// When set, subsequent same-key vowels append raw instead of re-transforming
-    /// Example: aaa→aa (reverted_circumflex_key=A), aaaa→aaa (skip transform, append raw)
-    reverted_circumflex_key: Option<u16>,
-    /// Tracks if ANY Telex transform was applied (tone, mark, or stroke)
-    /// Used for whitelist-based auto-restore to English words
-    had_telex_transform: bool,
-    /// Stores raw_input string when telex double pattern is detected (BEFORE modification)
-    /// For stroke revert (ddd→dd), raw_input is modified to remove one 'd', but we need
-    /// the original for whitelist lookup (e.g., "daddy" not "dady")
-    telex_double_raw: Option<String>,
-    /// Stores length of raw_input at time telex_double_raw was stored
-    /// Used to append subsequent chars typed after revert
-    telex_double_raw_len: usize,
+    /// Last committed word as (form on screen, other form) for toggle_last_word
+    /// Vietnamese and raw keystrokes; None if both are the same
+    last_word: Option<(String, String)>,
     /// Issue #107: Special character prefix for shortcut matching
     /// When a shifted symbol (like #, @, $) is typed first, store it here
     /// so shortcuts like "#fne" can match even though # is normally a break char
     /// Extended: Now accumulates multiple break chars for shortcuts like "->" → "→"
     shortcut_prefix: String,
-    /// Buffer was just restored from DELETE - clear on next letter input
-    /// This prevents typing after restore from appending to old buffer
-    restored_pending_clear: bool,
-    /// Restored word was pure ASCII (no Vietnamese chars) - clear on ANY letter
-    /// For Vietnamese restored words, only clear on consonant (allow mark/tone edits)
-    restored_is_ascii: bool,
     /// Auto-capitalize first letter after sentence-ending punctuation
     /// Triggers: . ! ? Enter → next letter becomes uppercase
     auto_capitalize: bool,
@@ -358,6 +471,72 @@ pub struct Engine {
     /// Allow foreign consonants (z, w, j, f) as valid initial consonants
     /// When true, these letters are accepted as Vietnamese consonants for loanwords
     allow_foreign_consonants: bool,
+    /// Named settings profiles (Chat, Code, Formal, ...)
+    profiles: Vec<Profile>,
+    /// Name of the currently active profile (None = manual settings)
+    active_profile: Option<String>,
+    /// Per-application rules in priority order (first match wins)
+    app_rules: Vec<AppRule>,
+    /// Previous values of the settings the app rule in effect changed
+    /// (restored on app switch; other settings keep manual changes)
+    app_rule_restore: Option<json::Value>,
+    /// Name of the app rule matching the focused application
+    matched_app_rule: Option<String>,
+    /// How key results are delivered (diff or preedit + commit)
+    output_mode: OutputMode,
+    /// Preedit text mirrored from diff results (Preedit output mode)
+    preedit: Preedit,
+    /// Transforms of the current word, undone one at a time
+    undo_history: UndoHistory,
+    /// Key that undoes the last transform (Shift + key redoes); None = FFI only
+    undo_key: Option<u16>,
+    /// Backspace removes diacritics before letters (tiếng → tiêng → tieng → tien)
+    smart_backspace: bool,
+    /// LEFT/RIGHT move a caret inside the word instead of ending it
+    caret_editing: bool,
+    /// Current key is an OS auto-repeat (only set during `on_key_event`)
+    key_repeat: bool,
+    /// Idle time (ms) after which the next key starts fresh; 0 = never
+    idle_timeout_ms: u32,
+    /// Timestamp of the last `on_key_timed` event (monotonic ms)
+    last_key_time: Option<u64>,
+    /// Host confirmed the word via surrounding text since the last key
+    word_confirmed: bool,
+    /// Key that makes the next keystroke literal (no mark/tone/stroke); None = off
+    literal_key: Option<u16>,
+    /// Literal key was pressed: the next input key is typed as-is
+    literal_next: bool,
+    /// Detect URLs, emails, paths and code identifiers (raw mode)
+    raw_mode_detection: bool,
+    /// Chars typed since the last whitespace (for raw mode detection)
+    raw_token: String,
+    /// Inside a raw token: keys pass through untransformed until whitespace
+    raw_mode: bool,
+    /// Track Markdown code spans/fences and skip transforms inside them
+    markdown_code: bool,
+    /// Consecutive backticks typed, resolved on the next key (3+ = fence)
+    code_ticks: u8,
+    /// Backticks that opened the current inline `code` span (0 = outside)
+    code_span: u8,
+    /// Inside a ``` fenced block
+    code_fence: bool,
+    /// All-caps words that can't be Vietnamese are typed as-is (AWS, DDR5)
+    acronym_passthrough: bool,
+    /// Current word was reverted as an acronym: later keys are typed as-is
+    acronym_literal: bool,
+    /// Split words typed without spaces into consecutive syllables
+    syllable_segmentation: bool,
+    /// Earlier syllables of the current word (buffer holds the latest one)
+    segments: Vec<Buffer>,
+    /// Accept loanword clusters (bl, str) and finals (-l, -s) for transforms
+    loanword_syllables: bool,
+    /// Events waiting for `poll_event` (tone applied, word restored, ...)
+    events: EventQueue,
+    /// Keep no typed text beyond the current word, wipe it on clear
+    incognito: bool,
+    /// Guess Telex/VNI from the first words typed
+    method_auto_detect: bool,
+    method_detector: MethodDetector,
 }
 
 impl Default for Engine {
@@ -372,33 +551,19 @@ impl Engine {
             buf: Buffer::new(),
             method: 0,
             enabled: true,
-            last_transform: None,
+            word: WordState::default(),
             shortcuts: ShortcutTable::with_defaults(),
             raw_input: Vec::with_capacity(64),
-            has_non_letter_prefix: false,
             skip_w_shortcut: false,
             bracket_shortcut: false,    // Default: OFF (Issue #159)
             esc_restore_enabled: false, // Default: OFF (user request)
             free_tone_enabled: false,
-            modern_tone: true,           // Default: modern style (hoà, thuý)
-            english_auto_restore: false, // Default: OFF (experimental feature)
+            modern_tone: true, // Default: modern style (hoà, thuý)
+            auto_restore: AutoRestoreLevel::Off, // Default: OFF (experimental feature)
             word_history: WordHistory::new(),
             spaces_after_commit: 0,
-            pending_breve_pos: None,
-            pending_u_horn_pos: None,
-            stroke_reverted: false,
-            had_mark_revert: false,
-            pending_mark_revert_pop: false,
-            had_any_transform: false,
-            had_vowel_triggered_circumflex: false,
-            had_circumflex_revert: false,
-            reverted_circumflex_key: None,
-            had_telex_transform: false,
-            telex_double_raw: None,
-            telex_double_raw_len: 0,
+            last_word: None,
             shortcut_prefix: String::new(),
-            restored_pending_clear: false,
-            restored_is_ascii: false,
             auto_capitalize: false, // Default: OFF
             pending_capitalize: false,
             auto_capitalize_used: false,
@@ -406,6 +571,39 @@ impl Engine {
             last_break_key: None,
             typed_after_space: false,
             allow_foreign_consonants: false, // Default: OFF
+            profiles: Vec::new(),
+            active_profile: None,
+            app_rules: Vec::new(),
+            app_rule_restore: None,
+            matched_app_rule: None,
+            output_mode: OutputMode::Diff,
+            preedit: Preedit::default(),
+            undo_history: UndoHistory::default(),
+            undo_key: None,
+            smart_backspace: false,
+            caret_editing: false,
+            key_repeat: false,
+            idle_timeout_ms: 0,
+            last_key_time: None,
+            word_confirmed: false,
+            literal_key: None,
+            literal_next: false,
+            raw_mode_detection: false,
+            raw_token: String::new(),
+            raw_mode: false,
+            markdown_code: false,
+            code_ticks: 0,
+            code_span: 0,
+            code_fence: false,
+            acronym_passthrough: false,
+            acronym_literal: false,
+            syllable_segmentation: false,
+            loanword_syllables: false,
+            segments: Vec::new(),
+            events: EventQueue::default(),
+            incognito: false,
+            method_auto_detect: false,
+            method_detector: MethodDetector::default(),
         }
     }
 
@@ -448,8 +646,24 @@ impl Engine {
     }
 
     /// Set whether to enable English auto-restore (experimental)
+    ///
+    /// Shorthand for `set_auto_restore_level`: true = Balanced, false = Off.
     pub fn set_english_auto_restore(&mut self, enabled: bool) {
-        self.english_auto_restore = enabled;
+        self.auto_restore = if enabled {
+            AutoRestoreLevel::Balanced
+        } else {
+            AutoRestoreLevel::Off
+        };
+    }
+
+    /// Set the English auto-restore level: 0=off, 1=dictionary-only,
+    /// 2=balanced, 3=aggressive (see `auto_restore`)
+    ///
+    /// Unknown levels are ignored.
+    pub fn set_auto_restore_level(&mut self, level: u8) {
+        if let Some(level) = AutoRestoreLevel::from_u8(level) {
+            self.auto_restore = level;
+        }
     }
 
     /// Set whether to enable auto-capitalize after sentence-ending punctuation
@@ -466,11 +680,453 @@ impl Engine {
         self.allow_foreign_consonants = enabled;
     }
 
+    /// Set output mode: 0=diff (backspace + chars), 1=preedit + commit,
+    /// 2=commit-only (whole words at word boundaries)
+    ///
+    /// Unknown modes are ignored. The preedit is reset on change.
+    pub fn set_output_mode(&mut self, mode: u8) {
+        if let Some(mode) = OutputMode::from_u8(mode) {
+            self.output_mode = mode;
+            self.preedit.clear();
+        }
+    }
+
+    /// Set whether backspace removes diacritics first
+    ///
+    /// Each backspace removes the mark, then tones (ươ together), then the
+    /// stroke, and only then the letter itself.
+    pub fn set_smart_backspace(&mut self, enabled: bool) {
+        self.smart_backspace = enabled;
+    }
+
+    /// Set whether LEFT/RIGHT move a caret inside the composing word
+    ///
+    /// When enabled, keys typed with the caret inside edit the word in place
+    /// and results may carry `delete_after`/`caret_back`, which the host must
+    /// apply. When disabled (default), LEFT/RIGHT end the word as before.
+    pub fn set_caret_editing(&mut self, enabled: bool) {
+        if self.caret_editing && !enabled && self.buf.caret_inside() {
+            // The screen caret stays inside the word: start over
+            self.clear();
+        }
+        self.caret_editing = enabled;
+    }
+
+    /// Set whether raw mode detection is enabled
+    ///
+    /// Inside a URL (`https://`, `www.`), email, path (`~/src`, `a/b/c`) or code
+    /// identifier (`user_id`, `getUser`, `std::io`), keys pass through
+    /// untransformed until whitespace. The part typed before the trigger is
+    /// restored to raw keystrokes.
+    pub fn set_raw_mode_detection(&mut self, enabled: bool) {
+        self.raw_mode_detection = enabled;
+        self.end_raw_mode();
+    }
+
+    /// Set whether Markdown code spans are typed without transforms
+    ///
+    /// Backticks open/close inline `code` spans, triple backticks open/close
+    /// fenced blocks. Inside them keys are handled like when the IME is
+    /// disabled: no Vietnamese transforms, shortcuts still work.
+    pub fn set_markdown_code(&mut self, enabled: bool) {
+        self.markdown_code = enabled;
+        self.reset_code_span();
+    }
+
+    /// Set whether all-caps acronyms pass through
+    ///
+    /// A word typed entirely in uppercase (two or more letters) whose
+    /// transformed syllable can't be Vietnamese is typed as-is: "DDR5", "USB",
+    /// "AWS" instead of "ĐR5", "ÚB", "Ắ". Valid words keep their diacritics
+    /// (VIỆT, ĐƯỢC).
+    pub fn set_acronym_passthrough(&mut self, enabled: bool) {
+        self.acronym_passthrough = enabled;
+    }
+
+    /// Set whether words typed without spaces are split into syllables
+    ///
+    /// When a letter can't continue the current (complete) syllable, it
+    /// starts the next one, so marks and tones apply to the syllable being
+    /// typed: "vieetjnam" → "việtnam", "#vieetjnamtuwoiddepj" →
+    /// "#việtnamtươiđẹp". ESC and auto-restore then act on the latest
+    /// syllable only, so this is off by default.
+    pub fn set_syllable_segmentation(&mut self, enabled: bool) {
+        self.syllable_segmentation = enabled;
+    }
+
+    /// Set whether loanword syllables accept marks and tones
+    ///
+    /// Extends the valid initials with clusters (bl, cl, br, st, str, xtr...)
+    /// and the finals with -l and -s, so "bloocs" → "blốc" and "xtreets" →
+    /// "xtrét". Validation reports these as `Loanword`, not `Valid`, so
+    /// English auto-restore still treats them as non-Vietnamese.
+    pub fn set_loanword_syllables(&mut self, enabled: bool) {
+        self.loanword_syllables = enabled;
+    }
+
+    /// Enable/disable incognito mode (password fields, privacy)
+    ///
+    /// No word history (backspace-after-space, toggle/cycle of the last
+    /// word), no events, and every clear overwrites the buffer, raw
+    /// keystrokes and undo steps instead of just resetting them. Turning it
+    /// on wipes everything typed so far. Not part of the settings: the
+    /// platform drives it from secure input detection.
+    pub fn set_incognito(&mut self, enabled: bool) {
+        self.incognito = enabled;
+        self.events.set_muted(enabled);
+        if enabled {
+            self.clear_all();
+            self.events.wipe();
+            self.method_detector.clear_word();
+        }
+    }
+
+    /// Enable/disable Telex/VNI auto-detection
+    ///
+    /// Watches the first words typed and switches to the method they fit
+    /// (`vieejt` → Telex, `vie65t` → VNI), only between words. A method
+    /// must lead by a few words before it is picked, so stray words don't
+    /// flip it. Turning it on (again) starts a new detection.
+    pub fn set_method_auto_detect(&mut self, enabled: bool) {
+        if enabled && !self.method_auto_detect {
+            self.method_detector = MethodDetector::default();
+        }
+        self.method_auto_detect = enabled;
+    }
+
+    /// Set the idle timeout in ms (0 = disabled)
+    ///
+    /// With timestamped keys (`on_key_timed`), a key arriving after this much
+    /// idle time clears the buffer and word history first: the caret has
+    /// likely moved without the platform noticing (missed click, focus change).
+    pub fn set_idle_timeout(&mut self, ms: u32) {
+        self.idle_timeout_ms = ms;
+    }
+
+    /// Set the key that undoes the last transform in the word
+    ///
+    /// Shift + key redoes. Outside a word, or with nothing to undo, the key
+    /// types as usual. None disables the key (`undo()`/`redo()` still work).
+    pub fn set_undo_key(&mut self, key: Option<u16>) {
+        self.undo_key = key;
+    }
+
+    /// Set the literal-next key
+    ///
+    /// The key is swallowed and the next letter (or VNI digit) is typed as-is,
+    /// e.g. `s\sh` → "ssh", `VNI\2` → "VNI2". Pressing it twice types the key
+    /// itself. None disables.
+    pub fn set_literal_key(&mut self, key: Option<u16>) {
+        self.literal_key = key;
+        self.literal_next = false;
+    }
+
     /// Get whether foreign consonants are allowed
     pub fn allow_foreign_consonants(&self) -> bool {
         self.allow_foreign_consonants
     }
 
+    /// Get current input method (0=Telex, 1=VNI)
+    pub fn method(&self) -> u8 {
+        self.method
+    }
+
+    /// Get whether Vietnamese input is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Get whether w→ư shortcut is skipped at word start
+    pub fn skip_w_shortcut(&self) -> bool {
+        self.skip_w_shortcut
+    }
+
+    /// Get whether bracket shortcuts are enabled
+    pub fn bracket_shortcut(&self) -> bool {
+        self.bracket_shortcut
+    }
+
+    /// Get whether ESC restores raw ASCII
+    pub fn esc_restore(&self) -> bool {
+        self.esc_restore_enabled
+    }
+
+    /// Get whether free tone placement is enabled
+    pub fn free_tone(&self) -> bool {
+        self.free_tone_enabled
+    }
+
+    /// Get whether modern tone placement is used
+    pub fn modern_tone(&self) -> bool {
+        self.modern_tone
+    }
+
+    /// Get whether English auto-restore is enabled (any level above Off)
+    pub fn english_auto_restore(&self) -> bool {
+        self.auto_restore != AutoRestoreLevel::Off
+    }
+
+    /// Get the English auto-restore level
+    pub fn auto_restore_level(&self) -> AutoRestoreLevel {
+        self.auto_restore
+    }
+
+    /// Get whether auto-capitalize is enabled
+    pub fn auto_capitalize(&self) -> bool {
+        self.auto_capitalize
+    }
+
+    /// Get current output mode
+    pub fn output_mode(&self) -> OutputMode {
+        self.output_mode
+    }
+
+    /// Get whether smart backspace is enabled
+    pub fn smart_backspace(&self) -> bool {
+        self.smart_backspace
+    }
+
+    /// Get whether caret editing inside the word is enabled
+    pub fn caret_editing(&self) -> bool {
+        self.caret_editing
+    }
+
+    /// Get whether raw mode detection is enabled
+    pub fn raw_mode_detection(&self) -> bool {
+        self.raw_mode_detection
+    }
+
+    /// Get whether Markdown code span awareness is enabled
+    pub fn markdown_code(&self) -> bool {
+        self.markdown_code
+    }
+
+    /// Get whether all-caps acronym passthrough is enabled
+    pub fn acronym_passthrough(&self) -> bool {
+        self.acronym_passthrough
+    }
+
+    /// Get whether syllable segmentation is enabled
+    pub fn syllable_segmentation(&self) -> bool {
+        self.syllable_segmentation
+    }
+
+    /// Get whether loanword syllables are accepted
+    pub fn loanword_syllables(&self) -> bool {
+        self.loanword_syllables
+    }
+
+    /// Get whether incognito mode is on
+    pub fn incognito(&self) -> bool {
+        self.incognito
+    }
+
+    /// Get whether Telex/VNI auto-detection is on
+    pub fn method_auto_detect(&self) -> bool {
+        self.method_auto_detect
+    }
+
+    /// Method picked by auto-detection (None: not detected yet or off)
+    pub fn detected_method(&self) -> Option<u8> {
+        self.method_detector
+            .detected()
+            .filter(|_| self.method_auto_detect)
+    }
+
+    /// Check if the caret is inside a Markdown code span or fence
+    pub fn in_code_span(&self) -> bool {
+        self.code_span > 0 || self.code_fence
+    }
+
+    /// Get the idle timeout in ms (0 = disabled)
+    pub fn idle_timeout(&self) -> u32 {
+        self.idle_timeout_ms
+    }
+
+    /// Get the undo key (None = disabled)
+    pub fn undo_key(&self) -> Option<u16> {
+        self.undo_key
+    }
+
+    /// Get the literal-next key (None = disabled)
+    pub fn literal_key(&self) -> Option<u16> {
+        self.literal_key
+    }
+
+    /// Collect all options into a settings struct (for export)
+    pub fn settings(&self) -> EngineSettings {
+        EngineSettings {
+            method: self.method,
+            enabled: self.enabled,
+            skip_w_shortcut: self.skip_w_shortcut,
+            bracket_shortcut: self.bracket_shortcut,
+            esc_restore: self.esc_restore_enabled,
+            free_tone: self.free_tone_enabled,
+            modern_tone: self.modern_tone,
+            auto_restore_level: self.auto_restore as u8,
+            auto_capitalize: self.auto_capitalize,
+            allow_foreign_consonants: self.allow_foreign_consonants,
+            output_mode: self.output_mode as u8,
+            smart_backspace: self.smart_backspace,
+            caret_editing: self.caret_editing,
+            idle_timeout_ms: self.idle_timeout_ms,
+            raw_mode_detection: self.raw_mode_detection,
+            markdown_code: self.markdown_code,
+            acronym_passthrough: self.acronym_passthrough,
+            syllable_segmentation: self.syllable_segmentation,
+            loanword_syllables: self.loanword_syllables,
+            method_auto_detect: self.method_auto_detect,
+            undo_key: self.undo_key,
+            literal_key: self.literal_key,
+        }
+    }
+
+    /// Apply all options at once
+    ///
+    /// Goes through the individual setters so their side effects still apply
+    /// (e.g. disabling clears the buffer). The in-progress word is kept otherwise.
+    pub fn apply_settings(&mut self, settings: &EngineSettings) {
+        self.set_method(settings.method);
+        self.set_enabled(settings.enabled);
+        self.set_skip_w_shortcut(settings.skip_w_shortcut);
+        self.set_bracket_shortcut(settings.bracket_shortcut);
+        self.set_esc_restore(settings.esc_restore);
+        self.set_free_tone(settings.free_tone);
+        self.set_modern_tone(settings.modern_tone);
+        self.set_auto_restore_level(settings.auto_restore_level);
+        self.set_auto_capitalize(settings.auto_capitalize);
+        self.set_allow_foreign_consonants(settings.allow_foreign_consonants);
+        if settings.output_mode != self.output_mode as u8 {
+            self.set_output_mode(settings.output_mode);
+        }
+        self.set_smart_backspace(settings.smart_backspace);
+        self.set_caret_editing(settings.caret_editing);
+        self.set_idle_timeout(settings.idle_timeout_ms);
+        if settings.raw_mode_detection != self.raw_mode_detection {
+            self.set_raw_mode_detection(settings.raw_mode_detection);
+        }
+        if settings.markdown_code != self.markdown_code {
+            self.set_markdown_code(settings.markdown_code);
+        }
+        self.set_acronym_passthrough(settings.acronym_passthrough);
+        self.set_syllable_segmentation(settings.syllable_segmentation);
+        self.set_loanword_syllables(settings.loanword_syllables);
+        self.set_method_auto_detect(settings.method_auto_detect);
+        self.set_undo_key(settings.undo_key);
+        if settings.literal_key != self.literal_key {
+            self.set_literal_key(settings.literal_key);
+        }
+    }
+
+    /// Add a settings profile, replacing any profile with the same name
+    pub fn add_profile(&mut self, profile: Profile) {
+        self.profiles.retain(|p| !p.is_named(&profile.name));
+        self.profiles.push(profile);
+    }
+
+    /// Remove a profile by name. If it was active, all shortcuts become
+    /// active again (current settings are kept).
+    pub fn remove_profile(&mut self, name: &str) -> bool {
+        let before = self.profiles.len();
+        self.profiles.retain(|p| !p.is_named(name));
+        if self
+            .active_profile
+            .as_ref()
+            .is_some_and(|active| active.to_lowercase() == name.to_lowercase())
+        {
+            self.active_profile = None;
+            self.shortcuts.set_active_subset(None);
+        }
+        self.profiles.len() != before
+    }
+
+    /// Activate a profile: apply its settings and shortcut subset in one step.
+    ///
+    /// The in-progress word is kept, so switching mid-word continues composing
+    /// with the new options. Returns false if no profile has that name.
+    pub fn activate_profile(&mut self, name: &str) -> bool {
+        let Some(profile) = self.profiles.iter().find(|p| p.is_named(name)).cloned() else {
+            return false;
+        };
+        // An app rule in effect stays in effect: the profile becomes its new base
+        let rule_settings = self.app_rule_restore.as_ref().and_then(|_| {
+            self.app_rules
+                .iter()
+                .find(|r| self.matched_app_rule.as_deref() == Some(r.name.as_str()))
+                .and_then(|r| r.apply(&profile.settings))
+        });
+        match rule_settings {
+            Some(effective) => {
+                self.apply_settings(&effective);
+                self.app_rule_restore = Some(profile.settings.diff(&effective));
+            }
+            None => self.apply_settings(&profile.settings),
+        }
+        self.shortcuts
+            .set_active_subset(profile.shortcuts.as_deref());
+        self.active_profile = Some(profile.name);
+        true
+    }
+
+    /// Get the name of the active profile
+    pub fn active_profile(&self) -> Option<&str> {
+        self.active_profile.as_deref()
+    }
+
+    /// Get all registered profiles
+    pub fn profiles(&self) -> &[Profile] {
+        &self.profiles
+    }
+
+    /// Replace all per-application rules (priority order, first match wins)
+    ///
+    /// Rules take effect on the next `set_app` call.
+    pub fn set_app_rules(&mut self, rules: Vec<AppRule>) {
+        self.app_rules = rules;
+    }
+
+    /// Get per-application rules
+    pub fn app_rules(&self) -> &[AppRule] {
+        &self.app_rules
+    }
+
+    /// Report the focused application and apply the first matching rule.
+    ///
+    /// Settings changed by the previous app's rule are restored first, so
+    /// rules never accumulate; settings the rule didn't touch keep any change
+    /// made while it was in effect. Focus changed, so the buffer and word
+    /// history are cleared (like `clear_all`).
+    ///
+    /// Returns the name of the matched rule.
+    pub fn set_app(&mut self, app_id: &str, window_title: &str) -> Option<&str> {
+        self.clear_all();
+
+        if let Some(restore) = self.app_rule_restore.take() {
+            if let Some(base) = self.settings().with_overrides(&restore) {
+                self.apply_settings(&base);
+            }
+        }
+        self.matched_app_rule = None;
+
+        let base = self.settings();
+        let matched = self
+            .app_rules
+            .iter()
+            .find(|r| r.matches(app_id, window_title))
+            .and_then(|r| Some((r.name.clone(), r.apply(&base)?)));
+        if let Some((name, effective)) = matched {
+            self.apply_settings(&effective);
+            self.app_rule_restore = Some(base.diff(&effective));
+            self.matched_app_rule = Some(name);
+        }
+        self.matched_app_rule.as_deref()
+    }
+
+    /// Get the name of the app rule currently in effect (for tray UI)
+    pub fn matched_app_rule(&self) -> Option<&str> {
+        self.matched_app_rule.as_deref()
+    }
+
     pub fn shortcuts(&self) -> &ShortcutTable {
         &self.shortcuts
     }
@@ -491,7 +1147,7 @@ impl Engine {
 
     /// Debug: check had_any_transform flag
     pub fn debug_had_any_transform(&self) -> bool {
-        self.had_any_transform
+        self.word.had_any_transform
     }
 
     /// Debug: get buffer content as string
@@ -513,7 +1169,7 @@ impl Engine {
 
     /// Debug: check had_mark_revert flag
     pub fn debug_had_mark_revert(&self) -> bool {
-        self.had_mark_revert
+        self.word.had_mark_revert
     }
 
     /// Debug: dump raw_input
@@ -544,26 +1200,237 @@ impl Engine {
         self.on_key_ext(key, caps, ctrl, false)
     }
 
+    /// Handle key event, returning preedit + commit instead of a diff
+    ///
+    /// Same arguments as `on_key_ext`. The diff is applied to a mirrored
+    /// preedit; when the key ends the word (space, punctuation, Enter, ...)
+    /// the preedit becomes the commit, so shortcut expansions and
+    /// auto-restored words arrive as commits.
+    pub fn on_key_composed(
+        &mut self,
+        key: u16,
+        caps: bool,
+        ctrl: bool,
+        shift: bool,
+    ) -> Composition {
+        let r = self.on_key_diff(key, caps, ctrl, shift);
+
+        // Mimic the host in diff mode: after a Send, only break keys are typed
+        // (Space and ESC results already include their effect)
+        let passes_through = if r.action == Action::Send as u8 {
+            keys::is_break_ext(key, shift)
+                && !r.key_consumed()
+                && key != keys::SPACE
+                && key != keys::ESC
+        } else {
+            true
+        };
+        self.preedit.apply(
+            &r,
+            KeyInfo {
+                typed: typed_char(key, caps, ctrl, shift),
+                passes_through,
+                is_delete: key == keys::DELETE,
+                composing: !self.buf.is_empty(),
+                caret: self.buf.caret(),
+            },
+        )
+    }
+
     /// Check if key+shift combo is a raw mode prefix character
-    /// Raw prefixes: @ # : /
-    #[allow(dead_code)] // TEMP DISABLED
+    /// Raw prefixes: @ : / . _
+    /// (# is not: hashtags are Vietnamese text)
     fn is_raw_prefix(key: u16, shift: bool) -> bool {
-        // / doesn't need shift
-        if key == keys::SLASH && !shift {
+        // / . don't need shift
+        if (key == keys::SLASH || key == keys::DOT) && !shift {
             return true;
         }
-        // @ # : need shift
+        // @ : _ need shift
         if !shift {
             return false;
         }
         matches!(
             key,
             keys::N2              // @ = Shift+2
-                | keys::N3        // # = Shift+3
                 | keys::SEMICOLON // : = Shift+;
+                | keys::MINUS // _ = Shift+-
         )
     }
 
+    /// Track the current token and enter/stay in raw mode
+    ///
+    /// Returns the result for keys handled in raw mode, None to continue
+    /// with normal processing.
+    fn try_raw_mode(&mut self, key: u16, caps: bool, shift: bool) -> Option<Result> {
+        if matches!(
+            key,
+            keys::SPACE | keys::TAB | keys::RETURN | keys::ENTER | keys::ESC
+        ) {
+            self.end_raw_mode();
+            return None;
+        }
+        if key == keys::DELETE {
+            self.raw_token.pop();
+            let was_raw = self.raw_mode;
+            self.raw_mode = is_raw_token(&self.raw_token);
+            return was_raw.then(Result::none);
+        }
+        let Some(ch) = typed_char(key, caps, false, shift) else {
+            // Arrows etc.: the token is no longer what's on screen
+            self.end_raw_mode();
+            return None;
+        };
+        self.raw_token.push(ch);
+        if self.raw_mode {
+            return Some(Result::none());
+        }
+
+        let may_start = Self::is_raw_prefix(key, shift) || (caps && keys::is_letter(key));
+        if !may_start
+            || self.buf.caret_inside()
+            || !is_raw_token(&self.raw_token)
+            || self.continues_shortcut(key, caps, shift)
+        {
+            return None;
+        }
+
+        // Restore the part typed so far ("user" in "user_id"); a letter
+        // trigger (camelCase hump) is swallowed by Send, so include it
+        self.raw_mode = true;
+        let mut result = self.restore_to_raw();
+        if result.action == Action::Send as u8 && keys::is_letter(key) {
+            let mut chars: Vec<char> = result.chars[..result.count as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+                .collect();
+            chars.push(ch);
+            result = Result::send(result.backspace, &chars);
+        }
+        self.clear();
+        self.word_history.clear();
+        self.spaces_after_commit = 0;
+        Some(result)
+    }
+
+    /// Leave raw mode and forget the current token
+    fn end_raw_mode(&mut self) {
+        if self.incognito {
+            wipe_string(&mut self.raw_token);
+        } else {
+            self.raw_token.clear();
+        }
+        self.raw_mode = false;
+    }
+
+    /// Forget the shortcut prefix (wiped in incognito)
+    fn clear_shortcut_prefix(&mut self) {
+        if self.incognito {
+            wipe_string(&mut self.shortcut_prefix);
+        } else {
+            self.shortcut_prefix.clear();
+        }
+    }
+
+    /// Update code span state for a key (before the key is processed)
+    ///
+    /// A run of backticks is resolved when the next key arrives, so "```"
+    /// toggles the fence instead of opening and closing inline spans. Like
+    /// Markdown, a span only closes on a run of the same length (``a`b``).
+    fn track_code_span(&mut self, key: u16, shift: bool) {
+        if key == keys::BACKQUOTE && !shift && self.undo_key != Some(key) {
+            self.code_ticks = self.code_ticks.saturating_add(1);
+            return;
+        }
+        if key == keys::DELETE && self.code_ticks > 0 {
+            self.code_ticks -= 1;
+            return;
+        }
+        let ticks = std::mem::take(&mut self.code_ticks);
+        let was_code = self.in_code_span();
+        if self.code_fence {
+            self.code_fence = ticks < 3;
+        } else if self.code_span > 0 {
+            if ticks == self.code_span {
+                self.code_span = 0;
+            }
+        } else if ticks >= 3 {
+            self.code_fence = true;
+        } else {
+            self.code_span = ticks;
+        }
+        // Inline spans don't continue past the line (stray backtick)
+        if key == keys::RETURN || key == keys::ENTER {
+            self.code_span = 0;
+        }
+        // Backticks typed before the span are not part of a shortcut trigger
+        if self.in_code_span() != was_code {
+            self.clear_shortcut_prefix();
+        }
+    }
+
+    /// Forget code span state (outside any span)
+    fn reset_code_span(&mut self) {
+        self.code_ticks = 0;
+        self.code_span = 0;
+        self.code_fence = false;
+    }
+
+    /// Handle key event in commit-only output mode
+    ///
+    /// Nothing reaches the app while composing (letter keys are swallowed);
+    /// the finished word is sent at a word boundary, shaped by shortcuts and
+    /// auto-restore as usual. ESC discards the hidden word. Backspace that
+    /// reopens a committed word takes it back from the app, so it is sent
+    /// whole again at the next boundary.
+    fn on_key_commit_only(&mut self, key: u16, caps: bool, ctrl: bool, shift: bool) -> Result {
+        if key == keys::ESC && !ctrl {
+            let had_word = !self.preedit.is_empty();
+            self.on_key_diff(key, caps, ctrl, shift);
+            self.preedit.clear();
+            return if had_word {
+                Result::send_consumed(0, &[])
+            } else {
+                Result::none()
+            };
+        }
+
+        let c = self.on_key_composed(key, caps, ctrl, shift);
+        if key == keys::DELETE && self.preedit.is_empty() && !self.buf.is_empty() {
+            let word = self.buf.to_full_string();
+            self.preedit.reopen(&word);
+            let deleted = c.delete_before + u8::from(!c.key_consumed());
+            let backspace = deleted.saturating_add(word.chars().count() as u8);
+            return Result::send_consumed(backspace, &[]);
+        }
+        let mut chars: Vec<char> = c.commit_string().chars().collect();
+        let mut consumed = c.key_consumed();
+
+        // Hosts type break keys after a Send but swallow other keys,
+        // and Space results carry the space themselves
+        if !consumed
+            && (!chars.is_empty() || c.delete_before > 0)
+            && (key == keys::SPACE || !keys::is_break_ext(key, shift))
+        {
+            if let Some(ch) = typed_char(key, caps, ctrl, shift) {
+                chars.push(ch);
+                consumed = true;
+            }
+        }
+
+        if chars.is_empty() && c.delete_before == 0 {
+            return if consumed {
+                Result::send_consumed(0, &[])
+            } else {
+                Result::none()
+            };
+        }
+        if consumed {
+            Result::send_consumed(c.delete_before, &chars)
+        } else {
+            Result::send(c.delete_before, &chars)
+        }
+    }
+
     /// Handle key event with extended parameters
     ///
     /// # Arguments
@@ -571,19 +1438,137 @@ impl Engine {
     /// * `caps` - true if Caps Lock is active (for uppercase letters)
     /// * `ctrl` - true if Cmd/Ctrl/Alt is pressed (bypasses IME)
     /// * `shift` - true if Shift key is pressed (for symbols like @, #, $)
+    ///
+    /// In commit-only output mode the result carries whole words only.
     pub fn on_key_ext(&mut self, key: u16, caps: bool, ctrl: bool, shift: bool) -> Result {
+        if self.output_mode == OutputMode::CommitOnly {
+            return self.on_key_commit_only(key, caps, ctrl, shift);
+        }
+        self.on_key_diff(key, caps, ctrl, shift)
+    }
+
+    /// Handle key event, with the OS auto-repeat flag
+    ///
+    /// Same as `on_key_ext`, plus `repeat` = the event comes from holding the
+    /// key down. Repeated letters (and VNI digits) are typed literally,
+    /// without tone, mark or stroke transforms: holding `a` gives `aaaa`
+    /// instead of cycling â/aa, holding `d` gives `dddd` instead of đ/dd.
+    /// They are still recorded as raw input, so ESC and auto-restore see them.
+    pub fn on_key_event(
+        &mut self,
+        key: u16,
+        caps: bool,
+        ctrl: bool,
+        shift: bool,
+        repeat: bool,
+    ) -> Result {
+        self.key_repeat = repeat;
+        let result = self.on_key_ext(key, caps, ctrl, shift);
+        self.key_repeat = false;
+        result
+    }
+
+    /// Handle key event with a timestamp (monotonic ms)
+    ///
+    /// Same as `on_key_event`. If the idle timeout is set and more than that
+    /// passed since the previous key, the engine starts fresh (`clear_all`),
+    /// so the key does not combine with a word typed elsewhere.
+    /// Exception: a mark/tone key after the host confirmed the word with
+    /// `set_surrounding_text` keeps editing that word.
+    pub fn on_key_timed(
+        &mut self,
+        key: u16,
+        caps: bool,
+        ctrl: bool,
+        shift: bool,
+        repeat: bool,
+        time_ms: u64,
+    ) -> Result {
+        self.track_key_time(key, time_ms);
+        self.on_key_event(key, caps, ctrl, shift, repeat)
+    }
+
+    /// Handle key event with repeat flag and timestamp, returning preedit + commit
+    ///
+    /// `on_key_composed` with the auto-repeat and idle timeout handling of
+    /// `on_key_timed`. A word still in the preedit when the timeout starts
+    /// fresh is committed as it is.
+    pub fn on_key_composed_timed(
+        &mut self,
+        key: u16,
+        caps: bool,
+        ctrl: bool,
+        shift: bool,
+        repeat: bool,
+        time_ms: u64,
+    ) -> Composition {
+        let pending = if self.idle_reset(key, time_ms) {
+            self.preedit.take()
+        } else {
+            Vec::new()
+        };
+        self.track_key_time(key, time_ms);
+        self.key_repeat = repeat;
+        let mut composition = self.on_key_composed(key, caps, ctrl, shift);
+        self.key_repeat = false;
+        if !pending.is_empty() {
+            composition.prepend_commit(&pending);
+        }
+        composition
+    }
+
+    /// Record the key time, starting fresh if the idle timeout passed
+    fn track_key_time(&mut self, key: u16, time_ms: u64) {
+        if self.idle_reset(key, time_ms) {
+            self.clear_all();
+        }
+        self.last_key_time = Some(time_ms);
+        self.word_confirmed = false;
+    }
+
+    /// Whether a key at `time_ms` starts fresh (idle timeout passed)
+    fn idle_reset(&self, key: u16, time_ms: u64) -> bool {
+        let idle = self
+            .last_key_time
+            .is_some_and(|t| time_ms.saturating_sub(t) >= self.idle_timeout_ms as u64);
+        if self.idle_timeout_ms == 0 || !idle {
+            return false;
+        }
+        let m = input::get(self.method);
+        let continues_word = m.mark(key).is_some() || m.tone(key).is_some();
+        !(continues_word && self.word_confirmed)
+    }
+
+    /// Handle key event, returning a backspace + chars diff
+    fn on_key_diff(&mut self, key: u16, caps: bool, ctrl: bool, shift: bool) -> Result {
         // Issue #129: Process shortcuts even when IME is disabled
         // Only bypass completely for Ctrl/Cmd modifier keys
         if ctrl {
             self.clear();
             self.word_history.clear();
             self.spaces_after_commit = 0;
+            self.end_raw_mode();
             return Result::none();
         }
 
+        if self.markdown_code {
+            self.track_code_span(key, shift);
+        }
+
+        // Switch methods between words only (buffer empty before this key)
+        if self.method_auto_detect && !self.incognito {
+            if let Some(method) = self
+                .method_detector
+                .observe(key, shift, !self.buf.is_empty())
+            {
+                self.method = method;
+            }
+        }
+
         // When IME is disabled, process shortcuts but skip Vietnamese transforms
         // This allows both word shortcuts (btw → by the way) and symbol shortcuts (-> → →)
-        if !self.enabled {
+        // Markdown code spans are handled the same way
+        if !self.enabled || self.in_code_span() {
             // Clear Vietnamese state
             self.buf.clear();
             self.raw_input.clear();
@@ -602,7 +1587,8 @@ impl Engine {
                     ) {
                         let output: Vec<char> = m.output.chars().collect();
                         let backspace_count = m.backspace_count as u8;
-                        self.shortcut_prefix.clear();
+                        self.push_shortcut_event(&self.shortcut_prefix.clone(), &m);
+                        self.clear_shortcut_prefix();
                         // For Space, include space in output; for Enter, don't
                         if key == keys::SPACE {
                             let mut output_with_space = output;
@@ -613,7 +1599,7 @@ impl Engine {
                         }
                     }
                 }
-                self.shortcut_prefix.clear();
+                self.clear_shortcut_prefix();
                 return Result::none();
             }
 
@@ -631,13 +1617,14 @@ impl Engine {
                     ) {
                         let output: Vec<char> = m.output.chars().collect();
                         let backspace_count = (m.backspace_count as u8).saturating_sub(1);
-                        self.shortcut_prefix.clear();
+                        self.push_shortcut_event(&self.shortcut_prefix.clone(), &m);
+                        self.clear_shortcut_prefix();
                         return Result::send_consumed(backspace_count, &output);
                     }
                     return Result::none();
                 }
                 // Break key without char mapping (Tab, arrows, etc.) - clear and pass through
-                self.shortcut_prefix.clear();
+                self.clear_shortcut_prefix();
                 return Result::none();
             }
 
@@ -648,13 +1635,72 @@ impl Engine {
             }
 
             // Unknown keys: clear shortcut prefix and pass through
-            self.shortcut_prefix.clear();
+            self.clear_shortcut_prefix();
             return Result::none();
         }
 
+        // Literal key: swallow it and type the next input key as-is.
+        // Pressed twice, the second press types the key itself. Not armed when
+        // the key's char continues a shortcut trigger (e.g. `\sig`).
+        if self.literal_key == Some(key)
+            && !shift
+            && !self.literal_next
+            && !self.continues_shortcut(key, caps, shift)
+        {
+            self.literal_next = true;
+            return Result::send_consumed(0, &[]);
+        }
+        let literal = std::mem::take(&mut self.literal_next);
+
+        // Raw mode: URLs, emails, paths and code identifiers stay as typed
+        if self.raw_mode_detection {
+            if let Some(result) = self.try_raw_mode(key, caps, shift) {
+                return result;
+            }
+        }
+
+        // Auto-repeated and literal input keys are typed as-is (no transforms)
+        let is_input_key =
+            keys::is_letter(key) || (self.method == 1 && keys::is_number(key) && !shift);
+        let literal = literal || self.key_repeat || self.acronym_literal;
+        if literal && is_input_key && !self.buf.caret_inside() {
+            self.buf.push(Char::new(key, caps));
+            self.raw_input.push((key, caps, shift));
+            self.word.last_transform = None;
+            return Result::none();
+        }
+
+        // Undo key: step back (Shift: forward) through the word's transforms
+        if self.undo_key == Some(key) && !self.buf.is_empty() {
+            if let Some(mut result) = self.history_step(shift) {
+                result.flags |= FLAG_KEY_CONSUMED;
+                return result;
+            }
+        }
+
+        // In-word caret: LEFT/RIGHT inside the composing word keep the buffer,
+        // keys typed with the caret inside the word edit it in place
+        if let Some(result) = self.try_caret_key(key, caps, shift) {
+            return result;
+        }
+
         // Check for word boundary shortcuts ONLY on SPACE
         // Also auto-restore invalid Vietnamese to raw English
         if key == keys::SPACE {
+            // Word as typed, for toggle_last_word (only words that had transforms)
+            let keep_word = !self.incognito && self.word.had_any_transform;
+            let committing = (!self.buf.is_empty() && keep_word).then(|| {
+                let raw: String = self
+                    .build_raw_chars_exact()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect();
+                (self.buf.to_full_string(), raw, self.raw_input.clone())
+            });
+            if !self.buf.is_empty() {
+                self.last_word = None;
+            }
+
             // Handle pending mark revert pop on space (end of word)
             // When telex_double_raw is set, we use it directly for restore, no pop needed.
             // The telex_double_raw contains the exact original input before any modification.
@@ -662,8 +1708,8 @@ impl Engine {
             //   "nurses" → telex_double_raw="nurses", use directly for restore
             //   "simss" → telex_double_raw="simss", use directly for restore (ss→sims via whitelist)
             //   "taxxi" → telex_double_raw="taxx", buffer "taxi" kept (clean, no marks)
-            if self.pending_mark_revert_pop {
-                self.pending_mark_revert_pop = false;
+            if self.word.pending_mark_revert_pop {
+                self.word.pending_mark_revert_pop = false;
                 // telex_double_raw is always set when pending_mark_revert_pop is true
                 // (both set in revert_mark). Don't modify raw_input here - use
                 // telex_double_raw for restore which has the correct original chars.
@@ -679,6 +1725,7 @@ impl Engine {
             // Auto-restore: if buffer has transforms but is invalid Vietnamese,
             // restore to raw English (like ESC but triggered by space)
             let restore_result = self.try_auto_restore_on_space();
+            self.push_commit_events(&restore_result);
 
             // If auto-restore happened, repopulate buffer with plain chars from raw_input
             // This ensures word_history stores the correct restored word (not transformed)
@@ -691,9 +1738,28 @@ impl Engine {
                 }
             }
 
+            if let Some((mut shown, raw, keystrokes)) = committing {
+                if restore_result.action != 0 {
+                    // Restored word on screen (result includes the space)
+                    shown = restore_result.chars[..restore_result.count as usize]
+                        .iter()
+                        .filter_map(|&c| char::from_u32(c))
+                        .collect();
+                    shown.pop();
+                }
+                let other = if shown == raw {
+                    self.vietnamese_form(&keystrokes)
+                } else {
+                    raw
+                };
+                self.last_word = (shown != other).then_some((shown, other));
+            }
+
             // Push buffer to history before clearing (for backspace-after-space feature)
             if !self.buf.is_empty() {
-                self.word_history.push(self.buf.clone());
+                if !self.incognito {
+                    self.word_history.push(self.buf.clone());
+                }
                 self.spaces_after_commit = 1; // First space after word
                 self.typed_after_space = false; // Reset for new word
             } else if self.spaces_after_commit > 0 {
@@ -730,7 +1796,10 @@ impl Engine {
         // Issue #159: In Telex mode, `]` → ư and `[` → ơ
         // caps affects revert: ]] → ], uppercase (Shift/CapsLock) → }
         if self.method == 0 && (key == keys::RBRACKET || key == keys::LBRACKET) {
+            let before = self.buf.clone();
             if let Some(result) = self.try_bracket_as_vowel(key, caps) {
+                self.undo_history.record(&before, &self.buf, key, caps);
+                self.push_transform_event(&before);
                 return result;
             }
         }
@@ -754,7 +1823,7 @@ impl Engine {
                 // Reset has_non_letter_prefix when starting a new shortcut at true start
                 // This ensures shortcuts like "->" work after DELETE cleared the buffer
                 if at_true_start {
-                    self.has_non_letter_prefix = false;
+                    self.word.has_non_letter_prefix = false;
                 }
 
                 // Try to get the character for this break key
@@ -774,7 +1843,8 @@ impl Engine {
                         // Example: "->" trigger has backspace_count=2, but only '-' is on screen
                         let output: Vec<char> = m.output.chars().collect();
                         let backspace_count = (m.backspace_count as u8).saturating_sub(1);
-                        self.shortcut_prefix.clear();
+                        self.push_shortcut_event(&self.shortcut_prefix.clone(), &m);
+                        self.clear_shortcut_prefix();
                         return Result::send_consumed(backspace_count, &output);
                     }
 
@@ -833,6 +1903,7 @@ impl Engine {
             }
 
             let restore_result = self.try_auto_restore_on_break();
+            self.push_commit_events(&restore_result);
             self.clear();
             self.word_history.clear();
             self.spaces_after_commit = 0;
@@ -858,9 +1929,10 @@ impl Engine {
                         // Restore raw_input from buffer (for ESC restore to work)
                         self.restore_raw_input_from_buffer(&restored_buf);
                         self.buf = restored_buf;
+                        self.events.push(Event::HistoryRestored);
                         // Mark that buffer was restored - if user types new letter,
                         // clear buffer first (they want fresh word, not append)
-                        self.restored_pending_clear = true;
+                        self.word.restored_pending_clear = true;
                     }
                 }
                 // Delete one space
@@ -874,7 +1946,7 @@ impl Engine {
             // that we don't track. Mark this to prevent false shortcut matches.
             // e.g., "đa" + SPACE + backspace×2 + "a" should NOT match shortcut "a"
             if self.buf.is_empty() {
-                self.has_non_letter_prefix = true;
+                self.word.has_non_letter_prefix = true;
                 
                 // Track backspaces on empty buffer to detect when user deletes past the dot
                 // 
@@ -920,104 +1992,589 @@ impl Engine {
                     self.typed_after_space = false;
                 }
             }
-            self.buf.pop();
-            self.raw_input.pop();
-            self.last_transform = None;
-            // Reset stroke_reverted on backspace so user can re-trigger stroke
-            // e.g., "ddddd" → "dddd", then backspace×3 → "d", then "d" → "đ"
-            self.stroke_reverted = false;
-            // Issue #217: Reset reverted_circumflex_key on backspace so user can re-trigger circumflex
-            // e.g., "eee" → "ee", then backspace×2 → "", type "phe" → "phê" (not "phee")
-            self.reverted_circumflex_key = None;
-            // Only reset restored_pending_clear when buffer is empty
-            // (user finished deleting restored word completely)
-            // If buffer still has chars, user might think they cleared everything
-            // but actually didn't - let them start fresh on next letter input
-            if self.buf.is_empty() {
-                self.restored_pending_clear = false;
-                // Restore pending_capitalize if user deleted the auto-capitalized letter
-                // This allows: ". B" → delete B → ". " → type again → auto-capitalizes
-                if self.auto_capitalize_used {
-                    self.pending_capitalize = true;
-                    self.auto_capitalize_used = false;
-                }
+            if self.smart_backspace {
+                if let Some(result) = self.try_remove_diacritic() {
+                    return result;
+                }
+            }
+            self.buf.pop();
+            self.raw_input.pop();
+            // Segmented word: deleting a whole syllable goes back to the previous one
+            if self.buf.is_empty() {
+                if let Some(prev) = self.segments.pop() {
+                    self.restore_raw_input_from_buffer(&prev);
+                    self.buf = prev;
+                }
+            }
+            self.word.last_transform = None;
+            // Reset stroke_reverted on backspace so user can re-trigger stroke
+            // e.g., "ddddd" → "dddd", then backspace×3 → "d", then "d" → "đ"
+            self.word.stroke_reverted = false;
+            // Issue #217: Reset reverted_circumflex_key on backspace so user can re-trigger circumflex
+            // e.g., "eee" → "ee", then backspace×2 → "", type "phe" → "phê" (not "phee")
+            self.word.reverted_circumflex_key = None;
+            // Only reset restored_pending_clear when buffer is empty
+            // (user finished deleting restored word completely)
+            // If buffer still has chars, user might think they cleared everything
+            // but actually didn't - let them start fresh on next letter input
+            if self.buf.is_empty() {
+                self.word.restored_pending_clear = false;
+                // Restore pending_capitalize if user deleted the auto-capitalized letter
+                // This allows: ". B" → delete B → ". " → type again → auto-capitalizes
+                if self.auto_capitalize_used {
+                    self.pending_capitalize = true;
+                    self.auto_capitalize_used = false;
+                }
+            }
+            return Result::none();
+        }
+
+        // After DELETE restore, determine if user wants to:
+        // 1. Continue editing restored word (add tone/mark) - mark keys, tone keys
+        // 2. Start fresh word - regular letters (not mark/tone keys)
+        // This allows "cha" + restore + "f" → "chà" (f is mark key)
+        // But "cha" + restore + "m" → "m..." (m is consonant, start fresh)
+        // For pure ASCII restored words (like "shortcuts"), also clear on vowels
+        // unless they're mark/tone keys (allow "ban" + restore + "s" → "bán")
+        if self.word.restored_pending_clear && keys::is_letter(key) {
+            let m = input::get(self.method);
+            let is_mark_or_tone = m.mark(key).is_some() || m.tone(key).is_some();
+            // Clear buffer when letter is NOT a mark/tone modifier:
+            // - Vietnamese restored: clear on consonant (vowels may add diacritics)
+            // - ASCII restored: clear on any non-mark/tone letter (consonant OR vowel)
+            let should_clear = if self.word.restored_is_ascii {
+                // Pure ASCII: clear on any letter except mark/tone keys
+                !is_mark_or_tone
+            } else {
+                // Vietnamese: clear only on consonant that's not mark/tone
+                keys::is_consonant(key) && !is_mark_or_tone
+            };
+            if should_clear {
+                self.clear();
+            }
+            // Reset flags regardless - user is now actively typing
+            self.word.restored_pending_clear = false;
+            self.word.restored_is_ascii = false;
+        }
+
+        // Issue #212: Reset has_non_letter_prefix when user starts typing letter into empty buffer
+        // This allows shortcuts to work after: expand → delete all → retype
+        // e.g., "ko" → "không " → backspace×6 → "ko" → should expand again
+        if self.buf.is_empty() && keys::is_letter(key) && self.word.has_non_letter_prefix {
+            self.word.has_non_letter_prefix = false;
+        }
+
+        // Auto-capitalize: force uppercase for first letter after sentence-ending punctuation
+        let was_auto_capitalized = self.pending_capitalize && keys::is_letter(key) && !caps;
+        let effective_caps = if self.pending_capitalize && keys::is_letter(key) {
+            self.pending_capitalize = false;
+            self.saw_sentence_ending = false; // Reset after capitalizing
+            self.auto_capitalize_used = true; // Track that we used auto-capitalize
+            true // Force uppercase
+        } else {
+            // Reset pending on number (e.g., "1.5" should not capitalize "5")
+            if self.pending_capitalize && keys::is_number(key) {
+                self.pending_capitalize = false;
+                self.saw_sentence_ending = false;
+                self.auto_capitalize_used = false; // Number after punctuation, reset
+            }
+            // Issue #185: Reset saw_sentence_ending when letter is typed without space
+            // e.g., "google.com" - 'c' typed after '.' without space, don't capitalize
+            if self.saw_sentence_ending && keys::is_letter(key) {
+                self.saw_sentence_ending = false;
+            }
+            caps
+        };
+
+        if was_auto_capitalized {
+            self.events.push(Event::AutoCapitalized);
+        }
+
+        // Syllable segmentation: a letter that can't continue the syllable
+        // starts the next one ("việt" + "n")
+        if self.syllable_segmentation && self.ends_syllable(key) {
+            let mut segments = std::mem::take(&mut self.segments);
+            segments.push(self.buf.clone());
+            let auto_capitalize_used = self.auto_capitalize_used;
+            self.clear();
+            self.auto_capitalize_used = auto_capitalize_used;
+            self.segments = segments;
+        }
+
+        // Record raw keystroke for ESC restore (letters and numbers only)
+        if keys::is_letter(key) || keys::is_number(key) {
+            self.raw_input.push((key, effective_caps, shift));
+        }
+
+        let before = self.buf.clone();
+        let pushed = self.events.pushed();
+        let result = self.process(key, effective_caps, shift);
+        self.undo_history
+            .record(&before, &self.buf, key, effective_caps);
+        // Mid-word auto-restore already reported the change
+        if self.events.pushed() == pushed {
+            self.push_transform_event(&before);
+        }
+
+        // All-caps acronym that can't become Vietnamese: retype the keys as-is
+        if self.is_acronym_miss(false) {
+            return self.type_acronym(&before);
+        }
+
+        // If auto-capitalize triggered for first letter of a new word and process returned none,
+        // we need to send the uppercase character since the original key was lowercase
+        if was_auto_capitalized && result.action == Action::None as u8 && self.buf.len() == 1 {
+            if let Some(ch) = crate::utils::key_to_char(key, true) {
+                return Result::send(0, &[ch]);
+            }
+        }
+
+        result
+    }
+
+    /// Check if a letter key starts a new syllable (segmentation)
+    ///
+    /// True when the buffer is a valid syllable, the key doesn't modify it
+    /// (mark, remove, or a tone/stroke with a target in the buffer), and the
+    /// buffer plus the key could no longer be a syllable.
+    fn ends_syllable(&self, key: u16) -> bool {
+        if !keys::is_letter(key) || self.buf.is_empty() || self.buf.caret_inside() {
+            return false;
+        }
+        let buffer_keys: Vec<u16> = self.buf.iter().map(|c| c.key).collect();
+        let m = input::get(self.method);
+        let modifies = m.mark(key).is_some()
+            || m.remove(key)
+            || (m.tone(key).is_some()
+                && m.tone_targets(key).iter().any(|t| buffer_keys.contains(t)))
+            || (m.stroke(key) && buffer_keys.contains(&keys::D));
+        if modifies {
+            return false;
+        }
+        let buffer_tones: Vec<u8> = self.buf.iter().map(|c| c.tone).collect();
+        let mut extended = buffer_keys.clone();
+        extended.push(key);
+        is_valid_with_tones(&buffer_keys, &buffer_tones) && !validation::is_valid_prefix(&extended)
+    }
+
+    /// Check if the word is an all-caps acronym rather than Vietnamese
+    ///
+    /// True when every typed key is an uppercase letter (at least two), the
+    /// buffer has diacritics, and the syllable is not valid Vietnamese:
+    /// while typing (`complete` false) it can no longer become valid ("ĐR",
+    /// "ÚB"); at word end it is not a valid syllable ("Ắ").
+    fn is_acronym_miss(&self, complete: bool) -> bool {
+        if !self.acronym_passthrough || self.raw_input.len() < 2 {
+            return false;
+        }
+        let all_caps = self
+            .raw_input
+            .iter()
+            .all(|&(key, caps, _)| caps && keys::is_letter(key))
+            && self.buf.iter().all(|c| c.caps);
+        let has_diacritics = self
+            .buf
+            .iter()
+            .any(|c| c.tone > 0 || c.mark > 0 || c.stroke);
+        if !all_caps || !has_diacritics {
+            return false;
+        }
+        let buffer_keys: Vec<u16> = self.buf.iter().map(|c| c.key).collect();
+        if complete {
+            let buffer_tones: Vec<u8> = self.buf.iter().map(|c| c.tone).collect();
+            !validation::is_complete_syllable(&buffer_keys, &buffer_tones)
+        } else {
+            !validation::is_valid_prefix(&buffer_keys)
+        }
+    }
+
+    /// Replace the word on screen (`before` this key) with the typed keys
+    /// and type the rest of the word as-is
+    fn type_acronym(&mut self, before: &Buffer) -> Result {
+        let old: Vec<char> = before.to_full_string().chars().collect();
+        self.buf.clear();
+        for &(key, caps, _) in &self.raw_input {
+            self.buf.push(Char::new(key, caps));
+        }
+        self.acronym_literal = true;
+        self.word.last_transform = None;
+        self.word.telex_double_raw = None;
+        self.undo_history.clear();
+        let new: Vec<char> = self.buf.to_full_string().chars().collect();
+        edit_result(&old, old.len(), &new, new.len(), None)
+    }
+
+    /// Raw keys to restore at a word boundary, by auto-restore level
+    ///
+    /// Dictionary keeps only restores to known English words; Aggressive
+    /// adds `english_word_restore` after the Balanced decision points.
+    fn word_end_restore(&self) -> Option<Vec<char>> {
+        let restore = match self.auto_restore {
+            AutoRestoreLevel::Off => None,
+            AutoRestoreLevel::Dictionary => self
+                .should_auto_restore(true)
+                .filter(|raw| auto_restore::is_known_english(raw)),
+            AutoRestoreLevel::Balanced | AutoRestoreLevel::Aggressive => {
+                self.should_auto_restore(true)
+            }
+        };
+        let restore = restore
+            .or_else(|| self.acronym_restore())
+            .or_else(|| self.classifier_restore());
+        if self.auto_restore == AutoRestoreLevel::Aggressive {
+            restore.or_else(|| self.english_word_restore())
+        } else {
+            restore
+        }
+    }
+
+    /// Raw keys of a dictionary word kept as valid Vietnamese (Aggressive)
+    ///
+    /// `should_auto_restore` keeps "bú" for "bus": the buffer is valid
+    /// Vietnamese. Here English wins when the keys are a dictionary word
+    /// that the classifier finds more English than Vietnamese, unless the
+    /// word is in the `COMMON_*` exception tables.
+    fn english_word_restore(&self) -> Option<Vec<char>> {
+        if !self.word.had_any_transform
+            || !self.segments.is_empty()
+            || !self.is_raw_input_valid_english()
+        {
+            return None;
+        }
+        let word: Vec<Char> = self.buf.iter().copied().collect();
+        if auto_restore::is_common_vietnamese(&word) {
+            return None;
+        }
+        let raw = self.build_raw_chars_exact()?;
+        if !auto_restore::is_known_english(&raw) {
+            return None;
+        }
+        let raw_keys: Vec<u16> = self.raw_input.iter().map(|&(key, _, _)| key).collect();
+        if classifier::english_margin(&raw_keys, &word)? <= 0.0 {
+            return None;
+        }
+        (raw.iter().collect::<String>() != self.buf.to_full_string()).then_some(raw)
+    }
+
+    /// Raw keys of an acronym that isn't a Vietnamese syllable (word end)
+    fn acronym_restore(&self) -> Option<Vec<char>> {
+        self.is_acronym_miss(true).then(|| {
+            self.raw_input
+                .iter()
+                .filter_map(|&(key, caps, shift)| utils::key_to_char_ext(key, caps, shift))
+                .collect()
+        })
+    }
+
+    /// Raw keys of a word the n-gram classifier finds clearly English (word end)
+    ///
+    /// Tie-breaker after `should_auto_restore` kept the word: the keystrokes
+    /// must look `RESTORE_MARGIN` bits per key more English than the shown
+    /// word looks Vietnamese. Telex only; words with reverts are left to
+    /// the whitelist logic, capitalized words may be Vietnamese names
+    /// (Búk) the syllable list lacks.
+    fn classifier_restore(&self) -> Option<Vec<char>> {
+        if self.auto_restore < AutoRestoreLevel::Balanced
+            || self.method != 0
+            || !self.word.had_any_transform
+            || self.word.telex_double_raw.is_some()
+            || !self.segments.is_empty()
+            || self.raw_input.len() < classifier::MIN_KEYS
+            || self.raw_input[0].1
+        {
+            return None;
+        }
+        let raw_keys: Vec<u16> = self.raw_input.iter().map(|&(key, _, _)| key).collect();
+        let margin = classifier::english_margin(&raw_keys, self.buf.iter())?;
+        if margin <= classifier::RESTORE_MARGIN {
+            return None;
+        }
+        let raw: Vec<char> = self
+            .raw_input
+            .iter()
+            .filter_map(|&(key, caps, shift)| utils::key_to_char_ext(key, caps, shift))
+            .collect();
+        (raw.iter().collect::<String>() != self.buf.to_full_string()).then_some(raw)
+    }
+
+    /// Handle keys while the caret may be inside the composing word
+    ///
+    /// LEFT/RIGHT move the caret within the word (with caret editing on).
+    /// With the caret inside, letters are processed as if typed at the end
+    /// (so marks and tones act on the whole syllable) and a plain appended
+    /// letter is moved to the caret.
+    ///
+    /// Returns None to continue with normal end-of-word processing.
+    fn try_caret_key(&mut self, key: u16, caps: bool, shift: bool) -> Option<Result> {
+        if (key == keys::LEFT || key == keys::RIGHT)
+            && self.caret_editing
+            && !shift
+            && !self.buf.is_empty()
+        {
+            let caret = self.buf.caret();
+            if key == keys::LEFT && caret > 0 {
+                self.buf.set_caret(caret - 1);
+                return Some(Result::none());
+            }
+            if key == keys::RIGHT && caret < self.buf.len() {
+                self.buf.set_caret(caret + 1);
+                return Some(Result::none());
             }
-            return Result::none();
+            // Leaving the word: break as usual
+            return None;
+        }
+        if !self.buf.caret_inside() {
+            return None;
         }
 
-        // After DELETE restore, determine if user wants to:
-        // 1. Continue editing restored word (add tone/mark) - mark keys, tone keys
-        // 2. Start fresh word - regular letters (not mark/tone keys)
-        // This allows "cha" + restore + "f" → "chà" (f is mark key)
-        // But "cha" + restore + "m" → "m..." (m is consonant, start fresh)
-        // For pure ASCII restored words (like "shortcuts"), also clear on vowels
-        // unless they're mark/tone keys (allow "ban" + restore + "s" → "bán")
-        if self.restored_pending_clear && keys::is_letter(key) {
-            let m = input::get(self.method);
-            let is_mark_or_tone = m.mark(key).is_some() || m.tone(key).is_some();
-            // Clear buffer when letter is NOT a mark/tone modifier:
-            // - Vietnamese restored: clear on consonant (vowels may add diacritics)
-            // - ASCII restored: clear on any non-mark/tone letter (consonant OR vowel)
-            let should_clear = if self.restored_is_ascii {
-                // Pure ASCII: clear on any letter except mark/tone keys
-                !is_mark_or_tone
-            } else {
-                // Vietnamese: clear only on consonant that's not mark/tone
-                keys::is_consonant(key) && !is_mark_or_tone
-            };
-            if should_clear {
+        let caret = self.buf.caret();
+        let old: Vec<char> = self.buf.to_full_string().chars().collect();
+        self.word.restored_pending_clear = false;
+
+        if key == keys::DELETE {
+            if caret == 0 {
+                // Deleting before the word: buffer no longer matches the screen
                 self.clear();
+                return Some(Result::none());
             }
-            // Reset flags regardless - user is now actively typing
-            self.restored_pending_clear = false;
-            self.restored_is_ascii = false;
+            self.buf.remove(caret - 1);
+            self.buf.set_caret(caret - 1);
+            self.undo_history.shift_after_remove(caret - 1);
+            self.sync_raw_input_after_caret_edit();
+            let new: Vec<char> = self.buf.to_full_string().chars().collect();
+            let new_caret = self.buf.caret();
+            return Some(edit_result(&old, caret, &new, new_caret, Some((key, None))));
         }
 
-        // Issue #212: Reset has_non_letter_prefix when user starts typing letter into empty buffer
-        // This allows shortcuts to work after: expand → delete all → retype
-        // e.g., "ko" → "không " → backspace×6 → "ko" → should expand again
-        if self.buf.is_empty() && keys::is_letter(key) && self.has_non_letter_prefix {
-            self.has_non_letter_prefix = false;
+        let is_input_key =
+            keys::is_letter(key) || (self.method == 1 && keys::is_number(key) && !shift);
+        if !is_input_key {
+            // Space/punctuation inside the word splits it: start over
+            self.clear();
+            self.word_history.clear();
+            self.spaces_after_commit = 0;
+            return None;
         }
 
-        // Auto-capitalize: force uppercase for first letter after sentence-ending punctuation
-        let was_auto_capitalized = self.pending_capitalize && keys::is_letter(key) && !caps;
-        let effective_caps = if self.pending_capitalize && keys::is_letter(key) {
-            self.pending_capitalize = false;
-            self.saw_sentence_ending = false; // Reset after capitalizing
-            self.auto_capitalize_used = true; // Track that we used auto-capitalize
-            true // Force uppercase
-        } else {
-            // Reset pending on number (e.g., "1.5" should not capitalize "5")
-            if self.pending_capitalize && keys::is_number(key) {
-                self.pending_capitalize = false;
-                self.saw_sentence_ending = false;
-                self.auto_capitalize_used = false; // Number after punctuation, reset
-            }
-            // Issue #185: Reset saw_sentence_ending when letter is typed without space
-            // e.g., "google.com" - 'c' typed after '.' without space, don't capitalize
-            if self.saw_sentence_ending && keys::is_letter(key) {
-                self.saw_sentence_ending = false;
+        let len_before = self.buf.len();
+        self.buf.set_caret(len_before);
+        let result = self.on_key_diff(key, caps, false, shift);
+        if self.buf.is_empty() {
+            return Some(result);
+        }
+
+        let appended = self.buf.len() == len_before + 1
+            && self
+                .buf
+                .last()
+                .is_some_and(|c| c.key == key && !c.has_tone() && !c.has_mark() && !c.stroke);
+        if appended {
+            if let Some(ch) = self.buf.pop() {
+                self.buf.insert(caret, ch);
+                self.buf.set_caret(caret + 1);
+                self.undo_history.shift_after_insert(caret);
+                self.sync_raw_input_after_caret_edit();
+                let typed = Some((key, utils::key_to_char(key, ch.caps)));
+                let new: Vec<char> = self.buf.to_full_string().chars().collect();
+                let new_caret = self.buf.caret();
+                return Some(edit_result(&old, caret, &new, new_caret, typed));
+            }
+        }
+        // Transform applied to the syllable: caret keeps its place
+        self.buf.set_caret(caret.min(self.buf.len()));
+        let new: Vec<char> = self.buf.to_full_string().chars().collect();
+        let new_caret = self.buf.caret();
+        Some(edit_result(&old, caret, &new, new_caret, Some((key, None))))
+    }
+
+    /// Rebuild raw_input and tone placement after inserting/removing mid-word
+    fn sync_raw_input_after_caret_edit(&mut self) {
+        let buf = self.buf.clone();
+        self.restore_raw_input_from_buffer(&buf);
+        self.reposition_tone_if_needed();
+    }
+
+    /// Undo the last transform in the current word
+    ///
+    /// Steps back one transform at a time (`người` → `ngươi` → `nguoi`),
+    /// keeping letters typed after it. Returns the diff to apply, or
+    /// `Action::None` if there is nothing to undo.
+    pub fn undo(&mut self) -> Result {
+        self.history_step(false).unwrap_or_else(Result::none)
+    }
+
+    /// Re-apply the last undone transform (see `undo`)
+    pub fn redo(&mut self) -> Result {
+        self.history_step(true).unwrap_or_else(Result::none)
+    }
+
+    /// Take the oldest queued event (see `events::Event`)
+    ///
+    /// Events describe what keys did (tone applied, word auto-restored,
+    /// shortcut expanded...) for frontend feedback and metrics. The queue
+    /// keeps the latest 64 events if nobody polls.
+    pub fn poll_event(&mut self) -> Option<Event> {
+        self.events.pop()
+    }
+
+    /// Look at the oldest queued event without taking it
+    pub fn peek_event(&self) -> Option<&Event> {
+        self.events.peek()
+    }
+
+    /// Queue TransformApplied/TransformReverted for a key's buffer change
+    fn push_transform_event(&mut self, before: &Buffer) {
+        if let Some(event) = events::transform_event(before, &self.buf) {
+            self.events.push(event);
+        }
+    }
+
+    /// Queue ShortcutExpanded; the trigger is the end of `typed` it replaced
+    fn push_shortcut_event(&mut self, typed: &str, m: &ShortcutMatch) {
+        let skip = typed.chars().count().saturating_sub(m.backspace_count);
+        let trigger = typed.chars().skip(skip).collect();
+        self.events.push(Event::ShortcutExpanded { trigger });
+    }
+
+    /// Queue the events of a word boundary: AutoRestored if `restore`
+    /// replaced the word, then WordCommitted with the word as shown
+    fn push_commit_events(&mut self, restore: &Result) {
+        if self.buf.is_empty() {
+            return;
+        }
+        let earlier: String = self.segments.iter().map(|b| b.to_full_string()).collect();
+        let shown = self.buf.to_full_string();
+        let text = if restore.action != 0 {
+            let mut raw: String = restore.chars[..restore.count as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+                .collect();
+            if raw.ends_with(' ') {
+                raw.pop();
             }
-            caps
+            self.events.push(Event::AutoRestored {
+                raw: raw.clone(),
+                vietnamese: shown,
+            });
+            raw
+        } else {
+            shown
+        };
+        self.events.push(Event::WordCommitted {
+            text: earlier + &text,
+        });
+    }
+
+    /// Undo or redo one transform; None if there was nothing to apply
+    fn history_step(&mut self, redo: bool) -> Option<Result> {
+        let before = self.buf.clone();
+        let old: Vec<char> = self.buf.to_full_string().chars().collect();
+        let caret = self.buf.caret();
+        let applied = if redo {
+            self.undo_history.redo(&mut self.buf)
+        } else {
+            self.undo_history.undo(&mut self.buf)
         };
+        if !applied {
+            return None;
+        }
+        self.push_transform_event(&before);
 
-        // Record raw keystroke for ESC restore (letters and numbers only)
-        if keys::is_letter(key) || keys::is_number(key) {
-            self.raw_input.push((key, effective_caps, shift));
+        let mut new: Vec<char> = self.buf.to_full_string().chars().collect();
+        let bracket = self
+            .buf
+            .iter()
+            .position(|c| c.key == keys::LBRACKET || c.key == keys::RBRACKET);
+        if let Some(pos) = bracket {
+            // Undone bracket vowel: the bracket is not a letter, so the word ends
+            let c = self.buf.get(pos).copied().unwrap_or_default();
+            let ch = match (c.key, c.caps) {
+                (keys::RBRACKET, true) => '}',
+                (keys::RBRACKET, false) => ']',
+                (_, true) => '{',
+                (_, false) => '[',
+            };
+            new.insert(pos, ch);
+            self.clear();
+            return Some(edit_result(&old, caret, &new, caret, None));
         }
 
-        let result = self.process(key, effective_caps, shift);
+        // Double-key reverts refer to the transform state before the undo
+        self.word.last_transform = None;
+        self.word.telex_double_raw = None;
+        self.word.telex_double_raw_len = 0;
+        self.word.pending_breve_pos = None;
+        let buf = self.buf.clone();
+        self.restore_raw_input_from_buffer(&buf);
+        Some(edit_result(&old, caret, &new, self.buf.caret(), None))
+    }
 
-        // If auto-capitalize triggered for first letter of a new word and process returned none,
-        // we need to send the uppercase character since the original key was lowercase
-        if was_auto_capitalized && result.action == Action::None as u8 && self.buf.len() == 1 {
-            if let Some(ch) = crate::utils::key_to_char(key, true) {
-                return Result::send(0, &[ch]);
+    /// Smart backspace: remove the word's latest diacritic instead of a letter
+    ///
+    /// Order: mark, then tone (last toned vowel; the ươ pair together),
+    /// then stroke. The modifier keystrokes are dropped from raw_input so ESC
+    /// and auto-restore see the word as if they had never been typed.
+    /// Returns None if the word has no diacritics left.
+    fn try_remove_diacritic(&mut self) -> Option<Result> {
+        let m = input::get(self.method);
+        let old: Vec<char> = self.buf.to_full_string().chars().collect();
+
+        if let Some(pos) = self.buf.iter().position(|c| c.has_mark()) {
+            let c = self.buf.get_mut(pos)?;
+            let value = c.mark;
+            c.mark = mark::NONE;
+            self.drop_modifier_keystrokes(1, |k| m.mark(k) == Some(value));
+        } else if let Some(pos) = self.buf.iter().rposition(|c| c.has_tone()) {
+            let c = self.buf.get_mut(pos)?;
+            let (target, value) = (c.key, c.tone);
+            c.tone = tone::NONE;
+            let mut horns = 1;
+            // ươ goes as a pair: one keystroke ("uow") or one per horn ("uwow")
+            if target == keys::O && value == tone::HORN && pos > 0 {
+                if let Some(u) = self.buf.get_mut(pos - 1) {
+                    if u.key == keys::U && u.tone == tone::HORN {
+                        u.tone = tone::NONE;
+                        horns = 2;
+                    }
+                }
             }
+            self.drop_modifier_keystrokes(horns, |k| {
+                m.tone(k).is_some_and(|t| t.value() == value) && m.tone_targets(k).contains(&target)
+            });
+        } else if let Some(pos) = self.buf.iter().rposition(|c| c.stroke) {
+            self.buf.get_mut(pos)?.stroke = false;
+            self.drop_modifier_keystrokes(1, |k| m.stroke(k));
+        } else {
+            return None;
         }
 
-        result
+        self.word.telex_double_raw = None;
+        self.word.telex_double_raw_len = 0;
+        self.word.last_transform = None;
+        self.undo_history.clear();
+
+        let new: Vec<char> = self.buf.to_full_string().chars().collect();
+        Some(edit_result(&old, old.len(), &new, new.len(), None))
+    }
+
+    /// Remove up to `count` of the latest modifier keystrokes from raw_input
+    ///
+    /// Modifier keystrokes are the raw entries beyond the buffer letters.
+    /// If there are none (restored word, w → ư), raw_input is rebuilt from
+    /// the buffer instead.
+    fn drop_modifier_keystrokes(&mut self, count: usize, is_modifier: impl Fn(u16) -> bool) {
+        let mut dropped = 0;
+        while dropped < count && self.raw_input.len() > self.buf.len() {
+            let Some(i) = self.raw_input.iter().rposition(|&(k, _, _)| is_modifier(k)) else {
+                break;
+            };
+            self.raw_input.remove(i);
+            dropped += 1;
+        }
+        if dropped == 0 {
+            let buf = self.buf.clone();
+            self.restore_raw_input_from_buffer(&buf);
+        }
     }
 
     /// Main processing pipeline - pattern-based
@@ -1029,8 +2586,8 @@ impl Engine {
         // restore, so no need to modify raw_input here.
         // For vowel (issue) vs consonant (test) patterns, the whitelist and
         // restore logic will handle them correctly using telex_double_raw.
-        if self.pending_mark_revert_pop && keys::is_letter(key) {
-            self.pending_mark_revert_pop = false;
+        if self.word.pending_mark_revert_pop && keys::is_letter(key) {
+            self.word.pending_mark_revert_pop = false;
             // telex_double_raw is always set when pending_mark_revert_pop is true
             // (both set in revert_mark). Don't modify raw_input here.
         }
@@ -1060,7 +2617,10 @@ impl Engine {
             && !is_mark_key
             && !is_tone_key
             && !is_stroke_key
-            && matches!(self.last_transform, Some(Transform::ShortPatternStroke))
+            && matches!(
+                self.word.last_transform,
+                Some(Transform::ShortPatternStroke)
+            )
         {
             // Build buffer_keys from raw_input (which already includes current key)
             let raw_keys: Vec<u16> = self.raw_input.iter().map(|&(k, _, _)| k).collect();
@@ -1084,7 +2644,7 @@ impl Engine {
                     for &(k, c, _) in &self.raw_input {
                         self.buf.push(Char::new(k, c));
                     }
-                    self.last_transform = None;
+                    self.word.last_transform = None;
 
                     return Result::send(backspace, &raw_chars);
                 }
@@ -1155,7 +2715,7 @@ impl Engine {
 
         // Don't trigger shortcut if word has non-letter prefix (like "149k")
         // But DO allow shortcut_prefix (like "#fne") - that's intentional
-        if self.has_non_letter_prefix {
+        if self.word.has_non_letter_prefix {
             return Result::none();
         }
 
@@ -1182,6 +2742,7 @@ impl Engine {
                 .try_match_for_method(&full_trigger, key_char, true, input_method)
         {
             let output: Vec<char> = m.output.chars().collect();
+            self.push_shortcut_event(&full_trigger, &m);
             // backspace_count = trigger.len() which already includes prefix (e.g., "#fne" = 4)
             return Result::send(m.backspace_count as u8, &output);
         }
@@ -1189,6 +2750,18 @@ impl Engine {
         Result::none()
     }
 
+    /// Check if the key's char extends the typed text toward a shortcut trigger
+    fn continues_shortcut(&self, key: u16, caps: bool, shift: bool) -> bool {
+        let Some(ch) = break_key_to_char(key, shift).or_else(|| utils::key_to_char(key, caps))
+        else {
+            return false;
+        };
+        let word = self.buf.to_full_string();
+        let typed = format!("{}{}{}", self.shortcut_prefix, word, ch);
+        self.shortcuts
+            .has_prefix_for_method(&typed, self.current_input_method())
+    }
+
     /// Try word boundary shortcuts (triggered by space)
     fn try_word_boundary_shortcut(&mut self) -> Result {
         self.try_word_boundary_shortcut_with_char(' ')
@@ -1206,7 +2779,7 @@ impl Engine {
         // Issue #44: If breve is pending (deferred due to open syllable),
         // don't convert w→ư. Let w be added as regular letter.
         // Example: "aw" → breve deferred → should stay "aw", not become "aư"
-        if self.pending_breve_pos.is_some() {
+        if self.word.pending_breve_pos.is_some() {
             return None;
         }
 
@@ -1217,7 +2790,7 @@ impl Engine {
         }
 
         // If shortcut was previously skipped, don't try again
-        if matches!(self.last_transform, Some(Transform::WShortcutSkipped)) {
+        if matches!(self.word.last_transform, Some(Transform::WShortcutSkipped)) {
             return None;
         }
 
@@ -1230,12 +2803,12 @@ impl Engine {
 
         // Check revert: ww → w (skip shortcut)
         // Preserve original case: Ww → W, wW → w
-        if let Some(Transform::WAsVowel) = self.last_transform {
-            self.last_transform = Some(Transform::WShortcutSkipped);
+        if let Some(Transform::WAsVowel) = self.word.last_transform {
+            self.word.last_transform = Some(Transform::WShortcutSkipped);
             // Track ww pattern for whitelist-based restore
-            self.had_telex_transform = true;
+            self.word.had_telex_transform = true;
             // Store raw_input BEFORE modification for whitelist lookup
-            self.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
+            self.word.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
             // Get original case from buffer before popping
             let original_caps = self.buf.last().map(|c| c.caps).unwrap_or(caps);
             self.buf.pop();
@@ -1252,7 +2825,7 @@ impl Engine {
                 }
             }
             // Store length AFTER modification
-            self.telex_double_raw_len = self.raw_input.len();
+            self.word.telex_double_raw_len = self.raw_input.len();
             let w = if original_caps { 'W' } else { 'w' };
             return Some(Result::send(1, &[w]));
         }
@@ -1270,8 +2843,8 @@ impl Engine {
         let buffer_keys: Vec<u16> = self.buf.iter().map(|c| c.key).collect();
         let buffer_tones: Vec<u8> = self.buf.iter().map(|c| c.tone).collect();
         if is_valid_with_tones(&buffer_keys, &buffer_tones) {
-            self.last_transform = Some(Transform::WAsVowel);
-            self.had_any_transform = true;
+            self.word.last_transform = Some(Transform::WAsVowel);
+            self.word.had_any_transform = true;
 
             // W shortcut adds ư without replacing anything on screen
             // (the raw 'w' key was never output, so no backspace needed)
@@ -1296,13 +2869,13 @@ impl Engine {
     fn try_stroke(&mut self, key: u16, caps: bool) -> Option<Result> {
         // If stroke was already reverted in this word (ddd → dd), skip further stroke attempts
         // This prevents "ddddd" from oscillating and ensures subsequent 'd's are just letters
-        if self.stroke_reverted && key == keys::D {
+        if self.word.stroke_reverted && key == keys::D {
             return None;
         }
 
         // Check for stroke revert first: ddd → dd
         // If last transform was stroke and same key pressed again, revert the stroke
-        if let Some(Transform::Stroke(last_key)) = self.last_transform {
+        if let Some(Transform::Stroke(last_key)) = self.word.last_transform {
             if last_key == key {
                 // Find the stroked 'd' to revert
                 if let Some(pos) = self.buf.iter().position(|c| c.key == keys::D && c.stroke) {
@@ -1312,14 +2885,14 @@ impl Engine {
                     }
                     // Add another 'd' as normal char (preserve caps state)
                     self.buf.push(Char::new(key, caps));
-                    self.last_transform = None;
+                    self.word.last_transform = None;
                     // Mark that stroke was reverted - subsequent 'd' keys will be normal letters
-                    self.stroke_reverted = true;
+                    self.word.stroke_reverted = true;
                     // Track dd pattern for whitelist-based restore
-                    self.had_telex_transform = true;
+                    self.word.had_telex_transform = true;
                     // Store raw_input BEFORE modification for whitelist lookup
                     // For "daddy": raw_input = [d,a,d,d] → store "dadd"
-                    self.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
+                    self.word.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
                     // Fix raw_input: "ddd" typed → raw has [d,d,d] but buffer is "dd"
                     // Remove the stroke-triggering 'd' from raw_input so restore works correctly
                     // raw_input: [d, d, d] → [d, d] (remove middle 'd' that triggered stroke)
@@ -1333,7 +2906,7 @@ impl Engine {
                     }
                     // Store length AFTER modification - for "daddy": [d,a,d] → len=3
                     // Subsequent chars (y) start at position 3
-                    self.telex_double_raw_len = self.raw_input.len();
+                    self.word.telex_double_raw_len = self.raw_input.len();
                     // Use rebuild_from_after_insert because the new 'd' was just pushed
                     // and hasn't been displayed on screen yet
                     return Some(self.rebuild_from_after_insert(pos));
@@ -1344,7 +2917,7 @@ impl Engine {
         // Check for short-pattern stroke revert: dadd → dad
         // If last transform was short-pattern stroke and 'd' is pressed again, revert the stroke
         // This is similar to the ddd → dd revert above, but for delayed stroke patterns
-        if let Some(Transform::ShortPatternStroke) = self.last_transform {
+        if let Some(Transform::ShortPatternStroke) = self.word.last_transform {
             if key == keys::D {
                 // Find the stroked 'd' to revert
                 if let Some(pos) = self.buf.iter().position(|c| c.key == keys::D && c.stroke) {
@@ -1354,13 +2927,13 @@ impl Engine {
                     }
                     // Add another 'd' as normal char (preserve caps state)
                     self.buf.push(Char::new(key, caps));
-                    self.last_transform = None;
+                    self.word.last_transform = None;
                     // Mark that stroke was reverted - subsequent 'd' keys will be normal letters
-                    self.stroke_reverted = true;
+                    self.word.stroke_reverted = true;
                     // Track dd pattern for whitelist-based restore
-                    self.had_telex_transform = true;
+                    self.word.had_telex_transform = true;
                     // Store raw_input BEFORE modification for whitelist lookup
-                    self.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
+                    self.word.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
                     // Fix raw_input same as above
                     if self.raw_input.len() >= 2 {
                         let current = self.raw_input.pop();
@@ -1370,7 +2943,7 @@ impl Engine {
                         }
                     }
                     // Store length AFTER modification
-                    self.telex_double_raw_len = self.raw_input.len();
+                    self.word.telex_double_raw_len = self.raw_input.len();
                     // Use rebuild_from_after_insert because the new 'd' was just pushed
                     // and hasn't been displayed on screen yet
                     return Some(self.rebuild_from_after_insert(pos));
@@ -1408,7 +2981,7 @@ impl Engine {
                 // Must form valid Vietnamese (including vowel pattern) for delayed stroke
                 // Use is_valid() instead of is_valid_for_transform() to check vowel patterns
                 // This prevents "dea" + "d" → "đea" (invalid "ea" diphthong)
-                if !is_valid_with_foreign(&buffer_keys, self.allow_foreign_consonants) {
+                if !self.accepts_syllable(&buffer_keys) {
                     return None;
                 }
 
@@ -1451,7 +3024,7 @@ impl Engine {
         };
 
         // Check revert: if last transform was stroke on same key at same position
-        if let Some(Transform::Stroke(last_key)) = self.last_transform {
+        if let Some(Transform::Stroke(last_key)) = self.word.last_transform {
             if last_key == key {
                 return Some(self.revert_stroke(key, pos));
             }
@@ -1461,10 +3034,7 @@ impl Engine {
         // Only validate if buffer has vowels (complete syllable)
         // Allow stroke on initial consonant before vowel is typed (e.g., "dd" → "đ" then "đi")
         // Skip validation if free_tone mode is enabled
-        if !self.free_tone_enabled
-            && has_vowel
-            && !is_valid_for_transform_with_foreign(&buffer_keys, self.allow_foreign_consonants)
-        {
+        if !self.free_tone_enabled && has_vowel && !self.accepts_for_transform(&buffer_keys) {
             return None;
         }
 
@@ -1474,13 +3044,13 @@ impl Engine {
         }
 
         // Track transform type for potential revert
-        self.last_transform = if is_short_pattern_stroke {
+        self.word.last_transform = if is_short_pattern_stroke {
             Some(Transform::ShortPatternStroke)
         } else {
             Some(Transform::Stroke(key))
         };
-        self.had_any_transform = true;
-        self.had_telex_transform = true; // dd pattern detected
+        self.word.had_any_transform = true;
+        self.word.had_telex_transform = true; // dd pattern detected
         Some(self.rebuild_from(pos))
     }
 
@@ -1498,18 +3068,18 @@ impl Engine {
 
         // Issue #44: Cancel pending breve if same modifier pressed again ("aww" → "aw")
         // When breve was deferred and user presses 'w' again, cancel without adding another 'w'
-        if self.pending_breve_pos.is_some()
+        if self.word.pending_breve_pos.is_some()
             && (tone_type == ToneType::Horn || tone_type == ToneType::Breve)
         {
             // Cancel the pending breve - user doesn't want Vietnamese
-            self.pending_breve_pos = None;
+            self.word.pending_breve_pos = None;
             // Return "consumed but no change" to prevent 'w' from being typed
             // action=Send with 0 backspace and 0 chars effectively consumes the key
             return Some(Result::send(0, &[]));
         }
 
         // Check revert first (same key pressed twice)
-        if let Some(Transform::Tone(last_key, _)) = self.last_transform {
+        if let Some(Transform::Tone(last_key, _)) = self.word.last_transform {
             if last_key == key {
                 return Some(self.revert_tone(key, caps));
             }
@@ -1517,7 +3087,7 @@ impl Engine {
 
         // Issue #211: Extended vowel mode - skip circumflex transform after revert
         // After aaa→aa revert, aaaa should become aaa (append raw), not aâ (re-transform)
-        if self.reverted_circumflex_key == Some(key) && tone_type == ToneType::Circumflex {
+        if self.word.reverted_circumflex_key == Some(key) && tone_type == ToneType::Circumflex {
             return None; // Let normal letter handling append raw vowel
         }
 
@@ -1525,9 +3095,7 @@ impl Engine {
         // Skip validation if free_tone mode is enabled
         let buffer_keys: Vec<u16> = self.buf.iter().map(|c| c.key).collect();
 
-        if !self.free_tone_enabled
-            && !is_valid_for_transform_with_foreign(&buffer_keys, self.allow_foreign_consonants)
-        {
+        if !self.free_tone_enabled && !self.accepts_for_transform(&buffer_keys) {
             return None;
         }
 
@@ -1584,17 +3152,17 @@ impl Engine {
                         if preceded_by_q {
                             // "Qu-" pattern - only second vowel gets horn
                             target_positions.push(pos2);
-                            self.pending_u_horn_pos = None;
+                            self.word.pending_u_horn_pos = None;
                         } else if is_uo_pattern && !has_final {
                             // "uơ" pattern - only 'o' gets horn initially
                             // Set pending so 'u' gets horn if final consonant/vowel is added
                             target_positions.push(pos2);
-                            self.pending_u_horn_pos = Some(pos1);
+                            self.word.pending_u_horn_pos = Some(pos1);
                         } else {
                             // "ươ" pattern (or has final) - both get horn
                             target_positions.push(pos1);
                             target_positions.push(pos2);
-                            self.pending_u_horn_pos = None;
+                            self.word.pending_u_horn_pos = None;
                         }
                     }
                 }
@@ -1990,8 +3558,8 @@ impl Engine {
                                         // Apply circumflex to first vowel
                                         if let Some(c) = self.buf.get_mut(i) {
                                             c.tone = tone::CIRCUMFLEX;
-                                            self.had_any_transform = true;
-                                            self.had_vowel_triggered_circumflex = true;
+                                            self.word.had_any_transform = true;
+                                            self.word.had_vowel_triggered_circumflex = true;
                                         }
                                         // Don't add the trigger vowel - return result immediately
                                         // Need extra backspace because we're replacing displayed char
@@ -2025,7 +3593,7 @@ impl Engine {
             // EXCEPTION: Don't absorb 'w' if last_transform was WAsVowel
             // because try_w_as_vowel needs to handle the revert (ww → w)
             let is_w_revert_pending =
-                key == keys::W && matches!(self.last_transform, Some(Transform::WAsVowel));
+                key == keys::W && matches!(self.word.last_transform, Some(Transform::WAsVowel));
 
             let has_tone_already = self
                 .buf
@@ -2130,8 +3698,11 @@ impl Engine {
             // - But "aw" ending makes it look like English
             // Only restore if buffer has EARLIER transforms (tone or mark)
             // Don't restore for simple "aw" or "raw" - let breve deferral handle those
-            // Only run if english_auto_restore is enabled (experimental feature)
-            if self.english_auto_restore && key == keys::W && self.raw_input.len() >= 2 {
+            // Only run from the Balanced auto-restore level (experimental feature)
+            if self.auto_restore >= AutoRestoreLevel::Balanced
+                && key == keys::W
+                && self.raw_input.len() >= 2
+            {
                 let (prev_key, _, _) = self.raw_input[self.raw_input.len() - 2];
                 if prev_key == keys::A {
                     // Check if there are earlier Vietnamese transforms in buffer
@@ -2157,7 +3728,7 @@ impl Engine {
                         let backspace = self.buf.len() as u8;
                         self.buf.clear();
                         self.raw_input.clear();
-                        self.last_transform = None;
+                        self.word.last_transform = None;
                         return Some(Result::send(backspace, &raw_chars));
                     }
                 }
@@ -2200,7 +3771,7 @@ impl Engine {
                         if c.key == keys::A {
                             c.tone = tone::NONE;
                             // Store position for deferred breve
-                            self.pending_breve_pos = Some(pos);
+                            self.word.pending_breve_pos = Some(pos);
                         }
                     }
                 }
@@ -2217,9 +3788,9 @@ impl Engine {
             earliest_pos = earliest_pos.min(compound_pos);
         }
 
-        self.last_transform = Some(Transform::Tone(key, tone_val));
-        self.had_any_transform = true;
-        self.had_telex_transform = true; // Track for whitelist-based auto-restore
+        self.word.last_transform = Some(Transform::Tone(key, tone_val));
+        self.word.had_any_transform = true;
+        self.word.had_telex_transform = true; // Track for whitelist-based auto-restore
 
         // Reposition tone mark if vowel pattern changed
         let mut rebuild_pos = earliest_pos;
@@ -2237,7 +3808,7 @@ impl Engine {
         }
 
         // Check revert first
-        if let Some(Transform::Mark(last_key, _)) = self.last_transform {
+        if let Some(Transform::Mark(last_key, _)) = self.word.last_transform {
             if last_key == key {
                 return Some(self.revert_mark(key, caps));
             }
@@ -2279,7 +3850,7 @@ impl Engine {
         // When user types "aws" (Telex) or "a81" (VNI), they want "ắ" (breve + sắc)
         // Breve was deferred due to open syllable, but adding mark confirms Vietnamese input
         let mut had_pending_breve = false;
-        if let Some(breve_pos) = self.pending_breve_pos {
+        if let Some(breve_pos) = self.word.pending_breve_pos {
             had_pending_breve = true;
             // Try to find and remove the breve modifier from buffer
             // Both Telex 'w' and VNI '8' are stored in buffer (handle_normal_letter adds them)
@@ -2296,10 +3867,10 @@ impl Engine {
             if let Some(c) = self.buf.get_mut(breve_pos) {
                 if c.key == keys::A {
                     c.tone = tone::HORN; // HORN on A = breve (ă)
-                    self.had_any_transform = true;
+                    self.word.had_any_transform = true;
                 }
             }
-            self.pending_breve_pos = None;
+            self.word.pending_breve_pos = None;
         }
 
         // Telex: Check for delayed circumflex pattern (V + C + V where both V are same)
@@ -2395,7 +3966,7 @@ impl Engine {
                         && second_vowel_at_end
                         && has_valid_vietnamese_initial
                         && !has_vietnamese_double_initial
-                        && !self.had_circumflex_revert
+                        && !self.word.had_circumflex_revert
                     {
                         // Skip delayed circumflex if raw_input is an English word
                         // This prevents "pasta" → "pất", "costa" → "côt", etc.
@@ -2446,7 +4017,7 @@ impl Engine {
                                 // Apply circumflex to first vowel
                                 if let Some(c) = self.buf.get_mut(pos1) {
                                     c.tone = tone::CIRCUMFLEX;
-                                    self.had_any_transform = true;
+                                    self.word.had_any_transform = true;
                                 }
                                 // Remove second vowel (it was just a trigger)
                                 self.buf.remove(pos2);
@@ -2473,7 +4044,7 @@ impl Engine {
         if !self.free_tone_enabled
             && !has_horn_transforms
             && !has_stroke_transforms
-            && !is_valid_for_transform_with_foreign(&buffer_keys, self.allow_foreign_consonants)
+            && !self.accepts_for_transform(&buffer_keys)
         {
             return None;
         }
@@ -2586,10 +4157,10 @@ impl Engine {
 
         if let Some(c) = self.buf.get_mut(pos) {
             c.mark = mark_val;
-            self.last_transform = Some(Transform::Mark(key, mark_val));
-            self.had_any_transform = true;
-            self.had_telex_transform = true; // Track for whitelist-based auto-restore
-                                             // Rebuild from the earlier position if compound was formed
+            self.word.last_transform = Some(Transform::Mark(key, mark_val));
+            self.word.had_any_transform = true;
+            self.word.had_telex_transform = true; // Track for whitelist-based auto-restore
+                                                  // Rebuild from the earlier position if compound was formed
             let mut rebuild_pos = rebuild_from_compound.map_or(pos, |cp| cp.min(pos));
 
             // If delayed stroke was applied, rebuild from position 0
@@ -3111,21 +4682,21 @@ impl Engine {
 
     /// Revert tone transformation
     fn revert_tone(&mut self, key: u16, caps: bool) -> Result {
-        self.last_transform = None;
+        self.word.last_transform = None;
         // Issue #211: Track which vowel triggered revert for extended vowel mode
         // After revert, subsequent same-key vowels append raw instead of re-transforming
-        self.reverted_circumflex_key = Some(key);
+        self.word.reverted_circumflex_key = Some(key);
 
         for pos in self.buf.find_vowels().into_iter().rev() {
             if let Some(c) = self.buf.get_mut(pos) {
                 if c.tone > tone::NONE {
                     c.tone = tone::NONE;
                     // Track for auto-restore logic (double ss/ff detection)
-                    self.had_mark_revert = true;
+                    self.word.had_mark_revert = true;
                     // Track ww pattern for whitelist-based restore
-                    self.had_telex_transform = true;
+                    self.word.had_telex_transform = true;
                     // Store raw_input BEFORE modification for whitelist lookup
-                    self.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
+                    self.word.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
                     // Fix raw_input: "ww" typed → raw has [w,w] but buffer is "w"
                     // Remove the tone-triggering key from raw_input so restore works correctly
                     // raw_input: [a, w, w] → [a, w] (remove first 'w' that triggered tone)
@@ -3138,7 +4709,7 @@ impl Engine {
                         }
                     }
                     // Store length AFTER modification
-                    self.telex_double_raw_len = self.raw_input.len();
+                    self.word.telex_double_raw_len = self.raw_input.len();
                     return self.revert_and_rebuild(pos, key, caps);
                 }
             }
@@ -3151,14 +4722,14 @@ impl Engine {
     /// Standard behavior: "ass" → "as" (first 's' was modifier, second 's' reverts + outputs one 's')
     /// This matches standard Vietnamese IME behavior (UniKey, ibus-unikey, etc.)
     fn revert_mark(&mut self, key: u16, caps: bool) -> Result {
-        self.last_transform = None;
-        self.had_mark_revert = true; // Track for auto-restore
-                                     // Set had_telex_transform for whitelist-based auto-restore
-                                     // This allows "taxxi" → "taxi" (not in whitelist → keep buffer)
-        self.had_telex_transform = true;
+        self.word.last_transform = None;
+        self.word.had_mark_revert = true; // Track for auto-restore
+                                          // Set had_telex_transform for whitelist-based auto-restore
+                                          // This allows "taxxi" → "taxi" (not in whitelist → keep buffer)
+        self.word.had_telex_transform = true;
         // Store raw_input for whitelist lookup
-        self.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
-        self.telex_double_raw_len = self.raw_input.len();
+        self.word.telex_double_raw = Some(self.get_raw_input_string_preserve_case());
+        self.word.telex_double_raw_len = self.raw_input.len();
 
         for pos in self.buf.find_vowels().into_iter().rev() {
             if let Some(c) = self.buf.get_mut(pos) {
@@ -3170,7 +4741,7 @@ impl Engine {
                     //   Example: "tesst" → next is 't' (consonant) → pop → "test"
                     // If next key is VOWEL: don't pop (user typing English word like "issue")
                     //   Example: "issue" → next is 'u' (vowel) → keep → "issue"
-                    self.pending_mark_revert_pop = true;
+                    self.word.pending_mark_revert_pop = true;
 
                     // Add only the reverting key (current key being pressed)
                     // The original mark key was consumed as a modifier and doesn't produce output
@@ -3192,7 +4763,7 @@ impl Engine {
 
     /// Revert stroke transformation at specific position
     fn revert_stroke(&mut self, key: u16, pos: usize) -> Result {
-        self.last_transform = None;
+        self.word.last_transform = None;
 
         if let Some(c) = self.buf.get_mut(pos) {
             if c.key == keys::D && !c.stroke {
@@ -3209,7 +4780,7 @@ impl Engine {
     /// Returns Some(Result) if a mark/tone was removed, None if nothing to remove
     /// When None is returned, the key falls through to handle_normal_letter()
     fn try_remove(&mut self) -> Option<Result> {
-        self.last_transform = None;
+        self.word.last_transform = None;
         for pos in self.buf.find_vowels().into_iter().rev() {
             if let Some(c) = self.buf.get_mut(pos) {
                 if c.mark > mark::NONE {
@@ -3232,12 +4803,12 @@ impl Engine {
         // Special case: "o" after "w→ư" should form "ươ" compound
         // This only handles the WAsVowel case (typing "w" alone creates ư)
         // For "uw" pattern, the compound is normalized in try_mark via normalize_uo_compound
-        if key == keys::O && matches!(self.last_transform, Some(Transform::WAsVowel)) {
+        if key == keys::O && matches!(self.word.last_transform, Some(Transform::WAsVowel)) {
             // Add O with horn to form ươ compound
             let mut c = Char::new(key, caps);
             c.tone = tone::HORN;
             self.buf.push(c);
-            self.last_transform = None;
+            self.word.last_transform = None;
 
             // Return the ơ character (o with horn)
             let vowel_char = chars::to_char(keys::O, caps, tone::HORN, 0).unwrap();
@@ -3255,7 +4826,7 @@ impl Engine {
         // immediate circumflex (VV pattern like "deep" → "dêp"). For immediate circumflex,
         // typing another vowel should NOT revert (allows words like "deeper").
         if self.method == 0
-            && self.had_vowel_triggered_circumflex
+            && self.word.had_vowel_triggered_circumflex
             && matches!(key, keys::A | keys::E | keys::O)
             && self.buf.len() >= 2
         {
@@ -3282,9 +4853,9 @@ impl Engine {
                     c.tone = tone::NONE;
                 }
                 // Reset vowel-triggered circumflex flag since we're reverting
-                self.had_vowel_triggered_circumflex = false;
+                self.word.had_vowel_triggered_circumflex = false;
                 // Track circumflex revert for auto-restore (used to collapse double vowel at end)
-                self.had_circumflex_revert = true;
+                self.word.had_circumflex_revert = true;
 
                 // Add the typed vowel to buffer (the one that triggered revert)
                 // "dataa" flow: "dât" (3 chars) → revert â → "dat" → add 'a' → "data" (4 chars)
@@ -3344,7 +4915,7 @@ impl Engine {
                     // Add circumflex to the vowel (keeping existing mark)
                     if let Some(c) = self.buf.get_mut(vowel_idx) {
                         c.tone = tone::CIRCUMFLEX;
-                        self.had_any_transform = true;
+                        self.word.had_any_transform = true;
                     }
 
                     // Note: raw_input already has the key (pushed at on_key_ext before process)
@@ -3357,7 +4928,7 @@ impl Engine {
             }
         }
 
-        self.last_transform = None;
+        self.word.last_transform = None;
         // Add letters to buffer, and numbers in both Telex and VNI modes
         // This ensures buffer.len() stays in sync with screen chars for correct backspace count
         // Issue #162: Numbers must be added to buffer in Telex mode too, otherwise patterns
@@ -3369,7 +4940,7 @@ impl Engine {
 
             // Issue #44 (part 2): Apply deferred breve when valid final consonant is typed
             // "trawm" → after "traw" (pending breve on 'a'), typing 'm' applies breve → "trăm"
-            if let Some(breve_pos) = self.pending_breve_pos {
+            if let Some(breve_pos) = self.word.pending_breve_pos {
                 // Valid final consonants that make breve valid: c, k, m, n, p, t
                 // Note: k is included for ethnic minority words (Đắk Lắk)
                 if matches!(
@@ -3393,10 +4964,10 @@ impl Engine {
                     if let Some(c) = self.buf.get_mut(breve_pos) {
                         if c.key == keys::A {
                             c.tone = tone::HORN; // HORN on A = breve (ă)
-                            self.had_any_transform = true;
+                            self.word.had_any_transform = true;
                         }
                     }
-                    self.pending_breve_pos = None;
+                    self.word.pending_breve_pos = None;
 
                     // Rebuild from breve position: delete "aw" (or "awX"), output "ăX"
                     // Buffer now has: ...ă (at breve_pos) + consonant (just added)
@@ -3409,7 +4980,7 @@ impl Engine {
                     // It will be added as a regular letter and removed later
                 } else if keys::is_vowel(key) {
                     // Vowel after "aw" pattern - breve not valid, clear pending
-                    self.pending_breve_pos = None;
+                    self.word.pending_breve_pos = None;
                 }
                 // For other consonants (not finals, not W), keep pending_breve_pos
                 // They might be followed by more letters that complete the syllable
@@ -3417,15 +4988,15 @@ impl Engine {
 
             // Issue #133: Apply deferred horn to 'u' when final consonant/vowel is typed
             // "duow" → "duơ" (pending on u), then "c" → apply horn to u → "dược"
-            if let Some(u_pos) = self.pending_u_horn_pos {
+            if let Some(u_pos) = self.word.pending_u_horn_pos {
                 // Apply horn to 'u' at pending position
                 if let Some(c) = self.buf.get_mut(u_pos) {
                     if c.key == keys::U && c.tone == tone::NONE {
                         c.tone = tone::HORN;
-                        self.had_any_transform = true;
+                        self.word.had_any_transform = true;
                     }
                 }
-                self.pending_u_horn_pos = None;
+                self.word.pending_u_horn_pos = None;
 
                 // Rebuild from u position: screen has "...uơ...", buffer has "...ươ...+new_char"
                 // The new char was already pushed at line 1799 but not yet on screen
@@ -3489,8 +5060,8 @@ impl Engine {
             // Exception: complete ươ compound + vowel = valid Vietnamese triphthong
             // (like "rượu" = ươu, "mười" = ươi) - don't revert in these cases
             // Only skip for vowels that form valid triphthongs (u, i), not for consonants
-            // Only run foreign word detection if english_auto_restore is enabled
-            if self.english_auto_restore {
+            // Only run foreign word detection from the Balanced auto-restore level
+            if self.auto_restore >= AutoRestoreLevel::Balanced {
                 let is_valid_triphthong_ending =
                     self.has_complete_uo_compound() && (key == keys::U || key == keys::I);
                 if self.has_w_as_vowel_transform() && !is_valid_triphthong_ending {
@@ -3515,10 +5086,10 @@ impl Engine {
             // IMPORTANT: Skip mark keys (s, f, r, x, j in Telex) because they're tone modifiers,
             // not true consonants. User typing "đườ" + 's' wants to add sắc mark, not restore.
             //
-            // Only run if english_auto_restore is enabled (experimental feature)
+            // Only run from the Balanced auto-restore level (experimental feature)
             let im = input::get(self.method);
             let is_mark_key = im.mark(key).is_some();
-            if self.english_auto_restore
+            if self.auto_restore >= AutoRestoreLevel::Balanced
                 && keys::is_consonant(key)
                 && !is_mark_key
                 && self.buf.len() >= 2
@@ -3531,6 +5102,10 @@ impl Engine {
                         // Clear English pattern detected - restore to raw
                         if let Some(raw_chars) = self.build_raw_chars() {
                             let backspace = (self.buf.len() - 1) as u8;
+                            self.events.push(Event::AutoRestored {
+                                raw: raw_chars.iter().collect(),
+                                vietnamese: self.buf.to_full_string(),
+                            });
 
                             // Repopulate buffer with restored content (plain chars, no marks)
                             self.buf.clear();
@@ -3538,7 +5113,7 @@ impl Engine {
                                 self.buf.push(Char::new(key, caps));
                             }
 
-                            self.last_transform = None;
+                            self.word.last_transform = None;
                             return Result::send(backspace, &raw_chars);
                         }
                     }
@@ -3549,7 +5124,7 @@ impl Engine {
             // Mark that this word has non-letter prefix to prevent false shortcut matches
             // e.g., "149k" should NOT trigger shortcut "k" → "không"
             // e.g., "@abc" should NOT trigger shortcut "abc"
-            self.has_non_letter_prefix = true;
+            self.word.has_non_letter_prefix = true;
         }
         Result::none()
     }
@@ -3697,26 +5272,18 @@ impl Engine {
             self.pending_capitalize = true;
             self.auto_capitalize_used = false;
         }
+        if self.incognito {
+            self.wipe_word();
+        }
         self.buf.clear();
         self.raw_input.clear();
-        self.last_transform = None;
-        self.has_non_letter_prefix = false;
-        self.pending_breve_pos = None;
-        self.pending_u_horn_pos = None;
-        self.stroke_reverted = false;
-        self.had_mark_revert = false;
-        self.pending_mark_revert_pop = false;
-        self.had_any_transform = false;
-        self.had_vowel_triggered_circumflex = false;
-        self.had_circumflex_revert = false;
-        self.reverted_circumflex_key = None;
-        self.had_telex_transform = false;
-        self.telex_double_raw = None;
-        self.telex_double_raw_len = 0;
-        self.restored_pending_clear = false;
-        self.restored_is_ascii = false;
+        self.word = WordState::default();
+        self.undo_history.clear();
         self.shortcut_prefix.clear();
         self.typed_after_space = false;
+        self.literal_next = false;
+        self.acronym_literal = false;
+        self.segments.clear();
         // Note: DO NOT clear last_break_key, saw_sentence_ending, or pending_capitalize here
         // These need to persist across word boundaries for auto-capitalize to work correctly
     }
@@ -3726,8 +5293,55 @@ impl Engine {
     /// to prevent accidental restore from stale history
     pub fn clear_all(&mut self) {
         self.clear();
+        if self.incognito {
+            self.word_history.wipe();
+            self.preedit.wipe();
+            if let Some((shown, other)) = self.last_word.as_mut() {
+                wipe_string(shown);
+                wipe_string(other);
+            }
+            self.last_word = None;
+        }
         self.word_history.clear();
         self.spaces_after_commit = 0;
+        self.preedit.clear();
+        self.end_raw_mode();
+        self.reset_code_span();
+    }
+
+    /// Overwrite the current word's text (incognito `clear`)
+    fn wipe_word(&mut self) {
+        self.buf.wipe();
+        wipe::wipe_vec(&mut self.raw_input, (0, false, false));
+        self.word.wipe();
+        self.undo_history.wipe();
+        wipe_string(&mut self.shortcut_prefix);
+        for segment in self.segments.iter_mut() {
+            segment.wipe();
+        }
+    }
+
+    /// Capture the current word: buffer, raw keystrokes and per-word flags
+    ///
+    /// Attach `snapshot().to_json()` to a bug report to reproduce the exact
+    /// state the next key sees.
+    pub fn snapshot(&self) -> WordSnapshot {
+        WordSnapshot {
+            buf: self.buf.clone(),
+            raw_input: self.raw_input.clone(),
+            state: self.word.clone(),
+        }
+    }
+
+    /// Resume typing from a snapshot (see `snapshot`)
+    ///
+    /// Replaces the current word; undo steps, syllable segments and the
+    /// shortcut prefix start empty. Settings and word history are kept.
+    pub fn restore(&mut self, snapshot: &WordSnapshot) {
+        self.clear();
+        self.buf = snapshot.buf.clone();
+        self.raw_input = snapshot.raw_input.clone();
+        self.word = snapshot.state.clone();
     }
 
     /// Get the full composed buffer as a Vietnamese string with diacritics.
@@ -3739,7 +5353,7 @@ impl Engine {
 
     /// Debug: Check if vowel-triggered circumflex flag is set
     pub fn had_vowel_circumflex(&self) -> bool {
-        self.had_vowel_triggered_circumflex
+        self.word.had_vowel_triggered_circumflex
     }
 
     /// Debug: Get raw_input length
@@ -3778,9 +5392,143 @@ impl Engine {
         // This allows: click on "shortcuts" → type "Nuw" → get "Nư" (not "shortcutsNuw")
         // But mark/tone keys like 's' will still work to modify the restored word
         if !self.buf.is_empty() {
-            self.restored_pending_clear = true;
-            self.restored_is_ascii = is_ascii;
+            self.word.restored_pending_clear = true;
+            self.word.restored_is_ascii = is_ascii;
+        }
+    }
+
+    /// Swap the last committed word between Vietnamese and raw keystrokes
+    ///
+    /// Fixes a wrong auto-restore guess without retyping: `saan ` ↔ `sân `,
+    /// `tẽt ` ↔ `text `. Works right after the word's trailing space(s), which
+    /// are deleted and retyped along with the word. Calling it again swaps back.
+    ///
+    /// Returns `Action::None` if there is no committed word to toggle.
+    pub fn toggle_last_word(&mut self) -> Result {
+        if !self.buf.is_empty() || self.spaces_after_commit == 0 {
+            return Result::none();
+        }
+        let Some((shown, other)) = self.last_word.take() else {
+            return Result::none();
+        };
+
+        let spaces = self.spaces_after_commit as usize;
+        let mut output: Vec<char> = other.chars().collect();
+        output.extend(std::iter::repeat_n(' ', spaces));
+        let backspace = (shown.chars().count() + spaces) as u8;
+
+        // Backspace-after-space restores the form now on screen
+        self.word_history.pop();
+        self.word_history.push(word_buffer(&other));
+        self.last_word = Some((other, shown));
+        Result::send(backspace, &output)
+    }
+
+    /// Cycle the case of the current word: lower → Title → UPPER → lower
+    ///
+    /// Works on the composing word, or right after a committed word (its
+    /// trailing spaces are retyped). Diacritics are kept; only the changed
+    /// chars are replaced. A mixed-case word goes to lower case.
+    ///
+    /// Clears `auto_capitalize_used`: the user picked the case, so lowering an
+    /// auto-capitalized letter does not re-trigger auto-capitalize.
+    ///
+    /// Returns `Action::None` if there is no word to change.
+    pub fn cycle_case(&mut self) -> Result {
+        if !self.buf.is_empty() {
+            let old: Vec<char> = self.buf.to_full_string().chars().collect();
+            let caret = self.buf.caret();
+            let case = next_case(&self.buf);
+            for i in 0..self.buf.len() {
+                if let Some(c) = self.buf.get_mut(i) {
+                    c.caps = case.caps_at(i);
+                }
+            }
+            for (i, entry) in self.raw_input.iter_mut().enumerate() {
+                entry.1 = case.caps_at(i);
+            }
+            self.auto_capitalize_used = false;
+            let new: Vec<char> = self.buf.to_full_string().chars().collect();
+            return edit_result(&old, caret, &new, caret, None);
+        }
+
+        if self.spaces_after_commit == 0 {
+            return Result::none();
+        }
+        let Some(mut word) = self.word_history.pop() else {
+            return Result::none();
+        };
+        let spaces = " ".repeat(self.spaces_after_commit as usize);
+        let old: Vec<char> = (word.to_full_string() + &spaces).chars().collect();
+        let case = next_case(&word);
+        for i in 0..word.len() {
+            if let Some(c) = word.get_mut(i) {
+                c.caps = case.caps_at(i);
+            }
+        }
+        let new: Vec<char> = (word.to_full_string() + &spaces).chars().collect();
+        self.word_history.push(word);
+        if let Some((shown, other)) = self.last_word.take() {
+            self.last_word = Some((case.apply(&shown), case.apply(&other)));
+        }
+        edit_result(&old, old.len(), &new, new.len(), None)
+    }
+
+    /// Word produced by typing `keystrokes` without English auto-restore
+    fn vietnamese_form(&self, keystrokes: &[(u16, bool, bool)]) -> String {
+        let mut e = Engine::new();
+        e.apply_settings(&EngineSettings {
+            auto_restore_level: AutoRestoreLevel::Off as u8,
+            output_mode: OutputMode::Diff as u8,
+            ..self.settings()
+        });
+        for &(key, caps, shift) in keystrokes {
+            e.on_key_diff(key, caps, false, shift);
+        }
+        e.buf.to_full_string()
+    }
+
+    /// Load the syllable around the caret from the text surrounding it
+    ///
+    /// `cursor` is a char offset into `text`. Lets the engine edit any word
+    /// under the cursor, e.g. add a tone to a word in the middle of a sentence.
+    ///
+    /// Returns (before, after): chars of the word before and after the caret,
+    /// or None (buffer cleared) if there is no word at the caret.
+    /// The buffer caret is placed at `before`, so edits are caret-relative
+    /// (`Result.delete_after` / `Result.caret_back`).
+    pub fn set_surrounding_text(&mut self, text: &str, cursor: usize) -> Option<(usize, usize)> {
+        // Caret moved: history and pending state are stale
+        self.clear_all();
+
+        let chars: Vec<char> = text.chars().collect();
+        if cursor > chars.len() {
+            return None;
+        }
+        let is_word_char = |c: &char| chars::parse_char(*c).is_some();
+        let before = chars[..cursor]
+            .iter()
+            .rev()
+            .take_while(|c| is_word_char(c))
+            .count();
+        let after = chars[cursor..]
+            .iter()
+            .take_while(|c| is_word_char(c))
+            .count();
+        if before + after == 0 {
+            return None;
         }
+
+        let word: String = chars[cursor - before..cursor + after].iter().collect();
+        self.restore_word(&word);
+        self.word_confirmed = true;
+        if after > 0 {
+            // Caret inside the word: letters are inserted at the caret
+            self.buf.set_caret(before);
+            self.word.restored_pending_clear = false;
+            self.word.restored_is_ascii = false;
+        }
+        Some((before, after))
     }
 
     /// Check if buffer has transforms and is invalid Vietnamese
@@ -3790,7 +5538,7 @@ impl Engine {
     ///                     false when called mid-word (during typing)
     fn should_auto_restore(&self, is_word_complete: bool) -> Option<Vec<char>> {
         // Only run auto-restore if the feature is enabled
-        if !self.english_auto_restore {
+        if self.auto_restore == AutoRestoreLevel::Off {
             return None;
         }
 
@@ -3803,7 +5551,7 @@ impl Engine {
         // where the buffer is invalid Vietnamese but no transforms were ever attempted
         // Also handles words with invalid initials like "forr" - since 'f' is not valid,
         // no mark was ever applied, so the result stays "forr" (not collapsed to "for")
-        if !self.had_any_transform {
+        if !self.word.had_any_transform {
             return None;
         }
 
@@ -3812,7 +5560,7 @@ impl Engine {
         // not English that needs to be restored. Detect by checking if:
         // 1. reverted_circumflex_key is set (revert happened)
         // 2. All vowels in buffer are the same key (extended pattern)
-        if self.reverted_circumflex_key.is_some() {
+        if self.word.reverted_circumflex_key.is_some() {
             let vowels: Vec<u16> = self
                 .buf
                 .iter()
@@ -3867,15 +5615,15 @@ impl Engine {
 
         // TELEX DOUBLES WHITELIST CHECK
         // Check whitelist for words with telex patterns (s/f/r/x/j tones, aa/ee/oo marks, dd stroke)
-        if self.had_telex_transform {
+        if self.word.had_telex_transform {
             // Build raw string for whitelist lookup
-            let raw_str = if let Some(ref stored) = self.telex_double_raw {
+            let raw_str = if let Some(ref stored) = self.word.telex_double_raw {
                 // Double revert pattern occurred (xx, ss, dd, etc.)
                 // Build full raw string including subsequent chars typed after revert
-                let subsequent_start = if self.raw_input.len() < self.telex_double_raw_len {
-                    self.telex_double_raw_len.saturating_sub(1)
+                let subsequent_start = if self.raw_input.len() < self.word.telex_double_raw_len {
+                    self.word.telex_double_raw_len.saturating_sub(1)
                 } else {
-                    self.telex_double_raw_len
+                    self.word.telex_double_raw_len
                 };
                 let subsequent: String = self
                     .raw_input
@@ -3947,9 +5695,9 @@ impl Engine {
             // looks clean but should restore to "mass" if "mass" is in dictionary.
             // IMPORTANT: Only apply when ss/ff is at END of complete word (no subsequent chars)
             // For "masson" (ss in middle), let normal collapse logic handle it → "mason"
-            if let Some(ref stored) = self.telex_double_raw {
+            if let Some(ref stored) = self.word.telex_double_raw {
                 // Check if any chars were typed AFTER the double pattern
-                let has_subsequent_chars = self.raw_input.len() > self.telex_double_raw_len;
+                let has_subsequent_chars = self.raw_input.len() > self.word.telex_double_raw_len;
 
                 // Only apply this check when ss/ff is at the END of the word
                 if !has_subsequent_chars {
@@ -3991,7 +5739,7 @@ impl Engine {
             // Example: "reff" → buffer "ref" (no marks, no repeats) → keep "ref"
             // But: "assssess" → buffer "asssess" (has repeated 's') → continue to collapse
             // But: "prooff" → buffer "prôf" (has mark ô) → continue to other logic
-            if let Some(ref stored) = self.telex_double_raw {
+            if let Some(ref stored) = self.word.telex_double_raw {
                 let has_marks = self.buf.iter().any(|c| c.tone > 0 || c.mark > 0);
                 let has_stroke = self.buf.iter().any(|c| c.stroke);
                 let buffer_str = self.get_buffer_string();
@@ -4009,7 +5757,7 @@ impl Engine {
                 let subsequent_len = self
                     .raw_input
                     .len()
-                    .saturating_sub(self.telex_double_raw_len);
+                    .saturating_sub(self.word.telex_double_raw_len);
                 let full_restore_len = stored.len() + subsequent_len;
                 // If restored is more than 1 char longer than buffer, modifiers were consumed → restore
                 let raw_much_longer = full_restore_len > self.buf.len() + 1;
@@ -4133,7 +5881,7 @@ impl Engine {
         }
 
         // If user typed double TONE modifier (rr) at END of SHORT word, keep reverted form
-        if self.had_mark_revert && self.raw_input.len() >= 2 && self.raw_input.len() <= 4 {
+        if self.word.had_mark_revert && self.raw_input.len() >= 2 && self.raw_input.len() <= 4 {
             let (last_key, _, _) = self.raw_input[self.raw_input.len() - 1];
             let (second_last_key, _, _) = self.raw_input[self.raw_input.len() - 2];
             // Double 'rr' at end of short word → keep reverted form
@@ -4152,7 +5900,7 @@ impl Engine {
         // Distinguish between:
         // - V + doubled_modifier (issue, offer) → restore to raw (common English)
         // - C + V + doubled_modifier (carre) → keep buffer (Telex revert pattern)
-        if self.had_mark_revert && buffer_invalid_vn && raw_input_valid_en {
+        if self.word.had_mark_revert && buffer_invalid_vn && raw_input_valid_en {
             let tone_mods = [keys::S, keys::F, keys::R, keys::X, keys::J];
 
             // Find position of doubled modifier in raw_input
@@ -4301,7 +6049,7 @@ impl Engine {
         // Compare: "hôm" (circumflex + m) and "sân" (circumflex + n) are valid Vietnamese
         // NOTE: Use `had_vowel_triggered_circumflex` flag for accurate detection
         if is_word_complete
-            && self.had_vowel_triggered_circumflex
+            && self.word.had_vowel_triggered_circumflex
             && !has_stroke
             && raw_input_valid_en
         {
@@ -4333,7 +6081,7 @@ impl Engine {
         // like "issue" (buffer "isue" = 4 chars) or "worry" (buffer "wory" = 4 chars)
         // For no-initial patterns: V + modifier + modifier + V → buf = 3 chars
         if is_word_complete
-            && self.had_mark_revert
+            && self.word.had_mark_revert
             && self.buf.len() <= 3
             && raw_input_valid_en
             && !has_stroke
@@ -4552,6 +6300,26 @@ impl Engine {
             .collect()
     }
 
+    /// Check if keys form a valid syllable (foreign consonants and loanwords per settings)
+    fn accepts_syllable(&self, buffer_keys: &[u16]) -> bool {
+        let snap = BufferSnapshot::from_keys_with_foreign(
+            buffer_keys.to_vec(),
+            self.allow_foreign_consonants,
+        )
+        .with_loanwords(self.loanword_syllables);
+        validation::validate(&snap).is_valid_or_loanword()
+    }
+
+    /// Check if keys can take a transform (see `validation::validate_for_transform`)
+    fn accepts_for_transform(&self, buffer_keys: &[u16]) -> bool {
+        let snap = BufferSnapshot::from_keys_with_foreign(
+            buffer_keys.to_vec(),
+            self.allow_foreign_consonants,
+        )
+        .with_loanwords(self.loanword_syllables);
+        validation::validate_for_transform(&snap).is_valid_or_loanword()
+    }
+
     /// Check if buffer is NOT valid Vietnamese (for unified auto-restore logic)
     ///
     /// Uses full validation including tone requirements (circumflex for êu, etc.)
@@ -4568,12 +6336,19 @@ impl Engine {
         let buffer_marks: Vec<u8> = self.buf.iter().map(|c| c.mark).collect();
 
         // Check 1: Basic structural validation (with foreign consonants support)
-        if !is_valid_with_tones_and_foreign(
-            &buffer_keys,
-            &buffer_tones,
-            self.allow_foreign_consonants,
-        ) {
-            return true;
+        // Loanword syllables count as Vietnamese unless the raw keys are English
+        let snap = BufferSnapshot {
+            keys: buffer_keys.clone(),
+            tones: buffer_tones.clone(),
+            has_tone_info: true,
+            allow_foreign_consonants: self.allow_foreign_consonants,
+            allow_loanwords: self.loanword_syllables,
+        };
+        match validation::validate(&snap) {
+            ValidationResult::Valid => {}
+            ValidationResult::Loanword
+                if !english_dict::is_english_word(&self.get_raw_input_string()) => {}
+            _ => return true,
         }
 
         // Check 2: -ing + tone mark is NOT valid Vietnamese
@@ -4699,8 +6474,8 @@ impl Engine {
         // If telex_double_raw is stored (original input before modification), use it
         // plus any subsequent chars typed after the revert
         // Example: "daddy" → telex_double_raw="dadd", subsequent="y" → "daddy"
-        if let Some(ref raw_str) = self.telex_double_raw {
-            if !raw_str.is_empty() && self.telex_double_raw_len > 0 {
+        if let Some(ref raw_str) = self.word.telex_double_raw {
+            if !raw_str.is_empty() && self.word.telex_double_raw_len > 0 {
                 let mut result: Vec<char> = raw_str.chars().collect();
                 // Append subsequent chars from raw_input
                 // For stroke revert (dd): raw_input was modified (1 char removed)
@@ -4708,12 +6483,12 @@ impl Engine {
                 // For mark revert (ss): raw_input was NOT modified (deferred pop)
                 //   → subsequent_start = stored_len
                 // Detect by comparing current length with stored length
-                let subsequent_start = if self.raw_input.len() < self.telex_double_raw_len {
+                let subsequent_start = if self.raw_input.len() < self.word.telex_double_raw_len {
                     // raw_input was modified (1 char removed)
-                    self.telex_double_raw_len.saturating_sub(1)
+                    self.word.telex_double_raw_len.saturating_sub(1)
                 } else {
                     // raw_input was NOT modified
-                    self.telex_double_raw_len
+                    self.word.telex_double_raw_len
                 };
                 for i in subsequent_start..self.raw_input.len() {
                     if let Some(&(key, caps, shift)) = self.raw_input.get(i) {
@@ -4748,7 +6523,9 @@ impl Engine {
     /// - Triple vowel (aaa, eee, ooo) is collapsed to double vowel
     /// - This handles circumflex revert in Telex (aa=â, aaa=aa)
     fn build_raw_chars(&self) -> Option<Vec<char>> {
-        let raw_chars: Vec<char> = if self.had_mark_revert && self.should_use_buffer_for_revert() {
+        let raw_chars: Vec<char> = if self.word.had_mark_revert
+            && self.should_use_buffer_for_revert()
+        {
             // Use buffer content which already has the correct reverted form
             // e.g., "dissable" → "disable", "usser" → "user"
             self.buf.to_string_preserve_case().chars().collect()
@@ -4838,7 +6615,7 @@ impl Engine {
             // This happens when user types a-a-a and third 'a' reverts the circumflex
             // IMPORTANT: Use had_circumflex_revert, NOT had_mark_revert
             // had_mark_revert is set for tone marks (ff in coffee), which should NOT collapse
-            if self.had_circumflex_revert && chars.len() >= 2 {
+            if self.word.had_circumflex_revert && chars.len() >= 2 {
                 let last = chars[chars.len() - 1].to_ascii_lowercase();
                 let second_last = chars[chars.len() - 2].to_ascii_lowercase();
                 // Double vowel at very end (a/e/o)
@@ -4876,7 +6653,8 @@ impl Engine {
                 && tone_modifiers_char.contains(&chars[1].to_ascii_lowercase())
                 && chars[1].eq_ignore_ascii_case(&chars[2]);
 
-            if self.had_mark_revert && (self.buf.len() <= 3 || starts_with_u_doubled_modifier) {
+            if self.word.had_mark_revert && (self.buf.len() <= 3 || starts_with_u_doubled_modifier)
+            {
                 // Collapse consecutive double modifiers, but skip 'ss'/'ff' at the VERY END
                 // Examples:
                 // - "usser" → "user" (ss in middle, collapse)
@@ -6361,7 +8139,7 @@ impl Engine {
         }
 
         // Check for revert: if last transform was BracketAsVowel with same bracket
-        if self.last_transform == Some(Transform::BracketAsVowel) && !self.buf.is_empty() {
+        if self.word.last_transform == Some(Transform::BracketAsVowel) && !self.buf.is_empty() {
             if let Some(last_char) = self.buf.last() {
                 // Check if last char matches the bracket we're typing
                 let should_revert = match key {
@@ -6376,7 +8154,7 @@ impl Engine {
                     // Also remove from raw_input
                     self.raw_input.pop();
                     // Clear transform
-                    self.last_transform = None;
+                    self.word.last_transform = None;
 
                     // Return the original bracket character
                     // Use caps (Shift or CapsLock) to decide: uppercase → {/}, lowercase → [/]
@@ -6421,8 +8199,8 @@ impl Engine {
         self.raw_input.push((key, caps, false));
 
         // Mark transform
-        self.last_transform = Some(Transform::BracketAsVowel);
-        self.had_any_transform = true;
+        self.word.last_transform = Some(Transform::BracketAsVowel);
+        self.word.had_any_transform = true;
 
         // Return result with key consumed (don't pass through bracket)
         let vowel_char = chars::to_char(base_key, caps, tone::HORN, 0).unwrap();
@@ -6436,7 +8214,8 @@ impl Engine {
     /// Example: "tẽt" (from typing "text") → "text " (restored + space)
     /// Example: "ễpct" (from typing "expect") → "expect " (restored + space)
     fn try_auto_restore_on_space(&self) -> Result {
-        if let Some(mut raw_chars) = self.should_auto_restore(true) {
+        let restore = self.word_end_restore();
+        if let Some(mut raw_chars) = restore {
             // Add space at the end
             raw_chars.push(' ');
             // Backspace count = current buffer length (displayed chars)
@@ -6454,7 +8233,8 @@ impl Engine {
     /// Does NOT include the break key (it's passed through by the app).
     /// Example: "ễpct" + comma → "expect" (comma added by app)
     fn try_auto_restore_on_break(&self) -> Result {
-        if let Some(raw_chars) = self.should_auto_restore(true) {
+        let restore = self.word_end_restore();
+        if let Some(raw_chars) = restore {
             // Backspace count = current buffer length (displayed chars)
             let backspace = self.buf.len() as u8;
             Result::send(backspace, &raw_chars)
@@ -6477,11 +8257,11 @@ impl Engine {
         // Build raw ASCII output from raw_input history
         // If telex_double_raw is set (revert happened), use it as base and append subsequent chars
         // This ensures "aww" → ESC → "aww" (not "aw"), "a66" → ESC → "a66" (not "a6")
-        let raw_chars: Vec<char> = if let Some(ref base_raw) = self.telex_double_raw {
+        let raw_chars: Vec<char> = if let Some(ref base_raw) = self.word.telex_double_raw {
             // Start with the original raw string before revert modification
             let mut chars: Vec<char> = base_raw.chars().collect();
             // Append any characters typed after the revert
-            for &(key, caps, shift) in self.raw_input.iter().skip(self.telex_double_raw_len) {
+            for &(key, caps, shift) in self.raw_input.iter().skip(self.word.telex_double_raw_len) {
                 if let Some(ch) = utils::key_to_char_ext(key, caps, shift) {
                     chars.push(ch);
                 }
@@ -6506,7 +8286,7 @@ impl Engine {
         // Only restore if:
         // 1. Any transform was ever applied (even if later reverted), OR
         // 2. Buffer differs from raw input (handles edge cases)
-        if !self.had_any_transform && buffer_str == raw_str {
+        if !self.word.had_any_transform && buffer_str == raw_str {
             return Result::none();
         }
 
@@ -6924,4 +8704,30 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_is_raw_token() {
+        for token in [
+            "https:/", "WWW.", "a@", "~/", "./", "a/b/", "user_", "std::", "getU",
+        ] {
+            assert!(super::is_raw_token(token), "{:?} should be raw", token);
+        }
+        for token in ["vaf/", "Vieetj", "VIEETJ", "a.", "dj:", "#tag", "www"] {
+            assert!(!super::is_raw_token(token), "{:?} should not be raw", token);
+        }
+    }
+
+    #[test]
+    fn test_incognito_wipes_raw_token() {
+        let mut e = Engine::new();
+        e.set_incognito(true);
+        e.set_raw_mode_detection(true);
+        assert_eq!(type_word(&mut e, "me@mail.com "), "me@mail.com ");
+        assert!(e.raw_token.is_empty());
+        let token = &e.raw_token;
+        // SAFETY: the allocation is still owned by `raw_token`, and `wipe_string`
+        // zeroed all of it
+        let spare = unsafe { std::slice::from_raw_parts(token.as_ptr(), token.capacity()) };
+        assert!(spare.iter().all(|&b| b == 0), "Space wipes the token");
+    }
 }
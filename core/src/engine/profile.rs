@@ -0,0 +1,137 @@
+//! Settings Profiles - Named option sets switchable at runtime
+//!
+//! A profile bundles a full `EngineSettings` with the subset of shortcuts
+//! that should be active while it is selected. Users switch between e.g.
+//! "Chat" (Vietnamese prose) and "Code" (English, no auto-capitalize) with
+//! a single call instead of flipping options one by one.
+//!
+//! Activation goes through `Engine::activate_profile`, which applies
+//! everything in one step and keeps the in-progress word.
+
+use super::json::{self, Value};
+use super::settings::EngineSettings;
+
+/// A named settings profile
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    /// Profile name (matched case-insensitively)
+    pub name: String,
+    /// Full settings applied on activation
+    pub settings: EngineSettings,
+    /// Shortcut triggers active in this profile (None = all shortcuts)
+    pub shortcuts: Option<Vec<String>>,
+}
+
+impl Profile {
+    /// Create a profile that keeps all shortcuts active
+    pub fn new(name: &str, settings: EngineSettings) -> Self {
+        Self {
+            name: name.to_string(),
+            settings,
+            shortcuts: None,
+        }
+    }
+
+    /// Restrict the profile to the given shortcut triggers
+    pub fn with_shortcuts(mut self, triggers: &[&str]) -> Self {
+        self.shortcuts = Some(triggers.iter().map(|t| t.to_lowercase()).collect());
+        self
+    }
+
+    /// Check if profile has the given name (case-insensitive)
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name.to_lowercase() == name.to_lowercase()
+    }
+
+    /// Export as JSON: `{"name":..,"settings":{..},"shortcuts":[..] | null}`
+    pub fn to_json(&self) -> String {
+        let shortcuts = match &self.shortcuts {
+            Some(list) => Value::Array(list.iter().map(|t| Value::String(t.clone())).collect()),
+            None => Value::Null,
+        };
+        Value::Object(vec![
+            ("name".to_string(), Value::String(self.name.clone())),
+            ("settings".to_string(), self.settings.to_value()),
+            ("shortcuts".to_string(), shortcuts),
+        ])
+        .to_json()
+    }
+
+    /// Import from JSON. `settings` may be partial (defaults fill the rest),
+    /// `shortcuts` may be omitted or null to keep all shortcuts active.
+    pub fn from_json(input: &str) -> Option<Self> {
+        let value = json::parse(input)?;
+        let name = value.get("name")?.as_str()?.trim();
+        if name.is_empty() {
+            return None;
+        }
+        let settings = match value.get("settings") {
+            Some(s) => EngineSettings::from_value(s)?,
+            None => EngineSettings::default(),
+        };
+        let shortcuts = match value.get("shortcuts") {
+            None | Some(Value::Null) => None,
+            Some(list) => Some(
+                list.as_array()?
+                    .iter()
+                    .map(|t| t.as_str().map(|s| s.to_lowercase()))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+        };
+        Some(Self {
+            name: name.to_string(),
+            settings,
+            shortcuts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_roundtrip() {
+        let p = Profile::new(
+            "Code",
+            EngineSettings {
+                auto_restore_level: 2,
+                auto_capitalize: false,
+                ..Default::default()
+            },
+        )
+        .with_shortcuts(&["fn", "Impl"]);
+        let parsed = Profile::from_json(&p.to_json()).unwrap();
+        assert_eq!(parsed, p);
+        assert_eq!(
+            parsed.shortcuts,
+            Some(vec!["fn".to_string(), "impl".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_partial_json() {
+        let p = Profile::from_json(r#"{"name":"Chat","settings":{"version":1,"free_tone":true}}"#)
+            .unwrap();
+        assert!(p.settings.free_tone);
+        assert!(p.settings.modern_tone, "Missing keys use defaults");
+        assert_eq!(p.shortcuts, None);
+
+        let p = Profile::from_json(r#"{"name":"Game","settings":{"output_mode":2}}"#).unwrap();
+        assert_eq!(p.settings.output_mode, 2, "Unversioned numbers kept");
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        assert_eq!(Profile::from_json(r#"{"settings":{}}"#), None);
+        assert_eq!(Profile::from_json(r#"{"name":"  "}"#), None);
+        assert_eq!(Profile::from_json(r#"{"name":"A","shortcuts":[1]}"#), None);
+    }
+
+    #[test]
+    fn test_name_case_insensitive() {
+        let p = Profile::new("Formal", EngineSettings::default());
+        assert!(p.is_named("formal"));
+        assert!(!p.is_named("chat"));
+    }
+}
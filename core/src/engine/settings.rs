@@ -0,0 +1,520 @@
+//! Engine Settings - Unified configuration for all frontends
+//!
+//! Collects every engine option into one struct with defaults, so that
+//! macOS/Windows/Linux frontends share a single config format instead of
+//! calling a dozen setters and keeping their own stores.
+//!
+//! Serialization is serde-free (the core crate has no dependencies):
+//! - JSON via the minimal `json` module
+//! - TOML as flat `key = value` lines (same shape as Linux `config.toml`)
+//!
+//! Every document carries a `version` field. Older documents are migrated
+//! to `SETTINGS_VERSION` before being applied; unknown keys are ignored
+//! so newer frontends can store extra (UI-only) options in the same file.
+
+use super::auto_restore::AutoRestoreLevel;
+use super::composition::OutputMode;
+use super::json::{self, Value};
+
+/// Current settings schema version
+///
+/// History:
+/// - 0: legacy, unversioned (Linux `config.toml`, Windows registry names)
+/// - 1: unified snake_case keys
+/// - 2: `english_auto_restore` bool → `auto_restore_level` (0-3)
+pub const SETTINGS_VERSION: u32 = 2;
+
+/// All user-configurable engine options
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineSettings {
+    /// Input method: 0=Telex, 1=VNI
+    pub method: u8,
+    /// Vietnamese input enabled (shortcuts still work when disabled)
+    pub enabled: bool,
+    /// Skip w→ư at word start in Telex
+    pub skip_w_shortcut: bool,
+    /// Bracket shortcuts: ] → ư, [ → ơ (Issue #159)
+    pub bracket_shortcut: bool,
+    /// ESC restores raw ASCII input
+    pub esc_restore: bool,
+    /// Free tone placement (skip spelling validation)
+    pub free_tone: bool,
+    /// Modern tone placement (hoà) instead of traditional (hòa)
+    pub modern_tone: bool,
+    /// Auto-restore English words on word boundary:
+    /// 0=off, 1=dictionary-only, 2=balanced, 3=aggressive
+    pub auto_restore_level: u8,
+    /// Auto-capitalize after sentence-ending punctuation
+    pub auto_capitalize: bool,
+    /// Allow z, w, j, f as initial consonants
+    pub allow_foreign_consonants: bool,
+    /// Output mode: 0=diff (backspace + chars), 1=preedit + commit, 2=commit-only
+    pub output_mode: u8,
+    /// Backspace removes mark, then tone, then the letter
+    pub smart_backspace: bool,
+    /// LEFT/RIGHT move a caret inside the composing word
+    pub caret_editing: bool,
+    /// Idle time (ms) after which the next key starts fresh; 0 = never
+    pub idle_timeout_ms: u32,
+    /// URLs, emails, paths and code identifiers pass through untransformed
+    pub raw_mode_detection: bool,
+    /// No transforms inside Markdown `code` spans and ``` fences
+    pub markdown_code: bool,
+    /// All-caps words that aren't Vietnamese stay as typed (AWS, DDR5)
+    pub acronym_passthrough: bool,
+    /// Split words typed without spaces into syllables (việtnam)
+    pub syllable_segmentation: bool,
+    /// Accept loanword clusters and finals (blốc, Xtrét)
+    pub loanword_syllables: bool,
+    /// Switch Telex/VNI to match the first words typed
+    pub method_auto_detect: bool,
+    /// Key that undoes the last transform (Shift redoes); None = disabled
+    pub undo_key: Option<u16>,
+    /// Key that types the next keystroke as-is; None = disabled
+    pub literal_key: Option<u16>,
+}
+
+impl Default for EngineSettings {
+    /// Defaults match `Engine::new()`
+    fn default() -> Self {
+        Self {
+            method: 0,
+            enabled: true,
+            skip_w_shortcut: false,
+            bracket_shortcut: false,
+            esc_restore: false,
+            free_tone: false,
+            modern_tone: true,
+            auto_restore_level: 0,
+            auto_capitalize: false,
+            allow_foreign_consonants: false,
+            output_mode: 0,
+            smart_backspace: false,
+            caret_editing: false,
+            idle_timeout_ms: 0,
+            raw_mode_detection: false,
+            markdown_code: false,
+            acronym_passthrough: false,
+            syllable_segmentation: false,
+            loanword_syllables: false,
+            method_auto_detect: false,
+            undo_key: None,
+            literal_key: None,
+        }
+    }
+}
+
+/// Legacy (version 0) key names → unified key names
+const LEGACY_KEYS: &[(&str, &str)] = &[
+    // Linux config.toml
+    ("input_method", "method"),
+    // Windows registry value names
+    ("InputMethod", "method"),
+    ("Enabled", "enabled"),
+    ("SkipWShortcut", "skip_w_shortcut"),
+    ("EscRestore", "esc_restore"),
+    ("FreeTone", "free_tone"),
+    ("ModernTone", "modern_tone"),
+    ("EnglishAutoRestore", "english_auto_restore"),
+    ("AutoCapitalize", "auto_capitalize"),
+];
+
+impl EngineSettings {
+    /// Serialize settings as ordered (key, value) pairs, version first
+    pub(crate) fn to_value(&self) -> Value {
+        let num = |k: &str, v: u32| (k.to_string(), Value::Number(v as f64));
+        let flag = |k: &str, v: bool| (k.to_string(), Value::Bool(v));
+        let key = |k: &str, v: Option<u16>| {
+            let value = v.map_or(Value::Null, |v| Value::Number(v as f64));
+            (k.to_string(), value)
+        };
+        Value::Object(vec![
+            num("version", SETTINGS_VERSION),
+            num("method", self.method as u32),
+            flag("enabled", self.enabled),
+            flag("skip_w_shortcut", self.skip_w_shortcut),
+            flag("bracket_shortcut", self.bracket_shortcut),
+            flag("esc_restore", self.esc_restore),
+            flag("free_tone", self.free_tone),
+            flag("modern_tone", self.modern_tone),
+            num("auto_restore_level", self.auto_restore_level as u32),
+            flag("auto_capitalize", self.auto_capitalize),
+            flag("allow_foreign_consonants", self.allow_foreign_consonants),
+            num("output_mode", self.output_mode as u32),
+            flag("smart_backspace", self.smart_backspace),
+            flag("caret_editing", self.caret_editing),
+            num("idle_timeout_ms", self.idle_timeout_ms),
+            flag("raw_mode_detection", self.raw_mode_detection),
+            flag("markdown_code", self.markdown_code),
+            flag("acronym_passthrough", self.acronym_passthrough),
+            flag("syllable_segmentation", self.syllable_segmentation),
+            flag("loanword_syllables", self.loanword_syllables),
+            flag("method_auto_detect", self.method_auto_detect),
+            key("undo_key", self.undo_key),
+            key("literal_key", self.literal_key),
+        ])
+    }
+
+    /// Build settings from a parsed object, migrating old versions.
+    ///
+    /// Missing keys keep their defaults. Returns None if the version is
+    /// newer than this engine understands or a known key has the wrong type.
+    pub(crate) fn from_value(value: &Value) -> Option<Self> {
+        Self::default().with_overrides(value)
+    }
+
+    /// Return a copy with the keys present in `value` overridden.
+    ///
+    /// Used for partial settings objects (app rules); same migration and
+    /// type rules as `from_value`.
+    pub(crate) fn with_overrides(&self, value: &Value) -> Option<Self> {
+        let fields = value.as_object()?;
+        let version = match fields.iter().find(|(k, _)| k == "version") {
+            Some((_, v)) => v.as_u64()? as u32,
+            None => 0,
+        };
+        if version > SETTINGS_VERSION {
+            return None;
+        }
+
+        let fields = migrate(version, fields.to_vec());
+        let mut settings = self.clone();
+        for (key, value) in &fields {
+            settings.set_field(key, value)?;
+        }
+        Some(settings)
+    }
+
+    /// Fields of `self` that differ in `other`, as a partial settings object
+    ///
+    /// Applied to `other` with `with_overrides`, it undoes exactly those
+    /// changes and leaves every other field as it is.
+    pub(crate) fn diff(&self, other: &Self) -> Value {
+        let (Value::Object(ours), Value::Object(theirs)) = (self.to_value(), other.to_value())
+        else {
+            return Value::Object(Vec::new());
+        };
+        Value::Object(
+            ours.into_iter()
+                .zip(theirs)
+                .filter(|((key, ours), (_, theirs))| key == "version" || ours != theirs)
+                .map(|(field, _)| field)
+                .collect(),
+        )
+    }
+
+    /// Set a single field by key. Unknown keys are ignored (Some),
+    /// type mismatches are rejected (None).
+    fn set_field(&mut self, key: &str, value: &Value) -> Option<()> {
+        match key {
+            "method" => {
+                let m = value.as_u64()?;
+                if m > 1 {
+                    return None;
+                }
+                self.method = m as u8;
+            }
+            "enabled" => self.enabled = value.as_bool()?,
+            "skip_w_shortcut" => self.skip_w_shortcut = value.as_bool()?,
+            "bracket_shortcut" => self.bracket_shortcut = value.as_bool()?,
+            "esc_restore" => self.esc_restore = value.as_bool()?,
+            "free_tone" => self.free_tone = value.as_bool()?,
+            "modern_tone" => self.modern_tone = value.as_bool()?,
+            "auto_restore_level" => {
+                let level = value.as_u64()?;
+                AutoRestoreLevel::from_u8(u8::try_from(level).ok()?)?;
+                self.auto_restore_level = level as u8;
+            }
+            "auto_capitalize" => self.auto_capitalize = value.as_bool()?,
+            "allow_foreign_consonants" => self.allow_foreign_consonants = value.as_bool()?,
+            "output_mode" => {
+                let m = value.as_u64()?;
+                OutputMode::from_u8(u8::try_from(m).ok()?)?;
+                self.output_mode = m as u8;
+            }
+            "smart_backspace" => self.smart_backspace = value.as_bool()?,
+            "caret_editing" => self.caret_editing = value.as_bool()?,
+            "idle_timeout_ms" => self.idle_timeout_ms = u32::try_from(value.as_u64()?).ok()?,
+            "raw_mode_detection" => self.raw_mode_detection = value.as_bool()?,
+            "markdown_code" => self.markdown_code = value.as_bool()?,
+            "acronym_passthrough" => self.acronym_passthrough = value.as_bool()?,
+            "syllable_segmentation" => self.syllable_segmentation = value.as_bool()?,
+            "loanword_syllables" => self.loanword_syllables = value.as_bool()?,
+            "method_auto_detect" => self.method_auto_detect = value.as_bool()?,
+            "undo_key" => self.undo_key = key_field(value)?,
+            "literal_key" => self.literal_key = key_field(value)?,
+            _ => {}
+        }
+        Some(())
+    }
+
+    /// Export as compact JSON
+    pub fn to_json(&self) -> String {
+        self.to_value().to_json()
+    }
+
+    /// Import from JSON (see `from_value` for migration rules)
+    pub fn from_json(input: &str) -> Option<Self> {
+        Self::from_value(&json::parse(input)?)
+    }
+
+    /// Export as flat TOML (`key = value` per line)
+    ///
+    /// TOML has no null: unset keys (`undo_key`, `literal_key`) are left out.
+    pub fn to_toml(&self) -> String {
+        let mut out = String::new();
+        if let Value::Object(fields) = self.to_value() {
+            for (key, value) in fields {
+                if value == Value::Null {
+                    continue;
+                }
+                out.push_str(&key);
+                out.push_str(" = ");
+                out.push_str(&value.to_json());
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// Import from flat TOML.
+    ///
+    /// Supports the subset frontends write: `key = value` lines with bools,
+    /// integers and basic strings, `#` comments and blank lines.
+    /// Table headers (`[section]`) are skipped so a frontend can keep its own
+    /// sections in the same file; only top-level keys are read.
+    pub fn from_toml(input: &str) -> Option<Self> {
+        let mut fields = Vec::new();
+        let mut in_table = false;
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') {
+                in_table = true;
+                continue;
+            }
+            if in_table {
+                continue;
+            }
+            let (key, raw) = line.split_once('=')?;
+            let key = key.trim().trim_matches('"').to_string();
+            fields.push((key, parse_toml_value(raw.trim())?));
+        }
+        Self::from_value(&Value::Object(fields))
+    }
+}
+
+/// Key code field: null = disabled, otherwise a keycode
+fn key_field(value: &Value) -> Option<Option<u16>> {
+    match value {
+        Value::Null => Some(None),
+        _ => u16::try_from(value.as_u64()?).ok().map(Some),
+    }
+}
+
+/// Parse a TOML scalar: bool, integer or basic "string" (with trailing comment)
+fn parse_toml_value(raw: &str) -> Option<Value> {
+    if raw.starts_with('"') {
+        // Basic strings use the same escapes as JSON; find the closing quote
+        let mut escaped = false;
+        for (i, c) in raw.char_indices().skip(1) {
+            match c {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => return json::parse(&raw[..=i]),
+                _ => escaped = false,
+            }
+        }
+        return None;
+    }
+    let raw = raw.split('#').next()?.trim();
+    match raw {
+        "true" => Some(Value::Bool(true)),
+        "false" => Some(Value::Bool(false)),
+        _ => raw.parse::<i64>().ok().map(|n| Value::Number(n as f64)),
+    }
+}
+
+/// Migrate fields from `version` to `SETTINGS_VERSION`, one step at a time
+fn migrate(version: u32, mut fields: Vec<(String, Value)>) -> Vec<(String, Value)> {
+    if version < 1 {
+        // v0 → v1: rename legacy keys, registry DWORD 0/1 → bool.
+        // Other keys are already v1: unversioned partial objects (app rules,
+        // profiles) keep their numbers (`{"output_mode":2}`).
+        for (key, value) in fields.iter_mut() {
+            let Some(&(_, new)) = LEGACY_KEYS.iter().find(|(old, _)| old == key) else {
+                continue;
+            };
+            *key = new.to_string();
+            if key != "method" {
+                if let Value::Number(n) = value {
+                    *value = Value::Bool(*n != 0.0);
+                }
+            }
+        }
+    }
+    if version < 2 {
+        // v1 → v2: english_auto_restore on = Balanced, off = Off
+        for (key, value) in fields.iter_mut() {
+            if key == "english_auto_restore" {
+                *key = "auto_restore_level".to_string();
+                if let Value::Bool(enabled) = *value {
+                    let level = if enabled {
+                        AutoRestoreLevel::Balanced
+                    } else {
+                        AutoRestoreLevel::Off
+                    };
+                    *value = Value::Number(level as u8 as f64);
+                }
+            }
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_roundtrip() {
+        let s = EngineSettings {
+            method: 1,
+            free_tone: true,
+            auto_capitalize: true,
+            ..Default::default()
+        };
+        let json = s.to_json();
+        assert!(json.starts_with("{\"version\":2,"));
+        assert_eq!(EngineSettings::from_json(&json), Some(s));
+    }
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let s = EngineSettings {
+            modern_tone: false,
+            auto_restore_level: 3,
+            ..Default::default()
+        };
+        assert_eq!(EngineSettings::from_toml(&s.to_toml()), Some(s));
+    }
+
+    #[test]
+    fn test_missing_keys_use_defaults() {
+        let s = EngineSettings::from_json(r#"{"version":1,"method":1}"#).unwrap();
+        assert_eq!(s.method, 1);
+        assert!(s.modern_tone);
+        assert!(!s.esc_restore);
+    }
+
+    #[test]
+    fn test_unknown_keys_ignored() {
+        let s = EngineSettings::from_json(r#"{"version":1,"show_osd":true}"#);
+        assert_eq!(s, Some(EngineSettings::default()));
+    }
+
+    #[test]
+    fn test_reject_bad_input() {
+        assert_eq!(EngineSettings::from_json("[]"), None);
+        assert_eq!(EngineSettings::from_json(r#"{"enabled":"yes"}"#), None);
+        assert_eq!(EngineSettings::from_json(r#"{"method":7}"#), None);
+        assert_eq!(
+            EngineSettings::from_json(r#"{"version":1,"output_mode":9}"#),
+            None
+        );
+        assert_eq!(
+            EngineSettings::from_json(r#"{"version":2,"auto_restore_level":4}"#),
+            None
+        );
+        assert_eq!(EngineSettings::from_json(r#"{"version":99}"#), None);
+    }
+
+    #[test]
+    fn test_migrate_linux_config() {
+        // Linux config.toml written before versioning
+        let toml = "enabled = true\ninput_method = 1\nmodern_tone = false\n\
+                    esc_restore = true\nauto_start = false\ntoggle_hotkey = \"Ctrl+Space\"\n";
+        let s = EngineSettings::from_toml(toml).unwrap();
+        assert_eq!(s.method, 1);
+        assert!(!s.modern_tone);
+        assert!(s.esc_restore);
+    }
+
+    #[test]
+    fn test_migrate_windows_registry_names() {
+        let s = EngineSettings::from_json(
+            r#"{"InputMethod":1,"EscRestore":1,"AutoCapitalize":0,"FreeTone":1}"#,
+        )
+        .unwrap();
+        assert_eq!(s.method, 1);
+        assert!(s.esc_restore);
+        assert!(!s.auto_capitalize);
+        assert!(s.free_tone);
+    }
+
+    #[test]
+    fn test_migrate_english_auto_restore() {
+        let on = EngineSettings::from_json(r#"{"version":1,"english_auto_restore":true}"#);
+        assert_eq!(on.unwrap().auto_restore_level, 2);
+        let off = EngineSettings::from_toml("version = 1\nenglish_auto_restore = false\n");
+        assert_eq!(off.unwrap().auto_restore_level, 0);
+        // Windows registry DWORD
+        let s = EngineSettings::from_json(r#"{"EnglishAutoRestore":1}"#).unwrap();
+        assert_eq!(s.auto_restore_level, 2);
+        // Version 2 no longer knows the flag
+        let s = EngineSettings::from_json(r#"{"version":2,"english_auto_restore":true}"#);
+        assert_eq!(s.unwrap().auto_restore_level, 0);
+    }
+
+    #[test]
+    fn test_diff() {
+        let base = EngineSettings::default();
+        let changed = EngineSettings {
+            method: 1,
+            output_mode: 2,
+            ..base.clone()
+        };
+        let undo = base.diff(&changed);
+        assert_eq!(
+            undo.to_json(),
+            r#"{"version":2,"method":0,"output_mode":0}"#
+        );
+        assert_eq!(changed.with_overrides(&undo), Some(base));
+    }
+
+    #[test]
+    fn test_unversioned_numbers_kept() {
+        let s = EngineSettings::from_json(r#"{"output_mode":2,"idle_timeout_ms":500}"#).unwrap();
+        assert_eq!(s.output_mode, 2);
+        assert_eq!(s.idle_timeout_ms, 500);
+    }
+
+    #[test]
+    fn test_key_fields() {
+        let s = EngineSettings {
+            undo_key: Some(50),
+            literal_key: Some(42),
+            ..Default::default()
+        };
+        assert_eq!(EngineSettings::from_json(&s.to_json()), Some(s.clone()));
+        assert_eq!(EngineSettings::from_toml(&s.to_toml()), Some(s));
+
+        // Unset keys are null in JSON and left out of TOML
+        let s = EngineSettings::default();
+        assert!(s.to_json().contains(r#""undo_key":null"#));
+        assert!(!s.to_toml().contains("undo_key"));
+        assert_eq!(EngineSettings::from_toml(&s.to_toml()), Some(s));
+
+        assert_eq!(
+            EngineSettings::from_json(r#"{"version":2,"undo_key":70000}"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_toml_comments_and_tables() {
+        let toml = "# fkey\nversion = 1\nmethod = 1 # VNI\n\n[hotkeys]\nmethod = 0\n";
+        assert_eq!(EngineSettings::from_toml(toml).unwrap().method, 1);
+    }
+}
@@ -4,7 +4,7 @@
 //! Shortcuts can be specific to input methods (Telex/VNI) or apply to all.
 
 use super::buffer::MAX;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Maximum replacement length in UTF-32 codepoints (matches Result.chars array size)
 /// This limit ensures replacement fits in the FFI result buffer.
@@ -171,6 +171,8 @@ pub struct ShortcutTable {
     shortcuts: HashMap<String, Shortcut>,
     /// Sorted triggers by length (longest first) for matching
     sorted_triggers: Vec<String>,
+    /// Triggers allowed by the active profile (None = all shortcuts active)
+    active_subset: Option<HashSet<String>>,
 }
 
 impl ShortcutTable {
@@ -178,6 +180,7 @@ impl ShortcutTable {
         Self {
             shortcuts: HashMap::new(),
             sorted_triggers: vec![],
+            active_subset: None,
         }
     }
 
@@ -264,7 +267,7 @@ impl ShortcutTable {
         for trigger in &self.sorted_triggers {
             if buffer_lower == *trigger {
                 if let Some(shortcut) = self.shortcuts.get(trigger) {
-                    if shortcut.enabled && shortcut.applies_to(method) {
+                    if shortcut.enabled && shortcut.applies_to(method) && self.is_active(trigger) {
                         return Some((trigger, shortcut));
                     }
                 }
@@ -273,6 +276,20 @@ impl ShortcutTable {
         None
     }
 
+    /// Check if typed text is the start of an active trigger for the method
+    ///
+    /// Case-insensitive like `lookup_for_method`. Used to keep keys that
+    /// build a trigger (e.g. `\` in `\sig`) from being treated specially.
+    pub fn has_prefix_for_method(&self, typed: &str, method: InputMethod) -> bool {
+        let typed_lower = typed.to_lowercase();
+        self.shortcuts.iter().any(|(trigger, shortcut)| {
+            trigger.starts_with(&typed_lower)
+                && shortcut.enabled
+                && shortcut.applies_to(method)
+                && self.is_active(trigger)
+        })
+    }
+
     /// Try to match buffer with trigger key (for any input method)
     ///
     /// # Arguments
@@ -376,6 +393,22 @@ impl ShortcutTable {
             .sort_by_key(|s| std::cmp::Reverse(s.len()));
     }
 
+    /// Restrict matching to a subset of triggers (used by settings profiles)
+    ///
+    /// Shortcuts stay in the table; triggers outside the subset simply don't match.
+    /// `None` makes every shortcut active again.
+    pub fn set_active_subset(&mut self, triggers: Option<&[String]>) {
+        self.active_subset =
+            triggers.map(|t| t.iter().map(|s| s.to_lowercase()).collect::<HashSet<_>>());
+    }
+
+    /// Check if trigger is allowed by the active subset
+    fn is_active(&self, trigger: &str) -> bool {
+        self.active_subset
+            .as_ref()
+            .is_none_or(|subset| subset.contains(trigger))
+    }
+
     /// Check if shortcut table is empty
     pub fn is_empty(&self) -> bool {
         self.shortcuts.is_empty()
@@ -567,6 +600,20 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_active_subset() {
+        let mut table = table_with_shortcut("vn", "Việt Nam");
+        table.add(Shortcut::new("ko", "không"));
+
+        table.set_active_subset(Some(&["KO".to_string()]));
+        assert!(table.lookup("vn").is_none());
+        assert!(table.lookup("ko").is_some());
+        assert_eq!(table.len(), 2, "Inactive shortcuts stay in the table");
+
+        table.set_active_subset(None);
+        assert!(table.lookup("vn").is_some());
+    }
+
     #[test]
     fn test_telex_specific_shortcut() {
         let table = table_with_telex_shortcut("w", "ư");
@@ -857,6 +904,17 @@ mod tests {
         assert!(table.lookup("kO").is_some());
     }
 
+    #[test]
+    fn has_prefix_for_method() {
+        let mut table = table_with_shortcut("\\sig", "Regards");
+        table.add(Shortcut::vni("vni", "VNI"));
+        assert!(table.has_prefix_for_method("\\", InputMethod::Telex));
+        assert!(table.has_prefix_for_method("\\SI", InputMethod::Telex));
+        assert!(!table.has_prefix_for_method("\\x", InputMethod::Telex));
+        assert!(!table.has_prefix_for_method("vn", InputMethod::Telex));
+        assert!(table.has_prefix_for_method("vn", InputMethod::Vni));
+    }
+
     #[test]
     fn issue86_smart_case_mixed_case_fallback() {
         let table = table_with_shortcut("ko", "không");
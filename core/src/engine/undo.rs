@@ -0,0 +1,314 @@
+//! Undo History - Step back through the transforms of the current word
+//!
+//! `Engine::last_transform` only remembers the latest transform (for
+//! double-key reverts), and ESC restores the raw keystrokes all at once.
+//! `UndoHistory` keeps one step per key that transformed the word, so the
+//! transforms can be undone (and redone) one at a time:
+//! `người` → `ngươi` → `nguoi`.
+//!
+//! A step stores what the key changed in the buffer, not a snapshot, so
+//! undoing a transform keeps letters typed after it. Marks are tracked by
+//! value since tone repositioning moves them between vowels.
+
+use super::buffer::{Buffer, Char};
+use super::wipe::wipe_vec;
+
+/// A single change made by a transform
+#[derive(Clone, Copy)]
+enum Edit {
+    /// Char at `pos` changed (tone, stroke, or key converted: w → ư, ] → ư)
+    Char {
+        pos: usize,
+        before: Char,
+        after: Char,
+    },
+    /// Syllable mark changed; `pos` is where it was last seen
+    Mark { pos: usize, from: u8, to: u8 },
+}
+
+/// Undo/redo stacks for the current word
+#[derive(Default)]
+pub(crate) struct UndoHistory {
+    undo: Vec<Vec<Edit>>,
+    redo: Vec<Vec<Edit>>,
+}
+
+impl UndoHistory {
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Clear, overwriting the recorded chars first
+    pub fn wipe(&mut self) {
+        let fill = Edit::Mark {
+            pos: 0,
+            from: 0,
+            to: 0,
+        };
+        for step in self.undo.iter_mut().chain(self.redo.iter_mut()) {
+            wipe_vec(step, fill);
+        }
+        self.clear();
+    }
+
+    /// Record what `key` did to the buffer (`old` → `new`)
+    ///
+    /// Keys that only append plain letters add no step. Keys that remove
+    /// diacritics (double-key reverts, stroke reverts) or shrink the buffer
+    /// end the history: older steps no longer describe the word.
+    pub fn record(&mut self, old: &Buffer, new: &Buffer, key: u16, caps: bool) {
+        // Chars deleted since their step was recorded
+        self.truncate(old.len());
+
+        if new.len() < old.len() || (mark_of(new).0 == 0 && mark_of(old).0 != 0) {
+            self.clear();
+            return;
+        }
+
+        let mut step = Vec::new();
+        for (pos, (a, b)) in old.iter().zip(new.iter()).enumerate() {
+            if same_letter(a, b) {
+                continue;
+            }
+            if (a.has_tone() && !b.has_tone()) || (a.stroke && !b.stroke) {
+                self.clear();
+                return;
+            }
+            step.push(Edit::Char {
+                pos,
+                before: *a,
+                after: *b,
+            });
+        }
+        // Typed key turned into something else (w → ư, ] → ư)
+        let mut plain_letter = false;
+        if new.len() == old.len() + 1 {
+            if let Some(last) = new.last() {
+                let typed = Char::new(key, caps);
+                if same_letter(last, &typed) {
+                    plain_letter = true;
+                } else {
+                    step.push(Edit::Char {
+                        pos: old.len(),
+                        before: typed,
+                        after: *last,
+                    });
+                }
+            }
+        }
+        let (from, _) = mark_of(old);
+        let (to, pos) = mark_of(new);
+        if from != to {
+            step.push(Edit::Mark { pos, from, to });
+        }
+
+        if step.is_empty() {
+            return;
+        }
+        match self.undo.last_mut() {
+            // Side effect of a plain letter (nguơ + i → ngươi completes the
+            // horn pair): part of the transform that caused it
+            Some(last) if plain_letter => last.extend(step),
+            _ => self.undo.push(step),
+        }
+        self.redo.clear();
+    }
+
+    /// Revert the latest transform; false if nothing applied
+    pub fn undo(&mut self, buf: &mut Buffer) -> bool {
+        Self::step(&mut self.undo, &mut self.redo, buf, true)
+    }
+
+    /// Re-apply the latest undone transform; false if nothing applied
+    pub fn redo(&mut self, buf: &mut Buffer) -> bool {
+        Self::step(&mut self.redo, &mut self.undo, buf, false)
+    }
+
+    /// Keep positions in sync after a char is inserted mid-word
+    pub fn shift_after_insert(&mut self, index: usize) {
+        for edit in self.edits_mut() {
+            let (Edit::Char { pos, .. } | Edit::Mark { pos, .. }) = edit;
+            if *pos >= index {
+                *pos += 1;
+            }
+        }
+    }
+
+    /// Keep positions in sync after a char is removed mid-word
+    pub fn shift_after_remove(&mut self, index: usize) {
+        for stack in [&mut self.undo, &mut self.redo] {
+            for step in stack.iter_mut() {
+                step.retain(|e| !matches!(e, Edit::Char { pos, .. } if *pos == index));
+            }
+            stack.retain(|step| !step.is_empty());
+        }
+        for edit in self.edits_mut() {
+            let (Edit::Char { pos, .. } | Edit::Mark { pos, .. }) = edit;
+            if *pos > index {
+                *pos -= 1;
+            }
+        }
+    }
+
+    fn edits_mut(&mut self) -> impl Iterator<Item = &mut Edit> {
+        self.undo.iter_mut().chain(self.redo.iter_mut()).flatten()
+    }
+
+    /// Drop char edits at or past `len`
+    fn truncate(&mut self, len: usize) {
+        for stack in [&mut self.undo, &mut self.redo] {
+            for step in stack.iter_mut() {
+                step.retain(|e| !matches!(e, Edit::Char { pos, .. } if *pos >= len));
+            }
+            stack.retain(|step| !step.is_empty());
+        }
+    }
+
+    /// Pop steps from `from` until one applies, then push it onto `to`
+    fn step(
+        from: &mut Vec<Vec<Edit>>,
+        to: &mut Vec<Vec<Edit>>,
+        buf: &mut Buffer,
+        backward: bool,
+    ) -> bool {
+        while let Some(mut step) = from.pop() {
+            let mut applied = false;
+            for edit in step.iter_mut().rev() {
+                applied |= apply(edit, buf, backward);
+            }
+            if applied {
+                to.push(step);
+                return true;
+            }
+            // Stale step (word edited since): skip it
+        }
+        false
+    }
+}
+
+/// Apply an edit forward (redo) or backward (undo); false if stale
+fn apply(edit: &mut Edit, buf: &mut Buffer, backward: bool) -> bool {
+    match edit {
+        Edit::Char { pos, before, after } => {
+            let (expect, target) = if backward {
+                (*after, *before)
+            } else {
+                (*before, *after)
+            };
+            match buf.get_mut(*pos) {
+                Some(c) if same_letter(c, &expect) => {
+                    c.key = target.key;
+                    c.tone = target.tone;
+                    c.stroke = target.stroke;
+                    true
+                }
+                _ => false,
+            }
+        }
+        Edit::Mark { pos, from, to } => {
+            let (expect, target) = if backward { (*to, *from) } else { (*from, *to) };
+            let found = if expect == 0 {
+                // Mark was added: redo puts it back where it was undone
+                Some(*pos).filter(|&p| mark_of(buf).0 == 0 && p < buf.len())
+            } else {
+                buf.iter().position(|c| c.mark == expect)
+            };
+            match found.and_then(|p| buf.get_mut(p).map(|c| (p, c))) {
+                Some((p, c)) => {
+                    c.mark = target;
+                    *pos = p;
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+}
+
+/// Same letter ignoring the mark (marks are tracked per syllable)
+fn same_letter(a: &Char, b: &Char) -> bool {
+    a.key == b.key && a.tone == b.tone && a.stroke == b.stroke
+}
+
+/// Syllable mark and its position (0 if none)
+fn mark_of(buf: &Buffer) -> (u8, usize) {
+    buf.iter()
+        .enumerate()
+        .find(|(_, c)| c.has_mark())
+        .map_or((0, 0), |(i, c)| (c.mark, i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{chars::mark, chars::tone, keys};
+
+    fn word(letters: &[u16]) -> Buffer {
+        let mut buf = Buffer::new();
+        for &key in letters {
+            buf.push(Char::new(key, false));
+        }
+        buf
+    }
+
+    #[test]
+    fn test_undo_steps_back_one_transform() {
+        let mut h = UndoHistory::default();
+        let old = word(&[keys::U, keys::O]);
+        let mut buf = old.clone();
+        buf.get_mut(0).unwrap().tone = tone::HORN;
+        buf.get_mut(1).unwrap().tone = tone::HORN;
+        h.record(&old, &buf, keys::W, false);
+
+        let old = buf.clone();
+        buf.get_mut(1).unwrap().mark = mark::HUYEN;
+        h.record(&old, &buf, keys::F, false);
+
+        assert!(h.undo(&mut buf));
+        assert_eq!(buf.to_full_string(), "ươ");
+        assert!(h.undo(&mut buf));
+        assert_eq!(buf.to_full_string(), "uo");
+        assert!(!h.undo(&mut buf));
+
+        assert!(h.redo(&mut buf));
+        assert!(h.redo(&mut buf));
+        assert_eq!(buf.to_full_string(), "ườ");
+    }
+
+    #[test]
+    fn test_plain_letters_add_no_step() {
+        let mut h = UndoHistory::default();
+        let old = word(&[keys::A]);
+        let buf = word(&[keys::A, keys::N]);
+        h.record(&old, &buf, keys::N, false);
+        assert!(!h.undo(&mut buf.clone()));
+    }
+
+    #[test]
+    fn test_converted_key_undoes_to_typed_key() {
+        let mut h = UndoHistory::default();
+        let old = Buffer::new();
+        let mut buf = word(&[keys::U]);
+        buf.get_mut(0).unwrap().tone = tone::HORN;
+        h.record(&old, &buf, keys::W, false);
+
+        assert!(h.undo(&mut buf));
+        assert_eq!(buf.to_full_string(), "w");
+    }
+
+    #[test]
+    fn test_revert_ends_history() {
+        let mut h = UndoHistory::default();
+        let old = word(&[keys::A]);
+        let mut buf = old.clone();
+        buf.get_mut(0).unwrap().mark = mark::SAC;
+        h.record(&old, &buf, keys::S, false);
+
+        // "ss": mark removed, 's' appended
+        let old = buf.clone();
+        let buf = word(&[keys::A, keys::S]);
+        h.record(&old, &buf, keys::S, false);
+        assert!(!h.undo(&mut buf.clone()));
+    }
+}
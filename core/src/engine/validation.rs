@@ -17,12 +17,19 @@ pub enum ValidationResult {
     InvalidSpelling,
     InvalidVowelPattern,
     NoVowel,
+    /// Valid only with the loanword profile (cluster initial or foreign final)
+    Loanword,
 }
 
 impl ValidationResult {
     pub fn is_valid(&self) -> bool {
         matches!(self, ValidationResult::Valid)
     }
+
+    /// Valid as Vietnamese or as a loanword syllable
+    pub fn is_valid_or_loanword(&self) -> bool {
+        matches!(self, ValidationResult::Valid | ValidationResult::Loanword)
+    }
 }
 
 // =============================================================================
@@ -39,6 +46,8 @@ pub struct BufferSnapshot {
     pub has_tone_info: bool,
     /// True when foreign consonants (z, w, j, f) are allowed as valid initials
     pub allow_foreign_consonants: bool,
+    /// True when loanword clusters and finals are accepted (as `Loanword`)
+    pub allow_loanwords: bool,
 }
 
 impl BufferSnapshot {
@@ -51,6 +60,7 @@ impl BufferSnapshot {
             tones: vec![0; len],
             has_tone_info: false,
             allow_foreign_consonants: false,
+            allow_loanwords: false,
         }
     }
 
@@ -62,8 +72,15 @@ impl BufferSnapshot {
             tones: vec![0; len],
             has_tone_info: false,
             allow_foreign_consonants,
+            allow_loanwords: false,
         }
     }
+
+    /// Accept loanword syllables (validation then returns `Loanword`)
+    pub fn with_loanwords(mut self, allow_loanwords: bool) -> Self {
+        self.allow_loanwords = allow_loanwords;
+        self
+    }
 }
 
 // =============================================================================
@@ -71,6 +88,9 @@ impl BufferSnapshot {
 // =============================================================================
 
 /// Rule type: takes buffer snapshot and parsed syllable, returns error or None
+///
+/// `Loanword` is not an error: it marks the syllable as a loanword and the
+/// remaining rules still run.
 type Rule = fn(&BufferSnapshot, &Syllable) -> Option<ValidationResult>;
 
 /// All validation rules in order of priority
@@ -113,11 +133,23 @@ fn rule_valid_initial(snap: &BufferSnapshot, syllable: &Syllable) -> Option<Vali
     };
 
     if !is_valid {
+        if snap.allow_loanwords && is_loanword_initial(&initial) {
+            return Some(ValidationResult::Loanword);
+        }
         return Some(ValidationResult::InvalidInitial);
     }
     None
 }
 
+/// Check if consonants form a loanword cluster (bl, str, xtr, ...)
+fn is_loanword_initial(initial: &[u16]) -> bool {
+    match initial {
+        [a, b] => constants::LOANWORD_INITIALS_2.contains(&[*a, *b]),
+        [a, b, c] => constants::LOANWORD_INITIALS_3.contains(&[*a, *b, *c]),
+        _ => false,
+    }
+}
+
 /// Rule 3: All characters must be parsed into syllable structure
 fn rule_all_chars_parsed(snap: &BufferSnapshot, syllable: &Syllable) -> Option<ValidationResult> {
     let parsed = syllable.initial.len()
@@ -126,6 +158,14 @@ fn rule_all_chars_parsed(snap: &BufferSnapshot, syllable: &Syllable) -> Option<V
         + syllable.final_c.len();
 
     if parsed != snap.keys.len() {
+        // The parser only knows Vietnamese finals: a single trailing foreign
+        // final is left unparsed
+        let foreign_final = parsed + 1 == snap.keys.len()
+            && syllable.final_c.is_empty()
+            && constants::LOANWORD_FINALS_1.contains(&snap.keys[parsed]);
+        if snap.allow_loanwords && foreign_final {
+            return Some(ValidationResult::Loanword);
+        }
         return Some(ValidationResult::InvalidFinal);
     }
     None
@@ -283,14 +323,20 @@ pub fn validate(snap: &BufferSnapshot) -> ValidationResult {
     }
 
     let syllable = parse(&snap.keys);
+    run_rules(snap, &syllable, RULES)
+}
 
-    for rule in RULES {
-        if let Some(error) = rule(snap, &syllable) {
-            return error;
+/// Run rules in order: first error wins, else `Loanword` if any rule said so
+fn run_rules(snap: &BufferSnapshot, syllable: &Syllable, rules: &[Rule]) -> ValidationResult {
+    let mut result = ValidationResult::Valid;
+    for rule in rules {
+        match rule(snap, syllable) {
+            Some(ValidationResult::Loanword) => result = ValidationResult::Loanword,
+            Some(error) => return error,
+            None => {}
         }
     }
-
-    ValidationResult::Valid
+    result
 }
 
 /// Quick check if buffer could be valid Vietnamese (with modifier info)
@@ -301,10 +347,24 @@ pub fn is_valid_with_tones(keys: &[u16], tones: &[u8]) -> bool {
         tones: tones.to_vec(),
         has_tone_info: true, // Enforce modifier requirements
         allow_foreign_consonants: false,
+        allow_loanwords: false,
     };
     validate(&snap).is_valid()
 }
 
+/// Check if buffer is a complete syllable (word end)
+///
+/// Stricter than `is_valid_with_tones`, which also accepts syllables still
+/// being typed: ă and â only appear in closed syllables ("ăn", "ấy"), so a
+/// syllable ending in ă/â is incomplete.
+pub fn is_complete_syllable(buffer_keys: &[u16], tones: &[u8]) -> bool {
+    if !is_valid_with_tones(buffer_keys, tones) {
+        return false;
+    }
+    let open_a = buffer_keys.last() == Some(&keys::A) && tones.last().is_some_and(|&t| t != 0);
+    !open_a
+}
+
 /// Quick check if buffer could be valid Vietnamese (with modifier info and foreign consonants option)
 pub fn is_valid_with_tones_and_foreign(
     keys: &[u16],
@@ -316,6 +376,7 @@ pub fn is_valid_with_tones_and_foreign(
         tones: tones.to_vec(),
         has_tone_info: true,
         allow_foreign_consonants,
+        allow_loanwords: false,
     };
     validate(&snap).is_valid()
 }
@@ -336,6 +397,26 @@ pub fn is_valid_with_foreign(buffer_keys: &[u16], allow_foreign_consonants: bool
     validate(&snap).is_valid()
 }
 
+/// Check if buffer could still become a valid syllable as letters are added
+///
+/// Without a vowel yet, the consonants must start a valid initial ("đ", "tr",
+/// but not "đr"). With a vowel, the structure must be valid; vowel patterns
+/// are not checked since "ươ" is only complete with its final.
+pub fn is_valid_prefix(buffer_keys: &[u16]) -> bool {
+    if buffer_keys.iter().any(|&k| keys::is_vowel(k)) {
+        return is_valid_for_transform(buffer_keys);
+    }
+    match buffer_keys {
+        [] => true,
+        [k] => constants::VALID_INITIALS_1.contains(k),
+        [a, b] => constants::VALID_INITIALS_2
+            .iter()
+            .any(|p| p[0] == *a && p[1] == *b),
+        [a, b, c] => *a == keys::N && *b == keys::G && *c == keys::H,
+        _ => false,
+    }
+}
+
 /// Rules for pre-transformation validation (excludes vowel pattern check)
 /// Used to validate buffer structure before applying tone/mark transformations.
 /// Allows intermediate states like "aa" that become valid after transformation.
@@ -367,15 +448,20 @@ pub fn is_valid_for_transform_with_foreign(
 
     let snap =
         BufferSnapshot::from_keys_with_foreign(buffer_keys.to_vec(), allow_foreign_consonants);
-    let syllable = parse(&snap.keys);
+    validate_for_transform(&snap).is_valid()
+}
 
-    for rule in RULES_FOR_TRANSFORM {
-        if rule(&snap, &syllable).is_some() {
-            return false;
-        }
+/// Pre-transformation validation of a snapshot (allows intermediate vowel patterns)
+///
+/// Like `validate`, returns `Loanword` for structures only the loanword
+/// profile accepts.
+pub fn validate_for_transform(snap: &BufferSnapshot) -> ValidationResult {
+    if snap.keys.is_empty() {
+        return ValidationResult::NoVowel;
     }
 
-    true
+    let syllable = parse(&snap.keys);
+    run_rules(snap, &syllable, RULES_FOR_TRANSFORM)
 }
 
 /// Check if the buffer shows patterns that suggest foreign word input.
@@ -576,6 +662,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_valid_prefix() {
+        for w in ["d", "tr", "ng", "ngh", "duo", "truong"] {
+            assert!(is_valid_prefix(&keys_from_str(w)), "'{}' can continue", w);
+        }
+        for w in ["dr", "sq", "nghh", "ub", "clau"] {
+            assert!(!is_valid_prefix(&keys_from_str(w)), "'{}' can't", w);
+        }
+    }
+
+    #[test]
+    fn test_loanword() {
+        let check = |w: &str, loanwords: bool| {
+            validate(&BufferSnapshot::from_keys(keys_from_str(w)).with_loanwords(loanwords))
+        };
+        for w in ["blo", "stret", "xtret", "bus", "bul"] {
+            assert_eq!(check(w, true), ValidationResult::Loanword, "{}", w);
+            assert!(!check(w, false).is_valid_or_loanword(), "{}", w);
+        }
+        assert_eq!(check("truong", true), ValidationResult::Valid);
+        assert_eq!(check("bla", true), ValidationResult::Loanword);
+        assert!(
+            !check("squa", true).is_valid_or_loanword(),
+            "sq isn't a cluster"
+        );
+        assert!(
+            !check("blust", true).is_valid_or_loanword(),
+            "one foreign final"
+        );
+    }
+
+    #[test]
+    fn test_complete_syllable_open_a() {
+        let keys = keys_from_str("an");
+        assert!(is_complete_syllable(&keys, &[tone::HORN, 0]), "ăn");
+        let keys = keys_from_str("a");
+        assert!(!is_complete_syllable(&keys, &[tone::HORN]), "ă alone");
+        assert!(!is_complete_syllable(&keys, &[tone::CIRCUMFLEX]), "â alone");
+        assert!(is_complete_syllable(&keys, &[0]), "a");
+    }
+
     #[test]
     fn test_valid_diphthongs() {
         // Test some valid diphthong patterns
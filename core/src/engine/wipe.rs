@@ -0,0 +1,57 @@
+//! Memory Wiping - Overwrite typed text before letting go of it
+//!
+//! `clear()` only resets lengths: old chars stay in the buffers until new
+//! ones overwrite them. Incognito mode (password fields) wipes instead.
+//! Writes are volatile so the compiler cannot drop them as dead stores.
+//!
+//! Only memory the engine still owns is wiped. Copies freed earlier (a
+//! `Vec` that grew and reallocated) are out of reach.
+
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/// Overwrite every element with `fill`
+pub(crate) fn wipe_slice<T: Copy>(s: &mut [T], fill: T) {
+    for x in s.iter_mut() {
+        // SAFETY: `x` is a valid, aligned, exclusive reference
+        unsafe { std::ptr::write_volatile(x, fill) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Overwrite the whole allocation (spare capacity included), then clear
+pub(crate) fn wipe_vec<T: Copy>(v: &mut Vec<T>, fill: T) {
+    v.resize(v.capacity(), fill);
+    wipe_slice(v, fill);
+    v.clear();
+}
+
+/// Zero the whole allocation of a string, then clear it
+pub(crate) fn wipe_string(s: &mut String) {
+    let mut bytes = std::mem::take(s).into_bytes();
+    wipe_vec(&mut bytes, 0);
+    // Empty, so always valid UTF-8; keeps the allocation for reuse
+    *s = String::from_utf8(bytes).unwrap_or_default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wipes_spare_capacity() {
+        let mut v: Vec<u16> = Vec::with_capacity(8);
+        v.extend([1, 2, 3, 4]);
+        v.truncate(1);
+        wipe_vec(&mut v, 0);
+        assert!(v.is_empty());
+        // SAFETY: capacity is unchanged, u16 has no invalid bit patterns
+        let spare = unsafe { std::slice::from_raw_parts(v.as_ptr(), v.capacity()) };
+        assert!(spare.iter().all(|&x| x == 0));
+
+        let mut s = String::from("secret");
+        let cap = s.capacity();
+        wipe_string(&mut s);
+        assert!(s.is_empty());
+        assert_eq!(s.capacity(), cap);
+    }
+}
@@ -0,0 +1,358 @@
+//! Word State - Per-word engine flags, snapshot and restore
+//!
+//! Besides the buffer and raw keystrokes, the engine tracks a set of
+//! interacting per-word flags (deferred breve, reverted stroke, pending
+//! mark-revert pop, ...). `WordState` groups them so `Engine::clear` resets
+//! them in one place, and `WordSnapshot` bundles them with the buffer so
+//! the exact state behind a bug report can be dumped and loaded again.
+//!
+//! Snapshots serialize to JSON via the minimal `json` module:
+//! `{"version":1,"buffer":[[key,caps,tone,mark,stroke],..],"caret":n,
+//! "raw_input":[[key,caps,shift],..],"state":{..}}`
+
+use super::buffer::{Buffer, Char, MAX};
+use super::json::{self, Value};
+use super::wipe::wipe_string;
+use super::Transform;
+
+/// Current snapshot schema version
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Per-word flags, reset at every word boundary (`Engine::clear`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WordState {
+    /// Latest transform, for double-key reverts (ss → s, ddd → dd)
+    pub(super) last_transform: Option<Transform>,
+    /// True if current word has non-letter characters before letters
+    /// Used to prevent false shortcut matches (e.g., "149k" should not match "k")
+    pub(super) has_non_letter_prefix: bool,
+    /// Pending breve position: position of 'a' that has deferred breve
+    /// Breve on 'a' in open syllables (like "raw") is invalid Vietnamese
+    /// We defer applying breve until a valid final consonant is typed
+    pub(super) pending_breve_pos: Option<usize>,
+    /// Issue #133: Pending horn position on 'u' in "uơ" pattern
+    /// When "uo" + 'w' is typed at end of syllable, only 'o' gets horn initially.
+    /// If a final consonant/vowel is added, also apply horn to 'u'.
+    /// Examples: "huow" → "huơ" (stays), "duow" + "c" → "dược" (u gets horn)
+    pub(super) pending_u_horn_pos: Option<usize>,
+    /// Tracks if stroke was reverted in current word (ddd → dd)
+    /// When true, subsequent 'd' keys are treated as normal letters, not stroke triggers
+    /// This prevents "ddddd" from oscillating between đ and dd states
+    pub(super) stroke_reverted: bool,
+    /// Tracks if a mark was reverted in current word
+    /// Used by auto-restore to detect words like "issue", "bass" that need restoration
+    pub(super) had_mark_revert: bool,
+    /// Pending pop from raw_input after mark revert
+    /// When true, the NEXT consonant key will trigger a pop to remove the consumed modifier
+    /// This differentiates: "tesst" → "test" (consonant after) vs "issue" → "issue" (vowel after)
+    pub(super) pending_mark_revert_pop: bool,
+    /// Tracks if ANY Vietnamese transform was ever applied during this word
+    /// (marks, tones, or stroke). Used to prevent false auto-restore for words
+    /// with numbers/symbols that never had Vietnamese transforms applied.
+    /// Example: "nhatkha1407@gmail.com" has no transforms, so shouldn't restore.
+    pub(super) had_any_transform: bool,
+    /// Tracks if circumflex was applied from V+C+V pattern by vowel trigger (not mark key)
+    /// Example: "toto" → "tôt" (second 'o' triggers circumflex on first 'o')
+    /// Used for auto-restore: if no mark follows, restore on space (e.g., "toto " → "toto ")
+    pub(super) had_vowel_triggered_circumflex: bool,
+    /// Tracks if circumflex was REVERTED by third vowel (aa→â, aaa→aa)
+    /// Example: "dataa" → "dât" (after 4th key), typing 5th 'a' reverts to "data"
+    /// Used in build_raw_chars to collapse double vowel at end for restore
+    pub(super) had_circumflex_revert: bool,
+    /// Issue #211: Tracks which vowel key triggered circumflex revert (extended vowel mode)
+    /// When set, subsequent same-key vowels append raw instead of re-transforming
+    /// Example: aaa→aa (reverted_circumflex_key=A), aaaa→aaa (skip transform, append raw)
+    pub(super) reverted_circumflex_key: Option<u16>,
+    /// Tracks if ANY Telex transform was applied (tone, mark, or stroke)
+    /// Used for whitelist-based auto-restore to English words
+    pub(super) had_telex_transform: bool,
+    /// Stores raw_input string when telex double pattern is detected (BEFORE modification)
+    /// For stroke revert (ddd→dd), raw_input is modified to remove one 'd', but we need
+    /// the original for whitelist lookup (e.g., "daddy" not "dady")
+    pub(super) telex_double_raw: Option<String>,
+    /// Stores length of raw_input at time telex_double_raw was stored
+    /// Used to append subsequent chars typed after revert
+    pub(super) telex_double_raw_len: usize,
+    /// Buffer was just restored from DELETE - clear on next letter input
+    /// This prevents typing after restore from appending to old buffer
+    pub(super) restored_pending_clear: bool,
+    /// Restored word was pure ASCII (no Vietnamese chars) - clear on ANY letter
+    /// For Vietnamese restored words, only clear on consonant (allow mark/tone edits)
+    pub(super) restored_is_ascii: bool,
+}
+
+impl WordState {
+    /// Reset, overwriting the kept raw input first
+    pub(super) fn wipe(&mut self) {
+        if let Some(raw) = self.telex_double_raw.as_mut() {
+            wipe_string(raw);
+        }
+        *self = Self::default();
+    }
+
+    fn to_value(&self) -> Value {
+        let flag = |k: &str, v: bool| (k.to_string(), Value::Bool(v));
+        let pos = |k: &str, v: Option<usize>| {
+            let v = v.map_or(Value::Null, |p| Value::Number(p as f64));
+            (k.to_string(), v)
+        };
+        let raw = match &self.telex_double_raw {
+            Some(s) => Value::String(s.clone()),
+            None => Value::Null,
+        };
+        let transform = self.last_transform.map_or(Value::Null, transform_to_value);
+        Value::Object(vec![
+            ("last_transform".to_string(), transform),
+            flag("has_non_letter_prefix", self.has_non_letter_prefix),
+            pos("pending_breve_pos", self.pending_breve_pos),
+            pos("pending_u_horn_pos", self.pending_u_horn_pos),
+            flag("stroke_reverted", self.stroke_reverted),
+            flag("had_mark_revert", self.had_mark_revert),
+            flag("pending_mark_revert_pop", self.pending_mark_revert_pop),
+            flag("had_any_transform", self.had_any_transform),
+            flag(
+                "had_vowel_triggered_circumflex",
+                self.had_vowel_triggered_circumflex,
+            ),
+            flag("had_circumflex_revert", self.had_circumflex_revert),
+            pos(
+                "reverted_circumflex_key",
+                self.reverted_circumflex_key.map(usize::from),
+            ),
+            flag("had_telex_transform", self.had_telex_transform),
+            ("telex_double_raw".to_string(), raw),
+            pos("telex_double_raw_len", Some(self.telex_double_raw_len)),
+            flag("restored_pending_clear", self.restored_pending_clear),
+            flag("restored_is_ascii", self.restored_is_ascii),
+        ])
+    }
+
+    /// Read flags from an object; missing keys keep their defaults
+    fn from_value(value: &Value) -> Option<Self> {
+        let mut state = Self::default();
+        for (key, value) in value.as_object()? {
+            state.set_field(key, value)?;
+        }
+        Some(state)
+    }
+
+    /// Set a single field by key. Unknown keys are ignored (Some),
+    /// type mismatches are rejected (None).
+    fn set_field(&mut self, key: &str, value: &Value) -> Option<()> {
+        let pos = |v: &Value| match v {
+            Value::Null => Some(None),
+            _ => usize::try_from(v.as_u64()?).ok().map(Some),
+        };
+        match key {
+            "last_transform" => {
+                self.last_transform = match value {
+                    Value::Null => None,
+                    _ => Some(transform_from_value(value)?),
+                }
+            }
+            "has_non_letter_prefix" => self.has_non_letter_prefix = value.as_bool()?,
+            "pending_breve_pos" => self.pending_breve_pos = pos(value)?,
+            "pending_u_horn_pos" => self.pending_u_horn_pos = pos(value)?,
+            "stroke_reverted" => self.stroke_reverted = value.as_bool()?,
+            "had_mark_revert" => self.had_mark_revert = value.as_bool()?,
+            "pending_mark_revert_pop" => self.pending_mark_revert_pop = value.as_bool()?,
+            "had_any_transform" => self.had_any_transform = value.as_bool()?,
+            "had_vowel_triggered_circumflex" => {
+                self.had_vowel_triggered_circumflex = value.as_bool()?
+            }
+            "had_circumflex_revert" => self.had_circumflex_revert = value.as_bool()?,
+            "reverted_circumflex_key" => {
+                self.reverted_circumflex_key = match pos(value)? {
+                    Some(k) => Some(u16::try_from(k).ok()?),
+                    None => None,
+                }
+            }
+            "had_telex_transform" => self.had_telex_transform = value.as_bool()?,
+            "telex_double_raw" => {
+                self.telex_double_raw = match value {
+                    Value::Null => None,
+                    _ => Some(value.as_str()?.to_string()),
+                }
+            }
+            "telex_double_raw_len" => self.telex_double_raw_len = pos(value)??,
+            "restored_pending_clear" => self.restored_pending_clear = value.as_bool()?,
+            "restored_is_ascii" => self.restored_is_ascii = value.as_bool()?,
+            _ => {}
+        }
+        Some(())
+    }
+}
+
+/// Encode a transform as `{"kind":"mark","key":1,"value":2}`
+fn transform_to_value(t: Transform) -> Value {
+    let (kind, key, value) = match t {
+        Transform::Mark(k, v) => ("mark", Some(k), Some(v)),
+        Transform::Tone(k, v) => ("tone", Some(k), Some(v)),
+        Transform::Stroke(k) => ("stroke", Some(k), None),
+        Transform::ShortPatternStroke => ("short_pattern_stroke", None, None),
+        Transform::WAsVowel => ("w_as_vowel", None, None),
+        Transform::WShortcutSkipped => ("w_shortcut_skipped", None, None),
+        Transform::BracketAsVowel => ("bracket_as_vowel", None, None),
+    };
+    let mut fields = vec![("kind".to_string(), Value::String(kind.to_string()))];
+    if let Some(k) = key {
+        fields.push(("key".to_string(), Value::Number(k as f64)));
+    }
+    if let Some(v) = value {
+        fields.push(("value".to_string(), Value::Number(v as f64)));
+    }
+    Value::Object(fields)
+}
+
+fn transform_from_value(value: &Value) -> Option<Transform> {
+    let key = || u16::try_from(value.get("key")?.as_u64()?).ok();
+    let val = || u8::try_from(value.get("value")?.as_u64()?).ok();
+    Some(match value.get("kind")?.as_str()? {
+        "mark" => Transform::Mark(key()?, val()?),
+        "tone" => Transform::Tone(key()?, val()?),
+        "stroke" => Transform::Stroke(key()?),
+        "short_pattern_stroke" => Transform::ShortPatternStroke,
+        "w_as_vowel" => Transform::WAsVowel,
+        "w_shortcut_skipped" => Transform::WShortcutSkipped,
+        "bracket_as_vowel" => Transform::BracketAsVowel,
+        _ => return None,
+    })
+}
+
+/// Everything needed to resume typing the current word
+///
+/// Taken with `Engine::snapshot`, applied with `Engine::restore`. Settings,
+/// shortcuts and word history are not included.
+#[derive(Clone)]
+pub struct WordSnapshot {
+    pub(super) buf: Buffer,
+    pub(super) raw_input: Vec<(u16, bool, bool)>,
+    pub(super) state: WordState,
+}
+
+impl WordSnapshot {
+    /// Export as compact JSON
+    pub fn to_json(&self) -> String {
+        let num = |v: u64| Value::Number(v as f64);
+        let buffer = self
+            .buf
+            .iter()
+            .map(|c| {
+                Value::Array(vec![
+                    num(c.key as u64),
+                    Value::Bool(c.caps),
+                    num(c.tone as u64),
+                    num(c.mark as u64),
+                    Value::Bool(c.stroke),
+                ])
+            })
+            .collect();
+        let raw_input = self
+            .raw_input
+            .iter()
+            .map(|&(key, caps, shift)| {
+                Value::Array(vec![num(key as u64), Value::Bool(caps), Value::Bool(shift)])
+            })
+            .collect();
+        Value::Object(vec![
+            ("version".to_string(), num(SNAPSHOT_VERSION as u64)),
+            ("buffer".to_string(), Value::Array(buffer)),
+            ("caret".to_string(), num(self.buf.caret() as u64)),
+            ("raw_input".to_string(), Value::Array(raw_input)),
+            ("state".to_string(), self.state.to_value()),
+        ])
+        .to_json()
+    }
+
+    /// Import from JSON. Returns None on malformed input, a newer version,
+    /// or out-of-range values (unknown tone/mark, buffer over capacity,
+    /// pending positions past the buffer).
+    pub fn from_json(input: &str) -> Option<Self> {
+        let value = json::parse(input)?;
+        let version = value.get("version").map_or(Some(0), Value::as_u64)?;
+        if version > SNAPSHOT_VERSION as u64 {
+            return None;
+        }
+        let small = |v: &Value, max: u64| v.as_u64().filter(|&n| n <= max);
+
+        let mut buf = Buffer::new();
+        let chars = value.get("buffer")?.as_array()?;
+        if chars.len() > MAX {
+            return None;
+        }
+        for c in chars {
+            let [key, caps, tone, mark, stroke] = c.as_array()? else {
+                return None;
+            };
+            let mut ch = Char::new(small(key, u16::MAX as u64)? as u16, caps.as_bool()?);
+            ch.tone = small(tone, 2)? as u8;
+            ch.mark = small(mark, 5)? as u8;
+            ch.stroke = stroke.as_bool()?;
+            buf.push(ch);
+        }
+        if let Some(caret) = value.get("caret") {
+            buf.set_caret(small(caret, buf.len() as u64)? as usize);
+        }
+
+        let mut raw_input = Vec::new();
+        for k in value.get("raw_input")?.as_array()? {
+            let [key, caps, shift] = k.as_array()? else {
+                return None;
+            };
+            let key = small(key, u16::MAX as u64)? as u16;
+            raw_input.push((key, caps.as_bool()?, shift.as_bool()?));
+        }
+
+        let state = match value.get("state") {
+            Some(s) => WordState::from_value(s)?,
+            None => WordState::default(),
+        };
+        let in_buffer = |pos: Option<usize>| pos.is_none_or(|p| p < buf.len());
+        if !in_buffer(state.pending_breve_pos) || !in_buffer(state.pending_u_horn_pos) {
+            return None;
+        }
+        Some(Self {
+            buf,
+            raw_input,
+            state,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::keys;
+
+    #[test]
+    fn state_json_roundtrip() {
+        let state = WordState {
+            last_transform: Some(Transform::Mark(keys::S, 1)),
+            pending_breve_pos: Some(2),
+            telex_double_raw: Some("daddy".to_string()),
+            telex_double_raw_len: 4,
+            stroke_reverted: true,
+            ..Default::default()
+        };
+        assert_eq!(WordState::from_value(&state.to_value()), Some(state));
+    }
+
+    #[test]
+    fn rejects_bad_snapshots() {
+        assert!(WordSnapshot::from_json(r#"{"buffer":[],"raw_input":[]}"#).is_some());
+        let breve =
+            r#"{"buffer":[[0,false,0,0,false]],"raw_input":[],"state":{"pending_breve_pos":0}}"#;
+        assert!(WordSnapshot::from_json(breve).is_some());
+        for bad in [
+            r#"{"version":99,"buffer":[],"raw_input":[]}"#,
+            r#"{"buffer":[[0,false,3,0,false]],"raw_input":[]}"#,
+            r#"{"buffer":[[0,false]],"raw_input":[]}"#,
+            r#"{"buffer":[],"raw_input":[],"state":{"stroke_reverted":1}}"#,
+            r#"{"buffer":[],"raw_input":[],"state":{"last_transform":{"kind":"x"}}}"#,
+            r#"{"buffer":[[0,false,0,0,false]],"raw_input":[],"state":{"pending_breve_pos":1}}"#,
+            r#"{"buffer":[],"raw_input":[],"state":{"pending_u_horn_pos":0}}"#,
+        ] {
+            assert!(WordSnapshot::from_json(bad).is_none(), "{}", bad);
+        }
+    }
+}
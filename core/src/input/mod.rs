@@ -60,6 +60,9 @@ pub trait Method {
     fn remove(&self, key: u16) -> bool;
 }
 
+/// Number of input methods: ids `0..METHOD_COUNT` are served by `get`
+pub const METHOD_COUNT: u8 = 2;
+
 /// Static method instances (zero-sized types, no heap allocation)
 static TELEX: Telex = Telex;
 static VNI: Vni = Vni;
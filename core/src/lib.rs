@@ -26,6 +26,7 @@ pub mod input;
 pub mod updater;
 pub mod utils;
 
+use engine::composition::Composition;
 use engine::{Engine, Result};
 use std::sync::Mutex;
 
@@ -114,6 +115,63 @@ pub extern "C" fn ime_key_ext(key: u16, caps: bool, ctrl: bool, shift: bool) ->
     }
 }
 
+/// Process a key event with the OS auto-repeat flag.
+///
+/// Same as `ime_key_ext`, plus:
+/// * `repeat` - true if the event is an auto-repeat from holding the key
+///
+/// Repeated letters (and VNI digits) are typed literally instead of
+/// cycling transforms (holding `a` → `aaaa`, not `â`/`aa`).
+///
+/// # Returns
+/// * Pointer to `Result` struct (caller must free with `ime_free`)
+/// * `null` if engine not initialized
+#[no_mangle]
+pub extern "C" fn ime_key_event(
+    key: u16,
+    caps: bool,
+    ctrl: bool,
+    shift: bool,
+    repeat: bool,
+) -> *mut Result {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        let r = e.on_key_event(key, caps, ctrl, shift, repeat);
+        Box::into_raw(Box::new(r))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+/// Process a key event with a timestamp.
+///
+/// Same as `ime_key_event`, plus:
+/// * `time_ms` - event time in milliseconds from a monotonic clock
+///
+/// With an idle timeout set (`ime_idle_timeout`), a key arriving after the
+/// timeout starts a fresh word.
+///
+/// # Returns
+/// * Pointer to `Result` struct (caller must free with `ime_free`)
+/// * `null` if engine not initialized
+#[no_mangle]
+pub extern "C" fn ime_key_timed(
+    key: u16,
+    caps: bool,
+    ctrl: bool,
+    shift: bool,
+    repeat: bool,
+    time_ms: u64,
+) -> *mut Result {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        let r = e.on_key_timed(key, caps, ctrl, shift, repeat, time_ms);
+        Box::into_raw(Box::new(r))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
 /// Set the input method.
 ///
 /// # Arguments
@@ -219,6 +277,24 @@ pub extern "C" fn ime_english_auto_restore(enabled: bool) {
     }
 }
 
+/// Set the English auto-restore level.
+///
+/// # Arguments
+/// * `level` - 0 = off (default), 1 = dictionary-only (restore only to
+///   English dictionary words), 2 = balanced (same as
+///   `ime_english_auto_restore(true)`), 3 = aggressive (English dictionary
+///   words win over valid Vietnamese, except common words like "à", "bê")
+///
+/// Unknown levels are ignored.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_auto_restore_level(level: u8) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_auto_restore_level(level);
+    }
+}
+
 /// Enable/disable auto-capitalize after sentence-ending punctuation.
 ///
 /// When `enabled` is true, automatically capitalizes the first letter
@@ -247,6 +323,165 @@ pub extern "C" fn ime_allow_foreign_consonants(enabled: bool) {
     }
 }
 
+/// Enable/disable diacritic-first backspace.
+///
+/// When `enabled` is true, backspace removes the mark, then the tone, then
+/// the letter (tiếng → tiêng → tieng → tien).
+/// When `enabled` is false (default), backspace deletes whole characters.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_smart_backspace(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_smart_backspace(enabled);
+    }
+}
+
+/// Enable/disable caret editing inside the composing word.
+///
+/// When `enabled` is true, LEFT/RIGHT move a caret inside the word and keys
+/// typed there edit it in place; results may then carry `delete_after` and
+/// `caret_back`, which the host must apply.
+/// When `enabled` is false (default), LEFT/RIGHT end the word.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_caret_editing(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_caret_editing(enabled);
+    }
+}
+
+/// Enable/disable raw mode detection.
+///
+/// When `enabled` is true, URLs, emails, file paths and code identifiers
+/// (`snake_case`, `camelCase`, `::`) are typed without Vietnamese transforms
+/// until the next whitespace.
+/// When `enabled` is false (default), every word is transformed.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_raw_mode(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_raw_mode_detection(enabled);
+    }
+}
+
+/// Enable/disable Markdown code span awareness.
+///
+/// When `enabled` is true, text inside `inline code` spans and ``` fenced
+/// blocks is typed without Vietnamese transforms (shortcuts still work).
+/// When `enabled` is false (default), backticks are ordinary punctuation.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_markdown_code(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_markdown_code(enabled);
+    }
+}
+
+/// Enable/disable all-caps acronym passthrough.
+///
+/// When `enabled` is true, words typed in all caps that can't be Vietnamese
+/// stay as typed ("AWS", "DDR5"), while VIỆT or ĐƯỢC keep their diacritics.
+/// When `enabled` is false (default), all-caps words are transformed as usual.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_acronym_passthrough(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_acronym_passthrough(enabled);
+    }
+}
+
+/// Enable/disable syllable segmentation.
+///
+/// When `enabled` is true, words typed without spaces are split into
+/// syllables so each gets its own diacritics ("vieetjnam" → "việtnam").
+/// When `enabled` is false (default), everything between breaks is one syllable.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_syllable_segmentation(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_syllable_segmentation(enabled);
+    }
+}
+
+/// Enable/disable loanword syllables.
+///
+/// When `enabled` is true, consonant clusters (bl, cl, str, xtr...) and the
+/// finals -l/-s are accepted, so loanwords take diacritics ("bloocs" → "blốc").
+/// When `enabled` is false (default), only Vietnamese syllables transform.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_loanword_syllables(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_loanword_syllables(enabled);
+    }
+}
+
+/// Enable/disable incognito mode (password fields, privacy).
+///
+/// When `enabled` is true, the engine keeps no word history or events and
+/// overwrites typed text in memory whenever it clears a word. Turning it on
+/// wipes everything typed so far. Platforms call this from secure input
+/// detection (e.g. `IsSecureEventInputEnabled` on macOS).
+/// When `enabled` is false (default), word history and events work normally.
+/// Not saved with the settings. No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_set_incognito(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_incognito(enabled);
+    }
+}
+
+/// Enable/disable Telex/VNI auto-detection.
+///
+/// When `enabled` is true, the engine watches the first words typed and
+/// switches to the method they fit (`vieejt` → Telex, `vie65t` → VNI),
+/// only between words and only once a method clearly leads.
+/// When `enabled` is false (default), the method set by `ime_method` is kept.
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_method_auto_detect(enabled: bool) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_method_auto_detect(enabled);
+    }
+}
+
+/// Get the method picked by auto-detection.
+///
+/// # Returns
+/// 0 for Telex, 1 for VNI, -1 if nothing detected yet, auto-detection is
+/// off, or engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_detected_method() -> i32 {
+    let guard = lock_engine();
+    guard
+        .as_ref()
+        .and_then(|e| e.detected_method())
+        .map_or(-1, i32::from)
+}
+
+/// Set the idle timeout for timestamped keys (`ime_key_timed`).
+///
+/// After `ms` milliseconds without keys, the next key clears the buffer and
+/// word history first, unless it is a mark/tone key for a word the host
+/// confirmed with `ime_set_surrounding_text`. 0 disables (default).
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_idle_timeout(ms: u32) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_idle_timeout(ms);
+    }
+}
+
 /// Clear the input buffer.
 ///
 /// Call on word boundaries (space, punctuation).
@@ -277,6 +512,7 @@ pub extern "C" fn ime_clear_all() {
 ///
 /// Used for "Select All + Replace" injection method where the entire
 /// buffer content is needed instead of incremental backspace + chars.
+/// In commit-only output mode this is the word not yet sent (for an overlay).
 ///
 /// # Arguments
 /// * `out` - Pointer to output buffer for UTF-32 codepoints
@@ -305,124 +541,736 @@ pub unsafe extern "C" fn ime_get_buffer(out: *mut u32, max_len: i64) -> i64 {
     }
 }
 
-/// Free a result pointer returned by `ime_key`.
+/// Free a result pointer returned by `ime_key`.
+///
+/// # Safety
+/// * `r` must be a pointer returned by `ime_key`, or null
+/// * Must be called exactly once per non-null `ime_key` return
+/// * Do not use `r` after calling this function
+#[no_mangle]
+pub unsafe extern "C" fn ime_free(r: *mut Result) {
+    if !r.is_null() {
+        drop(Box::from_raw(r));
+    }
+}
+
+// ============================================================
+// Shortcut FFI
+// ============================================================
+
+/// Add a shortcut to the engine.
+///
+/// # Arguments
+/// * `trigger` - C string for trigger (e.g., "vn")
+/// * `replacement` - C string for replacement (e.g., "Việt Nam")
+///
+/// # Safety
+/// Both pointers must be valid null-terminated UTF-8 strings.
+#[no_mangle]
+pub unsafe extern "C" fn ime_add_shortcut(
+    trigger: *const std::os::raw::c_char,
+    replacement: *const std::os::raw::c_char,
+) {
+    if trigger.is_null() || replacement.is_null() {
+        return;
+    }
+
+    let trigger_str = match std::ffi::CStr::from_ptr(trigger).to_str() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+    let replacement_str = match std::ffi::CStr::from_ptr(replacement).to_str() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        // Auto-detect shortcut type:
+        // - If trigger contains only non-letter chars (like "->", "=>"), use immediate trigger
+        // - Otherwise use word boundary trigger (traditional abbreviations like "vn" → "Việt Nam")
+        let is_symbol_trigger = trigger_str.chars().all(|c| !c.is_alphabetic());
+        let shortcut = if is_symbol_trigger {
+            engine::shortcut::Shortcut::immediate(trigger_str, replacement_str)
+        } else {
+            engine::shortcut::Shortcut::new(trigger_str, replacement_str)
+        };
+        e.shortcuts_mut().add(shortcut);
+    }
+}
+
+/// Remove a shortcut from the engine.
+///
+/// # Arguments
+/// * `trigger` - C string for trigger to remove
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_remove_shortcut(trigger: *const std::os::raw::c_char) {
+    if trigger.is_null() {
+        return;
+    }
+
+    let trigger_str = match std::ffi::CStr::from_ptr(trigger).to_str() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.shortcuts_mut().remove(trigger_str);
+    }
+}
+
+/// Clear all shortcuts from the engine.
+#[no_mangle]
+pub extern "C" fn ime_clear_shortcuts() {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.shortcuts_mut().clear();
+    }
+}
+
+// ============================================================
+// Word Restore FFI
+// ============================================================
+
+/// Restore buffer from a Vietnamese word string.
+///
+/// Used when native app detects cursor at word boundary and user
+/// wants to continue editing (e.g., backspace into previous word).
+/// Parses Vietnamese characters back to buffer components.
+///
+/// # Arguments
+/// * `word` - C string containing the Vietnamese word to restore
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_restore_word(word: *const std::os::raw::c_char) {
+    if word.is_null() {
+        return;
+    }
+    let word_str = match std::ffi::CStr::from_ptr(word).to_str() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.restore_word(word_str);
+    }
+}
+
+/// Load the word around the caret from the surrounding text.
+///
+/// Lets the engine edit any word under the cursor (IBus/Fcitx surrounding
+/// text, TSF document context), instead of guessing it like `ime_restore_word`.
+///
+/// # Arguments
+/// * `text` - C string with the text around the caret (UTF-8)
+/// * `cursor_offset` - Caret position in chars (Unicode code points) into `text`
+/// * `out_before` / `out_after` - Receive how many chars of the word are before
+///   and after the caret (nullable). The engine caret is placed between them, so
+///   following results are caret-relative (`delete_after`, `caret_back`).
+///
+/// # Returns
+/// true if a word was found at the caret (buffer loaded), false otherwise.
+///
+/// # Safety
+/// `text` must be a valid null-terminated UTF-8 string. `out_before` and
+/// `out_after` must be null or point to writable `u32`s.
+#[no_mangle]
+pub unsafe extern "C" fn ime_set_surrounding_text(
+    text: *const std::os::raw::c_char,
+    cursor_offset: i64,
+    out_before: *mut u32,
+    out_after: *mut u32,
+) -> bool {
+    if text.is_null() || cursor_offset < 0 {
+        return false;
+    }
+    let text_str = match std::ffi::CStr::from_ptr(text).to_str() {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    let mut guard = lock_engine();
+    let Some(ref mut e) = *guard else {
+        return false;
+    };
+    match e.set_surrounding_text(text_str, cursor_offset as usize) {
+        Some((before, after)) => {
+            if !out_before.is_null() {
+                *out_before = before as u32;
+            }
+            if !out_after.is_null() {
+                *out_after = after as u32;
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// Swap the last committed word between Vietnamese and raw keystrokes.
+///
+/// For fixing a wrong auto-restore guess (`saan` ↔ `sân`, `tẽt` ↔ `text`)
+/// right after the word was committed with space. The trailing spaces are
+/// included in the backspace count and retyped. Call again to swap back.
+///
+/// # Returns
+/// * Pointer to `Result` struct (caller must free with `ime_free`);
+///   `action` 0 if there is no word to toggle
+/// * `null` if engine not initialized
+#[no_mangle]
+pub extern "C" fn ime_toggle_last_word() -> *mut Result {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        Box::into_raw(Box::new(e.toggle_last_word()))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+/// Cycle the case of the current or last committed word.
+///
+/// lower → Title → UPPER → lower, keeping diacritics (`việt` → `Việt` →
+/// `VIỆT`). Right after a committed word, its trailing spaces are included
+/// in the backspace count and retyped.
+///
+/// # Returns
+/// * Pointer to `Result` struct (caller must free with `ime_free`);
+///   `action` 0 if there is no word
+/// * `null` if engine not initialized
+#[no_mangle]
+pub extern "C" fn ime_cycle_case() -> *mut Result {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        Box::into_raw(Box::new(e.cycle_case()))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+// ============================================================
+// Undo FFI
+// ============================================================
+
+/// Step back one transform in the current word.
+///
+/// `người` → `ngươi` → `nguoi`: marks, tones, stroke, w → ư and bracket
+/// vowels are undone one at a time, keeping letters typed after them.
+/// Returns a diff-mode result (`action` 0 if there is nothing to undo).
+///
+/// # Returns
+/// * Pointer to `Result` struct (caller must free with `ime_free`)
+/// * `null` if engine not initialized
+#[no_mangle]
+pub extern "C" fn ime_undo() -> *mut Result {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        Box::into_raw(Box::new(e.undo()))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+/// Re-apply the last transform undone with `ime_undo`.
+///
+/// # Returns
+/// * Pointer to `Result` struct (caller must free with `ime_free`)
+/// * `null` if engine not initialized
+#[no_mangle]
+pub extern "C" fn ime_redo() -> *mut Result {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        Box::into_raw(Box::new(e.redo()))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+/// Set the key that undoes the last transform while typing a word.
+///
+/// Shift + key redoes. Outside a word, or with nothing to undo, the key
+/// types as usual.
+///
+/// # Arguments
+/// * `key` - macOS virtual keycode, or negative to disable the key
+///
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_undo_key(key: i32) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_undo_key(u16::try_from(key).ok());
+    }
+}
+
+/// Set the key that makes the next keystroke literal.
+///
+/// The key is swallowed and the next letter (or VNI digit) is typed without
+/// any mark, tone or stroke. Pressing it twice types the key itself.
+///
+/// # Arguments
+/// * `key` - macOS virtual keycode, or negative to disable the key
+///
+/// No-op if engine not initialized.
+#[no_mangle]
+pub extern "C" fn ime_literal_key(key: i32) {
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_literal_key(u16::try_from(key).ok());
+    }
+}
+
+// ============================================================
+// Settings FFI
+// ============================================================
+
+/// Copy a string into a caller-provided buffer as NUL-terminated UTF-8.
+///
+/// Returns the string length in bytes (excluding NUL). If `max_len` is too
+/// small (or `out` is null), nothing is written - callers can query the
+/// required size first by passing null/0.
+unsafe fn write_c_string(s: &str, out: *mut std::os::raw::c_char, max_len: i64) -> i64 {
+    let bytes = s.as_bytes();
+    if !out.is_null() && max_len > bytes.len() as i64 {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), out as *mut u8, bytes.len());
+        *out.add(bytes.len()) = 0;
+    }
+    bytes.len() as i64
+}
+
+/// Export all engine settings as JSON.
+///
+/// # Arguments
+/// * `out` - Output buffer for NUL-terminated UTF-8 JSON (may be null)
+/// * `max_len` - Size of `out` in bytes
+///
+/// # Returns
+/// Length of the JSON in bytes (excluding NUL), or 0 if engine not initialized.
+/// If the return value is >= `max_len`, nothing was written: retry with a
+/// larger buffer.
+///
+/// # Safety
+/// `out` must be null or point to at least `max_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_get_settings_json(
+    out: *mut std::os::raw::c_char,
+    max_len: i64,
+) -> i64 {
+    let guard = lock_engine();
+    if let Some(ref e) = *guard {
+        write_c_string(&e.settings().to_json(), out, max_len)
+    } else {
+        0
+    }
+}
+
+/// Import engine settings from JSON and apply them.
+///
+/// Older schema versions are migrated; missing keys use defaults.
+/// The in-progress word is preserved unless the settings disable the engine.
+///
+/// # Returns
+/// `true` if the JSON was valid and applied, `false` otherwise
+/// (engine keeps its previous settings).
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_set_settings_json(json: *const std::os::raw::c_char) -> bool {
+    if json.is_null() {
+        return false;
+    }
+    let json_str = match std::ffi::CStr::from_ptr(json).to_str() {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    let settings = match engine::settings::EngineSettings::from_json(json_str) {
+        Some(s) => s,
+        None => return false,
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.apply_settings(&settings);
+        true
+    } else {
+        false
+    }
+}
+
+// ============================================================
+// Profile FFI
+// ============================================================
+
+/// Add or replace a named settings profile from JSON.
+///
+/// Format: `{"name":"Code","settings":{...},"shortcuts":["fn","impl"]}`
+/// * `settings` - same format as `ime_set_settings_json` (may be partial)
+/// * `shortcuts` - triggers active in this profile; omit or null for all
+///
+/// # Returns
+/// `true` if the profile was valid and stored.
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_profile_set_json(json: *const std::os::raw::c_char) -> bool {
+    if json.is_null() {
+        return false;
+    }
+    let json_str = match std::ffi::CStr::from_ptr(json).to_str() {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    let profile = match engine::profile::Profile::from_json(json_str) {
+        Some(p) => p,
+        None => return false,
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.add_profile(profile);
+        true
+    } else {
+        false
+    }
+}
+
+/// Remove a named settings profile.
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_profile_remove(name: *const std::os::raw::c_char) -> bool {
+    if name.is_null() {
+        return false;
+    }
+    let name_str = match std::ffi::CStr::from_ptr(name).to_str() {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.remove_profile(name_str)
+    } else {
+        false
+    }
+}
+
+/// Activate a named profile (settings + shortcut subset) atomically.
+///
+/// The in-progress word is preserved.
+///
+/// # Returns
+/// `false` if no profile has that name (settings unchanged).
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_profile_activate(name: *const std::os::raw::c_char) -> bool {
+    if name.is_null() {
+        return false;
+    }
+    let name_str = match std::ffi::CStr::from_ptr(name).to_str() {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.activate_profile(name_str)
+    } else {
+        false
+    }
+}
+
+/// Get the name of the active profile.
+///
+/// Same buffer convention as `ime_get_settings_json`.
+/// Returns 0 if no profile is active.
+///
+/// # Safety
+/// `out` must be null or point to at least `max_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_profile_active(out: *mut std::os::raw::c_char, max_len: i64) -> i64 {
+    let guard = lock_engine();
+    match guard.as_ref().and_then(|e| e.active_profile()) {
+        Some(name) => write_c_string(name, out, max_len),
+        None => 0,
+    }
+}
+
+// ============================================================
+// App Rules FFI
+// ============================================================
+
+/// Replace all per-application rules from a JSON array.
+///
+/// Format (priority order, first match wins):
+/// `[{"name":"KeePass","app":"keepass*","title":"*","settings":{"enabled":false}}]`
+/// * `app` / `title` - case-insensitive globs (`*`, `?`); `title` is optional
+/// * `settings` - partial settings object (same keys as `ime_set_settings_json`)
+///
+/// # Returns
+/// `true` if all rules were valid and stored (otherwise rules are unchanged).
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_set_app_rules_json(json: *const std::os::raw::c_char) -> bool {
+    if json.is_null() {
+        return false;
+    }
+    let json_str = match std::ffi::CStr::from_ptr(json).to_str() {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    let rules = match engine::app_rules::rules_from_json(json_str) {
+        Some(r) => r,
+        None => return false,
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_app_rules(rules);
+        true
+    } else {
+        false
+    }
+}
+
+/// Report the focused application and evaluate per-application rules.
+///
+/// Call on every focus change. Clears the buffer and word history.
+///
+/// # Arguments
+/// * `app_id` - process name, bundle id or WM_CLASS
+/// * `window_title` - window title, may be null
+///
+/// # Returns
+/// `true` if a rule matched (query its name with `ime_app_rule_matched`).
+///
+/// # Safety
+/// Pointers must be valid null-terminated UTF-8 strings (`window_title` may be null).
+#[no_mangle]
+pub unsafe extern "C" fn ime_set_app(
+    app_id: *const std::os::raw::c_char,
+    window_title: *const std::os::raw::c_char,
+) -> bool {
+    if app_id.is_null() {
+        return false;
+    }
+    let app_str = match std::ffi::CStr::from_ptr(app_id).to_str() {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    let title_str = if window_title.is_null() {
+        ""
+    } else {
+        std::ffi::CStr::from_ptr(window_title)
+            .to_str()
+            .unwrap_or("")
+    };
+    let mut guard = lock_engine();
+    if let Some(ref mut e) = *guard {
+        e.set_app(app_str, title_str).is_some()
+    } else {
+        false
+    }
+}
+
+/// Get the name of the app rule currently in effect.
+///
+/// Same buffer convention as `ime_get_settings_json`.
+/// Returns 0 if no rule matched.
+///
+/// # Safety
+/// `out` must be null or point to at least `max_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ime_app_rule_matched(out: *mut std::os::raw::c_char, max_len: i64) -> i64 {
+    let guard = lock_engine();
+    match guard.as_ref().and_then(|e| e.matched_app_rule()) {
+        Some(name) => write_c_string(name, out, max_len),
+        None => 0,
+    }
+}
+
+// ============================================================
+// Event FFI
+// ============================================================
+
+/// Take the oldest engine event as JSON.
+///
+/// Events tell what keys did beyond the screen diff, e.g.
+/// `{"type":"transform_applied","kind":"tone"}`,
+/// `{"type":"auto_restored","raw":"text","vietnamese":"tẽt"}`,
+/// `{"type":"shortcut_expanded","trigger":"vn"}`,
+/// `{"type":"word_committed","text":"việt"}`. Other types:
+/// `transform_reverted`, `auto_capitalized`, `history_restored`.
+/// Call after each key until it returns 0.
+///
+/// Same buffer convention as `ime_get_settings_json`. If `max_len` is too
+/// small, nothing is written and the event stays queued.
+/// Returns 0 if there is no event or engine not initialized.
 ///
 /// # Safety
-/// * `r` must be a pointer returned by `ime_key`, or null
-/// * Must be called exactly once per non-null `ime_key` return
-/// * Do not use `r` after calling this function
+/// `out` must be null or point to at least `max_len` writable bytes.
 #[no_mangle]
-pub unsafe extern "C" fn ime_free(r: *mut Result) {
-    if !r.is_null() {
-        drop(Box::from_raw(r));
+pub unsafe extern "C" fn ime_poll_event(out: *mut std::os::raw::c_char, max_len: i64) -> i64 {
+    let mut guard = lock_engine();
+    let Some(ref mut e) = *guard else {
+        return 0;
+    };
+    let Some(json) = e.peek_event().map(|ev| ev.to_json()) else {
+        return 0;
+    };
+    let len = write_c_string(&json, out, max_len);
+    if max_len > len {
+        e.poll_event();
     }
+    len
 }
 
 // ============================================================
-// Shortcut FFI
+// Word State FFI
 // ============================================================
 
-/// Add a shortcut to the engine.
+/// Export the current word's state as JSON (buffer, raw keys, per-word flags).
 ///
-/// # Arguments
-/// * `trigger` - C string for trigger (e.g., "vn")
-/// * `replacement` - C string for replacement (e.g., "Việt Nam")
+/// Attach to bug reports; load with `ime_set_word_state_json` to reproduce.
+/// Same buffer convention as `ime_get_settings_json`.
+/// Returns 0 if engine not initialized or in incognito mode.
 ///
 /// # Safety
-/// Both pointers must be valid null-terminated UTF-8 strings.
+/// `out` must be null or point to at least `max_len` writable bytes.
 #[no_mangle]
-pub unsafe extern "C" fn ime_add_shortcut(
-    trigger: *const std::os::raw::c_char,
-    replacement: *const std::os::raw::c_char,
-) {
-    if trigger.is_null() || replacement.is_null() {
-        return;
+pub unsafe extern "C" fn ime_get_word_state_json(
+    out: *mut std::os::raw::c_char,
+    max_len: i64,
+) -> i64 {
+    let guard = lock_engine();
+    match *guard {
+        Some(ref e) if !e.incognito() => write_c_string(&e.snapshot().to_json(), out, max_len),
+        _ => 0,
     }
+}
 
-    let trigger_str = match std::ffi::CStr::from_ptr(trigger).to_str() {
+/// Replace the current word with a state exported by `ime_get_word_state_json`.
+///
+/// The host should show the restored word itself; nothing is sent.
+///
+/// # Returns
+/// `true` if the JSON was valid and applied, `false` otherwise
+/// (current word unchanged).
+///
+/// # Safety
+/// Pointer must be a valid null-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn ime_set_word_state_json(json: *const std::os::raw::c_char) -> bool {
+    if json.is_null() {
+        return false;
+    }
+    let json_str = match std::ffi::CStr::from_ptr(json).to_str() {
         Ok(s) => s,
-        Err(_) => return,
+        Err(_) => return false,
     };
-    let replacement_str = match std::ffi::CStr::from_ptr(replacement).to_str() {
-        Ok(s) => s,
-        Err(_) => return,
+    let snapshot = match engine::word_state::WordSnapshot::from_json(json_str) {
+        Some(s) => s,
+        None => return false,
     };
-
     let mut guard = lock_engine();
     if let Some(ref mut e) = *guard {
-        // Auto-detect shortcut type:
-        // - If trigger contains only non-letter chars (like "->", "=>"), use immediate trigger
-        // - Otherwise use word boundary trigger (traditional abbreviations like "vn" → "Việt Nam")
-        let is_symbol_trigger = trigger_str.chars().all(|c| !c.is_alphabetic());
-        let shortcut = if is_symbol_trigger {
-            engine::shortcut::Shortcut::immediate(trigger_str, replacement_str)
-        } else {
-            engine::shortcut::Shortcut::new(trigger_str, replacement_str)
-        };
-        e.shortcuts_mut().add(shortcut);
+        e.restore(&snapshot);
+        true
+    } else {
+        false
     }
 }
 
-/// Remove a shortcut from the engine.
+// ============================================================
+// Composition FFI
+// ============================================================
+
+/// Set the output mode.
 ///
 /// # Arguments
-/// * `trigger` - C string for trigger to remove
+/// * `mode` - 0 for diff (`ime_key_ext`), 1 for preedit + commit (`ime_key_composed`),
+///   2 for commit-only (`ime_key_ext` sends whole words at word boundaries)
 ///
-/// # Safety
-/// Pointer must be a valid null-terminated UTF-8 string.
+/// Frontends read the mode (also part of settings and app rules) to pick
+/// which key function to call. Unknown modes are ignored.
+/// No-op if engine not initialized.
 #[no_mangle]
-pub unsafe extern "C" fn ime_remove_shortcut(trigger: *const std::os::raw::c_char) {
-    if trigger.is_null() {
-        return;
-    }
-
-    let trigger_str = match std::ffi::CStr::from_ptr(trigger).to_str() {
-        Ok(s) => s,
-        Err(_) => return,
-    };
-
+pub extern "C" fn ime_output_mode(mode: u8) {
     let mut guard = lock_engine();
     if let Some(ref mut e) = *guard {
-        e.shortcuts_mut().remove(trigger_str);
+        e.set_output_mode(mode);
     }
 }
 
-/// Clear all shortcuts from the engine.
+/// Process a key event, returning preedit + commit instead of a diff.
+///
+/// Same arguments as `ime_key_ext`. The host should:
+/// 1. Delete `delete_before` chars before the caret (already committed text)
+/// 2. Commit `commit` (if `commit_len > 0`)
+/// 3. Show `preedit` with the caret at `caret`
+/// 4. Pass the key through unless the key_consumed flag is set
+///
+/// # Returns
+/// * Pointer to `Composition` struct (caller must free with `ime_free_composition`)
+/// * `null` if engine not initialized
 #[no_mangle]
-pub extern "C" fn ime_clear_shortcuts() {
+pub extern "C" fn ime_key_composed(
+    key: u16,
+    caps: bool,
+    ctrl: bool,
+    shift: bool,
+) -> *mut Composition {
     let mut guard = lock_engine();
     if let Some(ref mut e) = *guard {
-        e.shortcuts_mut().clear();
+        let c = e.on_key_composed(key, caps, ctrl, shift);
+        Box::into_raw(Box::new(c))
+    } else {
+        std::ptr::null_mut()
     }
 }
 
-// ============================================================
-// Word Restore FFI
-// ============================================================
-
-/// Restore buffer from a Vietnamese word string.
-///
-/// Used when native app detects cursor at word boundary and user
-/// wants to continue editing (e.g., backspace into previous word).
-/// Parses Vietnamese characters back to buffer components.
+/// Process a key event with repeat flag and timestamp, returning preedit + commit.
 ///
-/// # Arguments
-/// * `word` - C string containing the Vietnamese word to restore
+/// Same as `ime_key_composed`, plus `repeat` and `time_ms` as in
+/// `ime_key_timed` (literal auto-repeat, idle timeout word reset).
 ///
-/// # Safety
-/// Pointer must be a valid null-terminated UTF-8 string.
+/// # Returns
+/// * Pointer to `Composition` struct (caller must free with `ime_free_composition`)
+/// * `null` if engine not initialized
 #[no_mangle]
-pub unsafe extern "C" fn ime_restore_word(word: *const std::os::raw::c_char) {
-    if word.is_null() {
-        return;
-    }
-    let word_str = match std::ffi::CStr::from_ptr(word).to_str() {
-        Ok(s) => s,
-        Err(_) => return,
-    };
+pub extern "C" fn ime_key_composed_timed(
+    key: u16,
+    caps: bool,
+    ctrl: bool,
+    shift: bool,
+    repeat: bool,
+    time_ms: u64,
+) -> *mut Composition {
     let mut guard = lock_engine();
     if let Some(ref mut e) = *guard {
-        e.restore_word(word_str);
+        let c = e.on_key_composed_timed(key, caps, ctrl, shift, repeat, time_ms);
+        Box::into_raw(Box::new(c))
+    } else {
+        std::ptr::null_mut()
+    }
+}
+
+/// Free a composition pointer returned by `ime_key_composed`.
+///
+/// # Safety
+/// * `c` must be a pointer returned by `ime_key_composed` or
+///   `ime_key_composed_timed`, or null
+/// * Must be called exactly once per non-null return
+#[no_mangle]
+pub unsafe extern "C" fn ime_free_composition(c: *mut Composition) {
+    if !c.is_null() {
+        drop(Box::from_raw(c));
     }
 }
 
@@ -743,4 +1591,342 @@ mod tests {
 
         ime_clear();
     }
+
+    #[test]
+    #[serial]
+    fn test_settings_json_ffi() {
+        ime_init();
+
+        let json = CString::new(r#"{"version":1,"method":1,"esc_restore":true}"#).unwrap();
+        assert!(unsafe { ime_set_settings_json(json.as_ptr()) });
+
+        // Query size, then read
+        let len = unsafe { ime_get_settings_json(std::ptr::null_mut(), 0) };
+        assert!(len > 0);
+        let mut buf = vec![0 as std::os::raw::c_char; len as usize + 1];
+        let written = unsafe { ime_get_settings_json(buf.as_mut_ptr(), buf.len() as i64) };
+        assert_eq!(written, len);
+        let out = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) }
+            .to_str()
+            .unwrap();
+        assert!(out.contains("\"method\":1"));
+        assert!(out.contains("\"esc_restore\":true"));
+
+        // Invalid JSON is rejected and leaves settings untouched
+        let bad = CString::new("{method:0}").unwrap();
+        assert!(!unsafe { ime_set_settings_json(bad.as_ptr()) });
+        assert!(!unsafe { ime_set_settings_json(std::ptr::null()) });
+        let guard = lock_engine();
+        if let Some(ref e) = *guard {
+            assert_eq!(e.method(), 1);
+        }
+        drop(guard);
+
+        ime_method(0);
+        ime_esc_restore(false);
+    }
+
+    #[test]
+    #[serial]
+    fn test_profile_ffi() {
+        ime_init();
+        ime_clear_shortcuts();
+
+        let code = CString::new(
+            r#"{"name":"Code","settings":{"version":1,"auto_capitalize":false,"english_auto_restore":true},"shortcuts":[]}"#,
+        )
+        .unwrap();
+        assert!(unsafe { ime_profile_set_json(code.as_ptr()) });
+
+        let name = CString::new("code").unwrap();
+        assert!(unsafe { ime_profile_activate(name.as_ptr()) });
+
+        let mut buf = [0 as std::os::raw::c_char; 16];
+        let len = unsafe { ime_profile_active(buf.as_mut_ptr(), buf.len() as i64) };
+        assert_eq!(len, 4);
+        let active = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) };
+        assert_eq!(active.to_str().unwrap(), "Code");
+
+        let guard = lock_engine();
+        if let Some(ref e) = *guard {
+            assert!(e.english_auto_restore());
+        }
+        drop(guard);
+
+        let missing = CString::new("Formal").unwrap();
+        assert!(!unsafe { ime_profile_activate(missing.as_ptr()) });
+        assert!(unsafe { ime_profile_remove(name.as_ptr()) });
+        assert_eq!(unsafe { ime_profile_active(std::ptr::null_mut(), 0) }, 0);
+
+        ime_english_auto_restore(false);
+        ime_clear();
+    }
+
+    #[test]
+    #[serial]
+    fn test_app_rules_ffi() {
+        ime_init();
+
+        let rules =
+            CString::new(r#"[{"name":"KeePass","app":"keepass*","settings":{"enabled":false}}]"#)
+                .unwrap();
+        assert!(unsafe { ime_set_app_rules_json(rules.as_ptr()) });
+
+        let app = CString::new("KeePass.exe").unwrap();
+        assert!(unsafe { ime_set_app(app.as_ptr(), std::ptr::null()) });
+
+        let mut buf = [0 as std::os::raw::c_char; 16];
+        let len = unsafe { ime_app_rule_matched(buf.as_mut_ptr(), buf.len() as i64) };
+        assert_eq!(len, 7);
+
+        // Disabled: 'a' + 's' passes through
+        let _ = ime_key(keys::A, false, false);
+        let r = ime_key(keys::S, false, false);
+        unsafe {
+            assert_eq!((*r).action, 0);
+            ime_free(r);
+        }
+
+        // Switching away restores the user's settings
+        let other = CString::new("notepad.exe").unwrap();
+        assert!(!unsafe { ime_set_app(other.as_ptr(), std::ptr::null()) });
+        assert_eq!(unsafe { ime_app_rule_matched(std::ptr::null_mut(), 0) }, 0);
+        let guard = lock_engine();
+        if let Some(ref e) = *guard {
+            assert!(e.is_enabled());
+        }
+        drop(guard);
+
+        let bad = CString::new(r#"[{"name":"x"}]"#).unwrap();
+        assert!(!unsafe { ime_set_app_rules_json(bad.as_ptr()) });
+        ime_clear();
+    }
+
+    #[test]
+    #[serial]
+    fn test_composition_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_output_mode(1);
+        ime_clear_all();
+
+        for key in [keys::V, keys::I, keys::E, keys::E, keys::T, keys::J] {
+            let c = ime_key_composed(key, false, false, false);
+            assert!(!c.is_null());
+            unsafe { ime_free_composition(c) };
+        }
+        let c = ime_key_composed(keys::SPACE, false, false, false);
+        let comp = unsafe { &*c };
+        assert_eq!(comp.commit_string(), "việt");
+        assert_eq!(comp.preedit_len, 0);
+        assert!(!comp.key_consumed());
+        unsafe { ime_free_composition(c) };
+
+        let c = ime_key_composed_timed(keys::A, false, false, false, false, 0);
+        unsafe { ime_free_composition(c) };
+        let c = ime_key_composed_timed(keys::A, false, false, false, true, 10);
+        assert_eq!(unsafe { &*c }.preedit_string(), "aa");
+        unsafe { ime_free_composition(c) };
+
+        ime_output_mode(0);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_surrounding_text_ffi() {
+        ime_init();
+        ime_method(0);
+
+        let text = CString::new("tôi đi hoc nhé").unwrap();
+        let (mut before, mut after) = (0u32, 0u32);
+        // Caret between 'h' and 'o' of "hoc"
+        assert!(unsafe { ime_set_surrounding_text(text.as_ptr(), 8, &mut before, &mut after) });
+        assert_eq!((before, after), (1, 2));
+
+        let r = ime_key(keys::J, false, false);
+        let res = unsafe { &*r };
+        // "ọc" replaces the 2 chars after the caret, caret steps back over them
+        assert_eq!((res.backspace, res.count), (0, 2));
+        assert_eq!((res.delete_after, res.caret_back), (2, 2));
+        unsafe { ime_free(r) };
+
+        // Caret between punctuation and space: no word
+        let text = CString::new("a, b").unwrap();
+        assert!(!unsafe {
+            ime_set_surrounding_text(text.as_ptr(), 2, std::ptr::null_mut(), std::ptr::null_mut())
+        });
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_undo_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_clear();
+
+        for key in [keys::A, keys::S] {
+            unsafe { ime_free(ime_key(key, false, false)) };
+        }
+        let r = ime_undo();
+        let res = unsafe { &*r };
+        assert_eq!(res.action, engine::Action::Send as u8);
+        assert_eq!((res.backspace, res.count), (1, 1));
+        assert_eq!(res.chars[0], 'a' as u32);
+        unsafe { ime_free(r) };
+
+        let r = ime_redo();
+        assert_eq!(unsafe { (*r).chars[0] }, 'á' as u32);
+        unsafe { ime_free(r) };
+
+        // Undo key: consumed while there is something to undo
+        ime_undo_key(keys::BACKQUOTE as i32);
+        let r = ime_key(keys::BACKQUOTE, false, false);
+        assert!(unsafe { (*r).key_consumed() });
+        unsafe { ime_free(r) };
+        ime_undo_key(-1);
+        ime_clear();
+    }
+
+    #[test]
+    #[serial]
+    fn test_toggle_last_word_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_clear_all();
+
+        // "tex " → "tẽ " → toggle → "tex "
+        for key in [keys::T, keys::E, keys::X, keys::SPACE] {
+            unsafe { ime_free(ime_key(key, false, false)) };
+        }
+        let r = ime_toggle_last_word();
+        let res = unsafe { &*r };
+        assert_eq!((res.backspace, res.count), (3, 4));
+        assert_eq!(res.chars[2], 'x' as u32);
+        assert_eq!(res.chars[3], ' ' as u32);
+        unsafe { ime_free(r) };
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_poll_event_ffi() {
+        ime_init();
+        ime_method(0);
+
+        // "as" → "á": one mark event
+        for key in [keys::A, keys::S] {
+            unsafe { ime_free(ime_key(key, false, false)) };
+        }
+        let poll = |max_len: i64| {
+            let mut buf = vec![0 as std::os::raw::c_char; 64];
+            let len = unsafe { ime_poll_event(buf.as_mut_ptr(), max_len) };
+            let out = unsafe { std::ffi::CStr::from_ptr(buf.as_ptr()) };
+            (len, out.to_str().unwrap().to_string())
+        };
+        let expected = r#"{"type":"transform_applied","kind":"mark"}"#;
+
+        // Too small: nothing written, event stays queued
+        assert_eq!(poll(4), (expected.len() as i64, String::new()));
+        assert_eq!(poll(64), (expected.len() as i64, expected.to_string()));
+        assert_eq!(poll(64).0, 0);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_word_state_json_ffi() {
+        ime_init();
+        ime_method(0);
+
+        // "dd" → "đ", export, clear, import, then "d" reverts to "dd"
+        for key in [keys::D, keys::D] {
+            unsafe { ime_free(ime_key(key, false, false)) };
+        }
+        let mut buf = vec![0 as std::os::raw::c_char; 1024];
+        let len = unsafe { ime_get_word_state_json(buf.as_mut_ptr(), 1024) };
+        assert!(len > 0);
+        ime_clear();
+
+        assert!(unsafe { ime_set_word_state_json(buf.as_ptr()) });
+        let invalid = std::ffi::CString::new("{}").unwrap();
+        assert!(!unsafe { ime_set_word_state_json(invalid.as_ptr()) });
+        let r = ime_key(keys::D, false, false);
+        let res = unsafe { &*r };
+        assert_eq!((res.backspace, res.count), (1, 2));
+        unsafe { ime_free(r) };
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_incognito_ffi() {
+        ime_init();
+        ime_set_incognito(true);
+
+        let mut buf = vec![0 as std::os::raw::c_char; 1024];
+        assert_eq!(
+            unsafe { ime_get_word_state_json(buf.as_mut_ptr(), 1024) },
+            0
+        );
+        ime_set_incognito(false);
+        assert!(unsafe { ime_get_word_state_json(buf.as_mut_ptr(), 1024) } > 0);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_method_auto_detect_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_method_auto_detect(true);
+        assert_eq!(ime_detected_method(), -1);
+
+        // "a1 a2 " → "á à ": two VNI words
+        for key in [
+            keys::A,
+            keys::N1,
+            keys::SPACE,
+            keys::A,
+            keys::N2,
+            keys::SPACE,
+        ] {
+            unsafe { ime_free(ime_key(key, false, false)) };
+        }
+        assert_eq!(ime_detected_method(), 1);
+        ime_method_auto_detect(false);
+        assert_eq!(ime_detected_method(), -1);
+        ime_clear_all();
+    }
+
+    #[test]
+    #[serial]
+    fn test_auto_restore_level_ffi() {
+        ime_init();
+        ime_method(0);
+        ime_auto_restore_level(3);
+        ime_auto_restore_level(9); // ignored
+
+        // "yes " → "yes ": aggressive restores over valid "yé"
+        for key in [keys::Y, keys::E, keys::S] {
+            unsafe { ime_free(ime_key(key, false, false)) };
+        }
+        let r = ime_key(keys::SPACE, false, false);
+        let res = unsafe { &*r };
+        assert_eq!(res.action, engine::Action::Send as u8);
+        assert_eq!((res.backspace, res.count), (2, 4));
+        unsafe { ime_free(r) };
+
+        ime_english_auto_restore(false);
+        let guard = lock_engine();
+        if let Some(ref e) = *guard {
+            assert_eq!(
+                e.auto_restore_level(),
+                engine::auto_restore::AutoRestoreLevel::Off
+            );
+        }
+        drop(guard);
+        ime_clear_all();
+    }
 }
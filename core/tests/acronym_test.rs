@@ -0,0 +1,62 @@
+//! All-caps acronym passthrough tests
+//! Uppercase words that can't be Vietnamese stay as typed; uppercase
+//! Vietnamese keeps its diacritics. '\x1b' = ESC.
+
+mod common;
+use common::{run_with, type_word};
+use gonhanh_core::engine::Engine;
+
+fn acronym(cases: &[(&str, &str)]) {
+    run_with("Acronym", |e| e.set_acronym_passthrough(true), cases);
+}
+
+#[test]
+fn acronyms_stay_as_typed() {
+    acronym(&[
+        ("SSD ", "SSD "),
+        ("HTTP ", "HTTP "),
+        ("DDR5 ", "DDR5 "),
+        ("USB ", "USB "),
+        ("AWS ", "AWS "),
+        ("AWS,", "AWS,"),
+    ]);
+}
+
+#[test]
+fn reverted_while_typing() {
+    // Can't become a syllable any more: reverted at once, rest typed as-is
+    acronym(&[("DDR", "DDR"), ("USB", "USB"), ("USBS", "USBS")]);
+}
+
+#[test]
+fn uppercase_vietnamese_kept() {
+    acronym(&[
+        ("VIEETJ NAM", "VIỆT NAM"),
+        ("DDUWOWCJ ", "ĐƯỢC "),
+        ("TRUWOWNGF ", "TRƯỜNG "),
+        ("NGUYEENX ", "NGUYỄN "),
+        ("AWN ", "ĂN "),
+        ("THUWR ", "THỬ "),
+    ]);
+}
+
+#[test]
+fn not_all_caps() {
+    acronym(&[("Usb ", "Úb "), ("aws ", "ắ "), ("ddr5", "đr5")]);
+}
+
+#[test]
+fn esc_after_revert() {
+    let mut e = Engine::new();
+    e.set_acronym_passthrough(true);
+    e.set_esc_restore(true);
+    assert_eq!(type_word(&mut e, "DDR\x1b"), "DDR");
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut e = Engine::new();
+    assert_eq!(type_word(&mut e, "DDR5 "), "ĐR5 ");
+    let mut e = Engine::new();
+    assert_eq!(type_word(&mut e, "AWS "), "Ắ ");
+}
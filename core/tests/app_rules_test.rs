@@ -0,0 +1,129 @@
+//! Per-application rule tests
+//! Rules are evaluated inside the engine on `set_app`, override settings
+//! on top of the user's own, and never accumulate across app switches.
+
+use gonhanh_core::engine::app_rules::rules_from_json;
+use gonhanh_core::engine::profile::Profile;
+use gonhanh_core::engine::settings::EngineSettings;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+const RULES: &str = r#"[
+    {"name": "KeePass", "app": "keepass*", "settings": {"enabled": false}},
+    {"name": "Excel VNI", "app": "*excel*", "settings": {"method": 1}},
+    {"name": "Terminal", "app": "*term*", "settings": {"auto_capitalize": false}},
+    {"name": "IDE search", "app": "code*", "title": "*search*", "settings": {"enabled": false}}
+]"#;
+
+fn engine_with_rules() -> Engine {
+    let mut e = Engine::new();
+    e.set_auto_capitalize(true);
+    e.set_app_rules(rules_from_json(RULES).unwrap());
+    e
+}
+
+#[test]
+fn disable_in_keepass() {
+    let mut e = engine_with_rules();
+    assert_eq!(e.set_app("KeePass.exe", "Database"), Some("KeePass"));
+    assert!(!e.is_enabled());
+    assert_eq!(type_word(&mut e, "as"), "as");
+}
+
+#[test]
+fn vni_in_excel() {
+    let mut e = engine_with_rules();
+    assert_eq!(e.set_app("com.microsoft.Excel", ""), Some("Excel VNI"));
+    assert_eq!(type_word(&mut e, "a1"), "á");
+}
+
+#[test]
+fn no_auto_capitalize_in_terminal() {
+    let mut e = engine_with_rules();
+    assert_eq!(e.set_app("gnome-terminal-server", "~"), Some("Terminal"));
+    assert!(!e.auto_capitalize());
+    assert_eq!(type_word(&mut e, "ls. cd"), "ls. cd");
+}
+
+#[test]
+fn title_pattern_required() {
+    let mut e = engine_with_rules();
+    assert_eq!(e.set_app("Code", "main.rs - fkey"), None);
+    assert!(e.is_enabled());
+    assert_eq!(e.set_app("Code", "Search: foo"), Some("IDE search"));
+    assert!(!e.is_enabled());
+}
+
+#[test]
+fn switching_restores_user_settings() {
+    let mut e = engine_with_rules();
+    e.set_app("excel", "");
+    assert_eq!(e.method(), 1);
+    e.set_app("keepass", "");
+    assert_eq!(e.method(), 0, "Excel override must not leak into KeePass");
+    assert!(!e.is_enabled());
+    assert_eq!(e.set_app("firefox", ""), None);
+    assert_eq!(e.matched_app_rule(), None);
+    assert!(e.is_enabled());
+    assert!(e.auto_capitalize());
+}
+
+#[test]
+fn manual_changes_survive_app_switch() {
+    let mut e = engine_with_rules();
+    e.set_app("excel", "");
+    e.set_auto_capitalize(false);
+    e.set_modern_tone(false);
+    e.set_app("firefox", "");
+    assert_eq!(e.method(), 0, "Rule override restored");
+    assert!(!e.auto_capitalize(), "Manual change kept");
+    assert!(!e.modern_tone(), "Manual change kept");
+}
+
+#[test]
+fn numeric_override_applies() {
+    let mut e = Engine::new();
+    e.set_app_rules(
+        rules_from_json(r#"[{"name":"Remote","app":"mstsc*","settings":{"output_mode":2}}]"#)
+            .unwrap(),
+    );
+    assert_eq!(e.set_app("mstsc.exe", ""), Some("Remote"));
+    assert_eq!(e.output_mode() as u8, 2);
+    e.set_app("firefox", "");
+    assert_eq!(e.output_mode() as u8, 0);
+}
+
+#[test]
+fn first_matching_rule_wins() {
+    let mut e = Engine::new();
+    e.set_app_rules(
+        rules_from_json(
+            r#"[{"name":"A","app":"*","settings":{"free_tone":true}},
+                {"name":"B","app":"x","settings":{"modern_tone":false}}]"#,
+        )
+        .unwrap(),
+    );
+    assert_eq!(e.set_app("x", ""), Some("A"));
+    assert!(e.modern_tone());
+}
+
+#[test]
+fn profile_activation_keeps_rule() {
+    let mut e = engine_with_rules();
+    e.add_profile(Profile::new(
+        "Formal",
+        EngineSettings {
+            modern_tone: false,
+            auto_capitalize: true,
+            ..Default::default()
+        },
+    ));
+    e.set_app("xterm", "");
+    e.activate_profile("Formal");
+    assert!(!e.modern_tone());
+    assert!(!e.auto_capitalize(), "Terminal rule still in effect");
+
+    e.set_app("firefox", "");
+    assert!(e.auto_capitalize(), "Profile is the new base");
+    assert!(!e.modern_tone());
+}
@@ -0,0 +1,159 @@
+//! In-word caret tests
+//! LEFT/RIGHT inside the composing word keep the buffer; keys typed there
+//! edit the word in place (opt-in via `set_caret_editing`).
+//! '←' = LEFT, '→' = RIGHT, '<' = DELETE.
+
+use gonhanh_core::data::keys;
+use gonhanh_core::engine::{Action, Engine};
+use gonhanh_core::utils::char_to_key;
+
+fn caret_engine() -> Engine {
+    let mut e = Engine::new();
+    e.set_caret_editing(true);
+    e
+}
+
+/// Type input into a caret-aware editor: returns (text, caret)
+fn type_caret(e: &mut Engine, input: &str) -> (String, usize) {
+    let mut screen: Vec<char> = Vec::new();
+    let mut caret = 0;
+    for c in input.chars() {
+        let key = match c {
+            '←' => keys::LEFT,
+            '→' => keys::RIGHT,
+            _ => char_to_key(c),
+        };
+        let r = e.on_key(key, c.is_uppercase(), false);
+        if r.action == Action::Send as u8 {
+            for _ in 0..r.backspace {
+                caret -= 1;
+                screen.remove(caret);
+            }
+            for _ in 0..r.delete_after {
+                screen.remove(caret);
+            }
+            for ch in r.chars[..r.count as usize]
+                .iter()
+                .filter_map(|&c| char::from_u32(c))
+            {
+                screen.insert(caret, ch);
+                caret += 1;
+            }
+            caret -= r.caret_back as usize;
+            if keys::is_break(key) && key != keys::SPACE && !r.key_consumed() {
+                screen.insert(caret, c);
+                caret += 1;
+            }
+            continue;
+        }
+        match key {
+            keys::DELETE => {
+                caret -= 1;
+                screen.remove(caret);
+            }
+            keys::LEFT => caret = caret.saturating_sub(1),
+            keys::RIGHT => caret = (caret + 1).min(screen.len()),
+            _ => {
+                screen.insert(caret, c);
+                caret += 1;
+            }
+        }
+    }
+    (screen.into_iter().collect(), caret)
+}
+
+#[test]
+fn horn_with_caret_inside() {
+    let mut e = caret_engine();
+    assert_eq!(type_caret(&mut e, "nguoi←w"), ("ngươi".to_string(), 4));
+    assert_eq!(e.get_buffer_string(), "ngươi");
+
+    let mut e = caret_engine();
+    assert_eq!(type_caret(&mut e, "nguoi←w→f").0, "người");
+}
+
+#[test]
+fn mark_with_caret_inside() {
+    let mut e = caret_engine();
+    assert_eq!(type_caret(&mut e, "tieeng←←s"), ("tiếng".to_string(), 3));
+}
+
+#[test]
+fn insert_missing_letter() {
+    let mut e = caret_engine();
+    assert_eq!(type_caret(&mut e, "nuowif←←←g"), ("người".to_string(), 2));
+    assert_eq!(e.get_buffer_string(), "người");
+
+    // Back to the end, the word commits as usual
+    let mut e = caret_engine();
+    assert_eq!(
+        type_caret(&mut e, "nuowif←←←g→→→ "),
+        ("người ".to_string(), 6)
+    );
+}
+
+#[test]
+fn mark_moves_after_insert() {
+    let mut e = caret_engine();
+    // "hoá" typed without 'o', then 'o' inserted: mark follows the syllable
+    assert_eq!(type_caret(&mut e, "has←o").0, "hoá");
+    assert_eq!(e.get_buffer_string(), "hoá");
+}
+
+#[test]
+fn delete_inside_word() {
+    let mut e = caret_engine();
+    assert_eq!(type_caret(&mut e, "hoaf←<"), ("hà".to_string(), 1));
+    assert_eq!(e.get_buffer_string(), "hà");
+}
+
+#[test]
+fn arrows_leave_word() {
+    let mut e = caret_engine();
+    type_caret(&mut e, "ab←←←");
+    assert_eq!(e.get_buffer_string(), "", "LEFT past the word start breaks");
+
+    let mut e = caret_engine();
+    type_caret(&mut e, "ab→");
+    assert_eq!(e.get_buffer_string(), "", "RIGHT at the word end breaks");
+}
+
+#[test]
+fn arrows_end_word_by_default() {
+    let mut e = Engine::new();
+    type_caret(&mut e, "nguoi←");
+    assert_eq!(e.get_buffer_string(), "", "LEFT breaks the word when off");
+    let r = e.on_key(keys::W, false, false);
+    assert_eq!(r.delete_after, 0);
+    assert_eq!(r.caret_back, 0);
+}
+
+#[test]
+fn space_inside_word_splits() {
+    let mut e = caret_engine();
+    assert_eq!(type_caret(&mut e, "chao←← ").0, "ch ao");
+    assert_eq!(e.get_buffer_string(), "");
+}
+
+#[test]
+fn preedit_follows_caret() {
+    let mut e = caret_engine();
+    e.set_output_mode(1);
+    let mut last = None;
+    for key in [
+        keys::N,
+        keys::G,
+        keys::U,
+        keys::O,
+        keys::I,
+        keys::LEFT,
+        keys::W,
+    ] {
+        last = Some(e.on_key_composed(key, false, false, false));
+    }
+    let c = last.unwrap();
+    assert_eq!(c.preedit_string(), "ngươi");
+    assert_eq!(c.caret, 4);
+    assert_eq!(c.commit_len, 0);
+    assert!(c.key_consumed());
+}
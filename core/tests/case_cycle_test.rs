@@ -0,0 +1,86 @@
+//! Case cycling tests
+//! lower → Title → UPPER → lower on the composing or last committed word.
+
+mod common;
+use common::{apply, type_word};
+use gonhanh_core::engine::{Action, Engine};
+
+/// Type `input`, then cycle `n` times: screen after each cycle
+fn cycles(e: &mut Engine, input: &str, n: usize) -> Vec<String> {
+    let mut screen = type_word(e, input);
+    (0..n)
+        .map(|_| {
+            apply(&mut screen, &e.cycle_case());
+            screen.clone()
+        })
+        .collect()
+}
+
+#[test]
+fn cycle_composing_word() {
+    let mut e = Engine::new();
+    assert_eq!(cycles(&mut e, "vieetj", 3), ["Việt", "VIỆT", "việt"]);
+    assert_eq!(e.get_buffer_string(), "việt");
+}
+
+#[test]
+fn cycle_last_word() {
+    let mut e = Engine::new();
+    assert_eq!(
+        cycles(&mut e, "xin chaof  ", 3),
+        ["xin Chào  ", "xin CHÀO  ", "xin chào  "]
+    );
+}
+
+#[test]
+fn minimal_replacement() {
+    let mut e = Engine::new();
+    type_word(&mut e, "Nam");
+    let r = e.cycle_case();
+    assert_eq!((r.backspace, r.count), (2, 2), "only 'am' → 'AM'");
+}
+
+#[test]
+fn mixed_case_goes_lower() {
+    let mut e = Engine::new();
+    e.restore_word("ĐưỢc");
+    let mut screen = "ĐưỢc".to_string();
+    apply(&mut screen, &e.cycle_case());
+    assert_eq!(screen, "được");
+}
+
+#[test]
+fn continues_after_cycle() {
+    let mut e = Engine::new();
+    type_word(&mut e, "tieeng");
+    e.cycle_case();
+    type_word(&mut e, "s");
+    assert_eq!(e.get_buffer_string(), "Tiếng", "mark still applies");
+}
+
+#[test]
+fn backspace_restores_cycled_word() {
+    let mut e = Engine::new();
+    type_word(&mut e, "nam ");
+    e.cycle_case();
+    type_word(&mut e, "<");
+    assert_eq!(e.get_buffer_string(), "Nam");
+}
+
+#[test]
+fn lowered_auto_capital_stays_lower() {
+    let mut e = Engine::new();
+    e.set_auto_capitalize(true);
+    assert_eq!(cycles(&mut e, "ok. v", 1), ["ok. v"]);
+    // Deleting the letter does not bring auto-capitalize back
+    type_word(&mut e, "<a");
+    assert_eq!(e.get_buffer_string(), "a");
+}
+
+#[test]
+fn nothing_to_cycle() {
+    let mut e = Engine::new();
+    assert_eq!(e.cycle_case().action, Action::None as u8);
+    type_word(&mut e, "ok, ");
+    assert_eq!(e.cycle_case().action, Action::None as u8);
+}
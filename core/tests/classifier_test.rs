@@ -0,0 +1,154 @@
+//! Word classifier tests
+//! The trigram tables are trained from the English dictionary and the
+//! Vietnamese 22k list; regenerate them with:
+//! `cargo test --test classifier_test train -- --ignored`
+
+use gonhanh_core::data::chars::parse_char;
+use gonhanh_core::engine::buffer::Char;
+use gonhanh_core::engine::classifier::{
+    english_margin, symbol, telex_keys, trigram_index, COST_SCALE, MIN_KEYS, RESTORE_MARGIN,
+    SYMBOLS,
+};
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::{char_to_key, key_to_char, type_word};
+use std::collections::BTreeSet;
+use std::fs;
+
+const ENGLISH_DICT: &str = include_str!("../src/data/english_dict_merged.txt");
+const VIETNAMESE_22K: &str = include_str!("data/vietnamese_22k.txt");
+
+/// Interpolation weights for trigram, bigram and unigram estimates
+const WEIGHTS: [f64; 3] = [0.9, 0.08, 0.02];
+
+fn chars_of(word: &str) -> Option<Vec<Char>> {
+    word.chars()
+        .map(|ch| {
+            let p = parse_char(ch)?;
+            let mut c = Char::new(p.key, p.caps);
+            c.tone = p.tone;
+            c.mark = p.mark;
+            c.stroke = p.stroke;
+            Some(c)
+        })
+        .collect()
+}
+
+fn english_words() -> Vec<Vec<u16>> {
+    ENGLISH_DICT
+        .lines()
+        .map(str::trim)
+        .filter(|w| !w.is_empty() && w.chars().all(|c| c.is_ascii_lowercase()))
+        .map(|w| w.chars().map(char_to_key).collect())
+        .collect()
+}
+
+/// Distinct Vietnamese syllables of the 22k list
+fn vietnamese_syllables() -> Vec<Vec<Char>> {
+    let syllables: BTreeSet<String> = VIETNAMESE_22K
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect();
+    syllables.iter().filter_map(|s| chars_of(s)).collect()
+}
+
+/// Interpolated trigram costs (-log2 p in quarter bits, capped at 255)
+fn train(words: &[Vec<u16>]) -> Vec<u8> {
+    let mut tri = vec![0f64; SYMBOLS * SYMBOLS * SYMBOLS];
+    let mut bi = vec![0f64; SYMBOLS * SYMBOLS];
+    let mut uni = vec![0f64; SYMBOLS];
+    for word in words {
+        let mut syms = vec![0u8, 0];
+        syms.extend(word.iter().filter_map(|&k| symbol(k)));
+        syms.push(0);
+        for w in syms.windows(3) {
+            let (b, c) = (w[1] as usize, w[2] as usize);
+            tri[trigram_index(w[0], w[1], w[2])] += 1.0;
+            bi[b * SYMBOLS + c] += 1.0;
+            uni[c] += 1.0;
+        }
+    }
+
+    let uni_total: f64 = uni.iter().sum();
+    let mut table = vec![0u8; tri.len()];
+    for a in 0..SYMBOLS {
+        for b in 0..SYMBOLS {
+            let tri_ctx: f64 = (0..SYMBOLS)
+                .map(|c| tri[trigram_index(a as u8, b as u8, c as u8)])
+                .sum();
+            let bi_ctx: f64 = bi[b * SYMBOLS..(b + 1) * SYMBOLS].iter().sum();
+            for c in 0..SYMBOLS {
+                let i = trigram_index(a as u8, b as u8, c as u8);
+                let p1 = (uni[c] + 1.0) / (uni_total + SYMBOLS as f64);
+                let p2 = if bi_ctx > 0.0 {
+                    bi[b * SYMBOLS + c] / bi_ctx
+                } else {
+                    p1
+                };
+                let p3 = if tri_ctx > 0.0 { tri[i] / tri_ctx } else { p2 };
+                let p = WEIGHTS[0] * p3 + WEIGHTS[1] * p2 + WEIGHTS[2] * p1;
+                table[i] = (-p.log2() * COST_SCALE as f64).round().min(255.0) as u8;
+            }
+        }
+    }
+    table
+}
+
+fn write_table(out: &mut String, name: &str, table: &[u8]) {
+    out.push_str(&format!("pub static {}: [u8; {}] = [\n", name, table.len()));
+    for row in table.chunks(SYMBOLS) {
+        let row: Vec<String> = row.iter().map(|v| v.to_string()).collect();
+        out.push_str(&format!("    {},\n", row.join(", ")));
+    }
+    out.push_str("];\n");
+}
+
+#[test]
+#[ignore]
+fn train_tables() {
+    let mut out = String::from(
+        "//! English/Vietnamese trigram tables for `engine::classifier`\n\
+         //!\n\
+         //! Generated by `cargo test --test classifier_test train -- --ignored`\n\
+         //! from `english_dict_merged.txt` and `tests/data/vietnamese_22k.txt`.\n\
+         //! Index: `trigram_index(a, b, c)`, value: -log2 p(c | a b) in quarter bits.\n\n\
+         #[rustfmt::skip]\n",
+    );
+    write_table(&mut out, "ENGLISH", &train(&english_words()));
+    out.push_str("\n#[rustfmt::skip]\n");
+    let vietnamese: Vec<Vec<u16>> = vietnamese_syllables().iter().map(telex_keys).collect();
+    write_table(&mut out, "VIETNAMESE", &train(&vietnamese));
+    fs::write("src/data/ngram.rs", out).expect("Failed to write src/data/ngram.rs");
+}
+
+#[test]
+fn vietnamese_syllables_below_restore_margin() {
+    let mut checked = 0;
+    for chars in vietnamese_syllables() {
+        let keys = telex_keys(&chars);
+        if keys.len() < MIN_KEYS {
+            continue;
+        }
+        let margin = english_margin(&keys, &chars).unwrap();
+        let word: String = chars
+            .iter()
+            .map(|c| c.key)
+            .filter_map(|k| key_to_char(k, false))
+            .collect();
+        assert!(margin <= RESTORE_MARGIN, "{} scores {}", word, margin);
+        checked += 1;
+    }
+    assert!(checked > 4000);
+}
+
+#[test]
+fn english_words_restored() {
+    let mut e = Engine::new();
+    e.set_english_auto_restore(true);
+    for word in ["hawk", "myers", "pores", "quark", "saks"] {
+        e.clear();
+        assert_eq!(
+            type_word(&mut e, &format!("{} ", word)),
+            format!("{} ", word)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Commit-only output mode tests
+//! Nothing is sent while composing; whole words arrive at word boundaries.
+
+use gonhanh_core::data::keys;
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::{Action, Engine};
+use gonhanh_core::utils::type_word;
+
+fn engine() -> Engine {
+    let mut e = Engine::new();
+    e.set_output_mode(2);
+    e
+}
+
+#[test]
+fn nothing_sent_while_composing() {
+    let mut e = engine();
+    for key in [keys::V, keys::I, keys::E, keys::E, keys::T, keys::J] {
+        let r = e.on_key_ext(key, false, false, false);
+        assert_eq!(r.action, Action::Send as u8);
+        assert_eq!((r.backspace, r.count), (0, 0));
+        assert!(r.key_consumed(), "Letter keys must be swallowed");
+    }
+    assert_eq!(e.get_buffer_string(), "việt");
+}
+
+#[test]
+fn word_sent_at_boundary() {
+    assert_eq!(type_word(&mut engine(), "vieetj nam "), "việt nam ");
+    assert_eq!(type_word(&mut engine(), "xin chaof."), "xin chào.");
+    // Backspace edits the hidden word: same final text as diff output
+    let input = "dduwowcj<<c, ";
+    assert_eq!(
+        type_word(&mut engine(), input),
+        type_word(&mut Engine::new(), input)
+    );
+    assert_eq!(type_word(&mut engine(), "tieengs"), "");
+}
+
+#[test]
+fn enter_commits_and_passes_through() {
+    let mut e = engine();
+    type_word(&mut e, "ddaau");
+    let r = e.on_key_ext(keys::RETURN, false, false, false);
+    assert_eq!(r.action, Action::Send as u8);
+    assert!(!r.key_consumed());
+    let sent: String = r.chars[..r.count as usize]
+        .iter()
+        .filter_map(|&c| char::from_u32(c))
+        .collect();
+    assert_eq!(sent, "đâu");
+}
+
+#[test]
+fn shortcut_and_auto_restore_shape_output() {
+    let mut e = engine();
+    e.shortcuts_mut().add(Shortcut::new("vn", "Việt Nam"));
+    assert_eq!(type_word(&mut e, "vn "), "Việt Nam ");
+
+    let mut e = engine();
+    e.set_english_auto_restore(true);
+    assert_eq!(type_word(&mut e, "text "), "text ");
+}
+
+#[test]
+fn esc_discards_word() {
+    let mut e = engine();
+    e.set_esc_restore(true);
+    assert_eq!(type_word(&mut e, "vieetj\x1bnam "), "nam ");
+    assert_eq!(e.get_buffer_string(), "");
+}
+
+#[test]
+fn backspace_reopens_whole_word() {
+    let mut e = engine();
+    type_word(&mut e, "vieets ");
+    // Space and "viết" leave the app, the word is held again
+    let r = e.on_key_ext(keys::DELETE, false, false, false);
+    assert_eq!(r.action, Action::Send as u8);
+    assert_eq!((r.backspace, r.count), (5, 0));
+    assert!(r.key_consumed());
+    assert_eq!(e.get_buffer_string(), "viết");
+
+    let r = e.on_key_ext(keys::S, false, false, false);
+    assert_eq!((r.backspace, r.count), (0, 0), "Edits stay hidden");
+    let r = e.on_key_ext(keys::SPACE, false, false, false);
+    let sent: String = r.chars[..r.count as usize]
+        .iter()
+        .filter_map(|&c| char::from_u32(c))
+        .collect();
+    assert_eq!((r.backspace, sent.as_str()), (0, "viets "));
+}
@@ -12,7 +12,7 @@ pub use gonhanh_core::utils::{
     vni_traditional,
 };
 
-use gonhanh_core::engine::{Action, Engine};
+use gonhanh_core::engine::{Action, Engine, Result};
 
 // ============================================================
 // TEST RUNNERS - Extended helpers for integration tests
@@ -39,6 +39,16 @@ pub fn both(telex_cases: &[(&str, &str)], vni_cases: &[(&str, &str)]) {
     vni(vni_cases);
 }
 
+/// Run Telex cases, each on a fresh engine configured by `setup`
+pub fn run_with(label: &str, setup: impl Fn(&mut Engine), cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        let mut e = Engine::new();
+        setup(&mut e);
+        let result = type_word(&mut e, input);
+        assert_eq!(result, *expected, "[{}] '{}' → '{}'", label, input, result);
+    }
+}
+
 // ============================================================
 // ENGINE STATE HELPERS
 // ============================================================
@@ -53,6 +63,21 @@ pub fn engine_vni() -> Engine {
     e
 }
 
+/// Apply a result to the screen (caret at the end of the word)
+pub fn apply(screen: &mut String, r: &Result) {
+    if r.action != Action::Send as u8 {
+        return;
+    }
+    for _ in 0..r.backspace {
+        screen.pop();
+    }
+    screen.extend(
+        r.chars[..r.count as usize]
+            .iter()
+            .filter_map(|&c| char::from_u32(c)),
+    );
+}
+
 // ============================================================
 // ASSERTION HELPERS
 // ============================================================
@@ -0,0 +1,99 @@
+//! Preedit/composition output tests
+//! A simulated IME-framework host (commit + inline preedit) must end up with
+//! the same text as a host applying backspace diffs.
+
+use gonhanh_core::data::keys;
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::{char_to_key, type_word};
+
+/// Type input like an IBus/Fcitx/TSF host: returns (document, preedit)
+fn type_composed(e: &mut Engine, input: &str) -> (String, String) {
+    let mut doc = String::new();
+    let mut preedit = String::new();
+    for c in input.chars() {
+        let (key, shift) = match c {
+            '>' => (keys::DOT, true),
+            '@' => (keys::N2, true),
+            _ => (char_to_key(c), false),
+        };
+        let comp = e.on_key_composed(key, c.is_uppercase(), false, shift);
+        for _ in 0..comp.delete_before {
+            doc.pop();
+        }
+        doc.push_str(&comp.commit_string());
+        preedit = comp.preedit_string();
+        assert_eq!(comp.caret, comp.preedit_len);
+        if !comp.key_consumed() {
+            match key {
+                keys::DELETE => {
+                    doc.pop();
+                }
+                keys::ESC => {}
+                _ => doc.push(c),
+            }
+        }
+    }
+    (doc, preedit)
+}
+
+fn engine() -> Engine {
+    let mut e = Engine::new();
+    e.set_output_mode(1);
+    e
+}
+
+#[test]
+fn same_text_as_diff_output() {
+    let cases = [
+        "vieetj nam ",
+        "tieengs vieetj",
+        "xin chaof. ban",
+        "dduwowcj<<c ",
+        "ddeenf ",
+        "user@gmail.com",
+        "a ->",
+        "Nguyeenx Vawn A",
+        "chaof < s",
+    ];
+    for input in cases {
+        let expected = type_word(&mut Engine::new(), input);
+        let (doc, preedit) = type_composed(&mut engine(), input);
+        assert_eq!(doc + &preedit, expected, "input: {:?}", input);
+    }
+}
+
+#[test]
+fn preedit_holds_current_word() {
+    let mut e = engine();
+    let (doc, preedit) = type_composed(&mut e, "xin tieengs");
+    assert_eq!(doc, "xin ");
+    assert_eq!(preedit, "tiếng");
+    assert_eq!(preedit, e.get_buffer_string());
+}
+
+#[test]
+fn shortcut_expansion_is_committed() {
+    let mut e = engine();
+    e.shortcuts_mut().add(Shortcut::new("vn", "Việt Nam"));
+    let (doc, preedit) = type_composed(&mut e, "vn ");
+    assert_eq!(doc, "Việt Nam ");
+    assert_eq!(preedit, "");
+}
+
+#[test]
+fn auto_restore_is_committed() {
+    let mut e = engine();
+    e.set_english_auto_restore(true);
+    let (doc, preedit) = type_composed(&mut e, "text ");
+    assert_eq!(doc, "text ");
+    assert_eq!(preedit, "");
+}
+
+#[test]
+fn punctuation_commits_word() {
+    let mut e = engine();
+    let (doc, preedit) = type_composed(&mut e, "chaof,");
+    assert_eq!(doc, "chào,");
+    assert_eq!(preedit, "");
+}
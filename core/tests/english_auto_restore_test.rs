@@ -28,7 +28,7 @@
 //! Users should use raw mode (\word) or Esc to restore these manually.
 
 mod common;
-use common::{telex, telex_auto_restore};
+use common::{run_with, telex, telex_auto_restore};
 
 // =============================================================================
 // PATTERN 1: MODIFIER FOLLOWED BY CONSONANT
@@ -1351,3 +1351,120 @@ fn oe_diphthong_typing_orders() {
         ("tose ", "toé "),
     ]);
 }
+
+// =============================================================================
+// AUTO-RESTORE LEVELS
+// 0=off, 1=dictionary-only, 2=balanced (set_english_auto_restore(true)),
+// 3=aggressive
+// =============================================================================
+
+fn telex_restore_level(level: u8, cases: &[(&str, &str)]) {
+    let label = format!("Telex AutoRestore L{}", level);
+    run_with(&label, |e| e.set_auto_restore_level(level), cases);
+}
+
+#[test]
+fn level_off_never_restores() {
+    telex_restore_level(
+        0,
+        &[
+            ("text ", "tẽt "),
+            ("expect ", "ẽpect "),
+            ("window ", "ưindow "),
+            ("law ", "lă "),
+            ("hawk ", "hăk "),
+        ],
+    );
+}
+
+#[test]
+fn level_dictionary_restores_known_words() {
+    telex_restore_level(
+        1,
+        &[
+            // Invalid Vietnamese, raw keys are English dictionary words
+            ("text ", "text "),
+            ("expect ", "expect "),
+            ("window ", "window "),
+            ("user ", "user "),
+            ("law ", "law "),
+            ("boxes ", "boxes "),
+            // Valid Vietnamese stays
+            ("vieetj ", "việt "),
+            ("ddi ", "đi "),
+            ("bits ", "bít "),
+            ("yes ", "yé "),
+        ],
+    );
+}
+
+#[test]
+fn level_dictionary_skips_heuristic_restores() {
+    telex_restore_level(
+        1,
+        &[
+            // Classifier-only restores are Balanced and up
+            ("hawk ", "hăk "),
+            ("quark ", "quảk "),
+            ("saks ", "sák "),
+            ("myers ", "myé "),
+        ],
+    );
+}
+
+#[test]
+fn level_balanced_matches_english_auto_restore() {
+    let cases = [
+        ("text ", "text "),
+        ("window ", "window "),
+        ("hawk ", "hawk "),
+        ("saks ", "saks "),
+        ("myers ", "myers "),
+        // Valid Vietnamese wins
+        ("bits ", "bít "),
+        ("yes ", "yé "),
+        ("bus ", "bú "),
+        ("mix ", "mĩ "),
+        ("poor ", "pổ "),
+    ];
+    telex_restore_level(2, &cases);
+    telex_auto_restore(&cases);
+}
+
+#[test]
+fn level_aggressive_prefers_english_words() {
+    telex_restore_level(
+        3,
+        &[
+            ("text ", "text "),
+            ("hawk ", "hawk "),
+            // Dictionary words over valid Vietnamese
+            ("yes ", "yes "),
+            ("bus ", "bus "),
+            ("us ", "us "),
+            ("mix ", "mix "),
+            ("hers ", "hers "),
+            ("poor ", "poor "),
+        ],
+    );
+}
+
+#[test]
+fn level_aggressive_keeps_vietnamese() {
+    telex_restore_level(
+        3,
+        &[
+            // Keys look more Vietnamese than English
+            ("cos ", "có "),
+            ("mas ", "má "),
+            ("bits ", "bít "),
+            ("vieetj ", "việt "),
+            ("dduwowngf ", "đường "),
+            // COMMON_* exception tables
+            ("as ", "á "),
+            ("of ", "ò "),
+            ("bee ", "bê "),
+            ("ofo ", "ồ "),
+        ],
+    );
+}
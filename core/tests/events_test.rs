@@ -0,0 +1,109 @@
+//! Engine event tests
+//! Keys queue typed events (transform, auto-restore, shortcut, commit...)
+//! alongside the screen diff. '<' = DELETE.
+
+use gonhanh_core::engine::events::{Event, TransformKind};
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+fn drain(e: &mut Engine) -> Vec<Event> {
+    std::iter::from_fn(|| e.poll_event()).collect()
+}
+
+fn applied(kind: TransformKind) -> Event {
+    Event::TransformApplied { kind }
+}
+
+fn committed(text: &str) -> Event {
+    Event::WordCommitted {
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn transforms() {
+    let mut e = Engine::new();
+    type_word(&mut e, "dduwowcj");
+    assert_eq!(
+        drain(&mut e),
+        [
+            applied(TransformKind::Stroke),
+            applied(TransformKind::Tone),
+            applied(TransformKind::Tone),
+            applied(TransformKind::Mark),
+        ]
+    );
+
+    type_word(&mut e, " ass");
+    assert_eq!(
+        drain(&mut e),
+        [
+            committed("được"),
+            applied(TransformKind::Mark),
+            Event::TransformReverted,
+        ]
+    );
+}
+
+#[test]
+fn undo_reverts() {
+    let mut e = Engine::new();
+    type_word(&mut e, "as");
+    drain(&mut e);
+    e.undo();
+    assert_eq!(drain(&mut e), [Event::TransformReverted]);
+}
+
+#[test]
+fn auto_restored() {
+    let mut e = Engine::new();
+    e.set_english_auto_restore(true);
+    assert_eq!(type_word(&mut e, "text "), "text ");
+    let events = drain(&mut e);
+    assert_eq!(
+        events[events.len() - 2..],
+        [
+            Event::AutoRestored {
+                raw: "text".to_string(),
+                vietnamese: "tẽt".to_string(),
+            },
+            committed("text"),
+        ]
+    );
+}
+
+#[test]
+fn shortcut_expanded() {
+    let mut e = Engine::new();
+    e.shortcuts_mut().add(Shortcut::new("vn", "Việt Nam"));
+    assert_eq!(type_word(&mut e, "vn "), "Việt Nam ");
+    assert_eq!(
+        drain(&mut e),
+        [Event::ShortcutExpanded {
+            trigger: "vn".to_string()
+        }]
+    );
+}
+
+#[test]
+fn auto_capitalized() {
+    let mut e = Engine::new();
+    e.set_auto_capitalize(true);
+    type_word(&mut e, "ok. b");
+    assert_eq!(drain(&mut e), [committed("ok"), Event::AutoCapitalized]);
+}
+
+#[test]
+fn history_restored() {
+    let mut e = Engine::new();
+    type_word(&mut e, "ban <");
+    assert_eq!(drain(&mut e), [committed("ban"), Event::HistoryRestored]);
+}
+
+#[test]
+fn queue_is_empty_without_events() {
+    let mut e = Engine::new();
+    type_word(&mut e, "ban");
+    assert_eq!(e.poll_event(), None);
+}
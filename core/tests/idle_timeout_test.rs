@@ -0,0 +1,103 @@
+//! Idle timeout tests
+//! After the idle timeout, the next timestamped key starts a fresh word,
+//! unless it is a mark/tone for a word the host confirmed.
+
+use gonhanh_core::data::keys;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::char_to_key;
+
+/// Type `input` with one key at `time_ms`
+fn type_at(e: &mut Engine, input: &str, time_ms: u64) {
+    for c in input.chars() {
+        let key = match c {
+            '<' => keys::DELETE,
+            _ => char_to_key(c),
+        };
+        e.on_key_timed(key, c.is_uppercase(), false, false, false, time_ms);
+    }
+}
+
+fn engine(timeout: u32) -> Engine {
+    let mut e = Engine::new();
+    e.set_idle_timeout(timeout);
+    e
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut e = Engine::new();
+    type_at(&mut e, "vie", 0);
+    type_at(&mut e, "e", 1_000_000);
+    assert_eq!(e.get_buffer_string(), "viê");
+}
+
+#[test]
+fn within_timeout_continues() {
+    let mut e = engine(5000);
+    type_at(&mut e, "vie", 1000);
+    type_at(&mut e, "e", 5999);
+    assert_eq!(e.get_buffer_string(), "viê");
+}
+
+#[test]
+fn after_timeout_starts_fresh() {
+    let mut e = engine(5000);
+    type_at(&mut e, "vie", 1000);
+    type_at(&mut e, "e", 6000);
+    assert_eq!(e.get_buffer_string(), "e");
+}
+
+#[test]
+fn mark_after_timeout_without_confirmation() {
+    let mut e = engine(5000);
+    type_at(&mut e, "ban", 1000);
+    type_at(&mut e, "s", 9000);
+    assert_eq!(e.get_buffer_string(), "s");
+}
+
+#[test]
+fn mark_after_timeout_with_confirmed_word() {
+    let mut e = engine(5000);
+    type_at(&mut e, "ban", 1000);
+    assert!(e.set_surrounding_text("ban", 3).is_some());
+    type_at(&mut e, "s", 9000);
+    assert_eq!(e.get_buffer_string(), "bán");
+}
+
+#[test]
+fn letter_after_timeout_ignores_confirmation() {
+    let mut e = engine(5000);
+    type_at(&mut e, "ban", 1000);
+    e.set_surrounding_text("ban", 3);
+    type_at(&mut e, "h", 9000);
+    assert_eq!(e.get_buffer_string(), "h");
+}
+
+#[test]
+fn timeout_clears_word_history() {
+    let mut e = engine(5000);
+    type_at(&mut e, "ban ", 1000);
+    type_at(&mut e, "<", 9000);
+    type_at(&mut e, "s", 9001);
+    assert_eq!(e.get_buffer_string(), "s", "no restore of 'ban'");
+}
+
+#[test]
+fn composed_timed() {
+    let mut e = engine(5000);
+    e.set_output_mode(1);
+    for key in [keys::V, keys::I, keys::E] {
+        e.on_key_composed_timed(key, false, false, false, false, 1000);
+    }
+    let c = e.on_key_composed_timed(keys::E, false, false, false, false, 2000);
+    assert_eq!(c.preedit_string(), "viê");
+
+    // The word left in the preedit is committed, the key starts a new one
+    let c = e.on_key_composed_timed(keys::E, false, false, false, false, 9000);
+    assert_eq!(c.commit_string(), "viê");
+    assert_eq!(c.preedit_string(), "e");
+
+    // Held keys type literally
+    let c = e.on_key_composed_timed(keys::E, false, false, false, true, 9001);
+    assert_eq!(c.preedit_string(), "ee");
+}
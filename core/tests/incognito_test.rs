@@ -0,0 +1,62 @@
+//! Incognito mode tests
+//! Password fields and privacy: typing works, but nothing is kept past the
+//! current word (history, last word, events). '<' = DELETE.
+
+use gonhanh_core::engine::{Action, Engine};
+use gonhanh_core::utils::type_word;
+
+fn incognito() -> Engine {
+    let mut e = Engine::new();
+    e.set_incognito(true);
+    e
+}
+
+#[test]
+fn typing_still_transforms() {
+    let mut e = incognito();
+    assert_eq!(type_word(&mut e, "vieejt nam"), "việt nam");
+}
+
+#[test]
+fn no_backspace_after_space_restore() {
+    // Normally the word comes back for editing: "việt" + s → "viết"
+    let mut e = Engine::new();
+    assert_eq!(type_word(&mut e, "vieejt <s"), "viết");
+
+    let mut e = incognito();
+    assert_eq!(type_word(&mut e, "vieejt <s"), "việts");
+}
+
+#[test]
+fn no_last_word_toggle() {
+    let mut e = incognito();
+    type_word(&mut e, "tex ");
+    assert_eq!(e.toggle_last_word().action, Action::None as u8);
+    assert_eq!(e.cycle_case().action, Action::None as u8);
+}
+
+#[test]
+fn no_events() {
+    let mut e = incognito();
+    type_word(&mut e, "as ban ");
+    assert_eq!(e.poll_event(), None);
+}
+
+#[test]
+fn enabling_drops_earlier_words() {
+    let mut e = Engine::new();
+    type_word(&mut e, "vieejt ");
+    assert!(e.peek_event().is_some());
+    e.set_incognito(true);
+    assert_eq!(e.poll_event(), None);
+    assert_eq!(e.toggle_last_word().action, Action::None as u8);
+    assert_eq!(type_word(&mut e, "<s"), "s");
+}
+
+#[test]
+fn disabling_restores_history() {
+    let mut e = incognito();
+    e.set_incognito(false);
+    assert!(!e.incognito());
+    assert_eq!(type_word(&mut e, "vieejt <s"), "viết");
+}
@@ -0,0 +1,85 @@
+//! Key auto-repeat tests
+//! Holding a key types it literally instead of cycling transforms.
+//! In inputs, '+' marks the previous key as held (one auto-repeat each).
+
+use gonhanh_core::data::keys;
+use gonhanh_core::engine::{Action, Engine};
+use gonhanh_core::utils::char_to_key;
+
+/// Type input where each '+' repeats the previous key as an auto-repeat
+fn type_held(e: &mut Engine, input: &str) -> String {
+    let mut screen = String::new();
+    let mut last = ' ';
+    for c in input.chars() {
+        let (c, repeat) = if c == '+' { (last, true) } else { (c, false) };
+        last = c;
+        let key = match c {
+            '\x1b' => keys::ESC,
+            _ => char_to_key(c),
+        };
+        let r = e.on_key_event(key, c.is_uppercase(), false, false, repeat);
+        if r.action == Action::Send as u8 {
+            for _ in 0..r.backspace {
+                screen.pop();
+            }
+            screen.extend(
+                r.chars[..r.count as usize]
+                    .iter()
+                    .filter_map(|&c| char::from_u32(c)),
+            );
+            if keys::is_letter(key) || keys::is_number(key) || r.key_consumed() {
+                continue;
+            }
+        }
+        match key {
+            keys::DELETE => {
+                screen.pop();
+            }
+            keys::ESC => {}
+            _ => screen.push(c),
+        }
+    }
+    screen
+}
+
+#[test]
+fn held_letter_is_literal() {
+    let mut e = Engine::new();
+    assert_eq!(type_held(&mut e, "a++++"), "aaaaa");
+    let mut e = Engine::new();
+    assert_eq!(type_held(&mut e, "d++++"), "ddddd");
+}
+
+#[test]
+fn first_press_still_transforms() {
+    let mut e = Engine::new();
+    assert_eq!(type_held(&mut e, "viee++"), "viêee");
+    let mut e = Engine::new();
+    assert_eq!(type_held(&mut e, "as++"), "áss");
+}
+
+#[test]
+fn vni_held_digit() {
+    let mut e = Engine::new();
+    e.set_method(1);
+    assert_eq!(type_held(&mut e, "a6++"), "â66");
+}
+
+#[test]
+fn without_repeat_flag_unchanged() {
+    let mut e = Engine::new();
+    assert_eq!(type_held(&mut e, "aaa"), "aa");
+}
+
+#[test]
+fn esc_restores_held_keys() {
+    let mut e = Engine::new();
+    e.set_esc_restore(true);
+    assert_eq!(type_held(&mut e, "viee++\x1b"), "vieeee");
+}
+
+#[test]
+fn held_delete_deletes() {
+    let mut e = Engine::new();
+    assert_eq!(type_held(&mut e, "abc<+"), "a");
+}
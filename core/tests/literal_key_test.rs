@@ -0,0 +1,107 @@
+//! Literal-next key tests
+//! The literal key ('\\' here) is swallowed and the next key is typed as-is.
+//! '<' = DELETE, '\x1b' = ESC.
+
+use gonhanh_core::data::keys;
+use gonhanh_core::engine::settings::EngineSettings;
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+fn telex() -> Engine {
+    let mut e = Engine::new();
+    e.set_literal_key(Some(keys::BACKSLASH));
+    e
+}
+
+fn vni() -> Engine {
+    let mut e = telex();
+    e.set_method(1);
+    e
+}
+
+#[test]
+fn next_key_is_literal() {
+    let cases = [
+        ("s\\sh", "ssh"),
+        ("c\\ss", "css"),
+        ("te\\st", "test"),
+        ("d\\d", "dd"),
+        ("a\\a", "aa"),
+        ("\\w", "w"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            type_word(&mut telex(), input),
+            expected,
+            "input: {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn vni_digit() {
+    assert_eq!(type_word(&mut vni(), "VNI\\2"), "VNI2");
+    assert_eq!(type_word(&mut vni(), "vi\\2"), "vi2");
+    assert_eq!(type_word(&mut vni(), "vi2"), "vì");
+}
+
+#[test]
+fn only_one_key() {
+    assert_eq!(
+        type_word(&mut telex(), "vi\\eej"),
+        "việ",
+        "later keys transform"
+    );
+}
+
+#[test]
+fn double_press_types_key() {
+    assert_eq!(type_word(&mut telex(), "\\\\"), "\\");
+    assert_eq!(type_word(&mut telex(), "a\\\\s"), "a\\s");
+}
+
+#[test]
+fn esc_and_backspace_after_space() {
+    let mut e = telex();
+    e.set_esc_restore(true);
+    assert_eq!(type_word(&mut e, "vieet\\s\x1b"), "vieets");
+
+    let mut e = telex();
+    type_word(&mut e, "c\\ss <");
+    assert_eq!(e.get_buffer_string(), "css");
+}
+
+#[test]
+fn shortcuts_still_match() {
+    let mut e = telex();
+    e.shortcuts_mut().add(Shortcut::new("ssh", "Secure Shell"));
+    assert_eq!(type_word(&mut e, "s\\sh "), "Secure Shell ");
+
+    let mut e = telex();
+    e.shortcuts_mut().add(Shortcut::new("\\sig", "Regards"));
+    assert_eq!(type_word(&mut e, "\\sig "), "Regards ");
+}
+
+#[test]
+fn disabled_by_default() {
+    assert_eq!(type_word(&mut Engine::new(), "c\\ss"), "c\\ss");
+}
+
+#[test]
+fn literal_key_in_settings() {
+    let settings = telex().settings();
+    let json = settings.to_json();
+    assert!(json.contains("\"literal_key\":42"), "{json}");
+
+    let mut e = Engine::new();
+    e.apply_settings(&EngineSettings::from_json(&json).unwrap());
+    assert_eq!(e.literal_key(), Some(keys::BACKSLASH));
+
+    // Re-applying the same settings keeps a pending literal key
+    type_word(&mut e, "a\\");
+    e.apply_settings(&settings);
+    type_word(&mut e, "s");
+    assert_eq!(e.get_buffer_string(), "as");
+}
@@ -0,0 +1,56 @@
+//! Loanword syllable tests
+//! Consonant clusters (bl, str, xtr) and finals (-l, -s) take diacritics.
+
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+fn telex() -> Engine {
+    let mut e = Engine::new();
+    e.set_loanword_syllables(true);
+    e
+}
+
+#[test]
+fn clusters_take_diacritics() {
+    let cases = [
+        ("bloocs", "blốc"),
+        ("Xtrets", "Xtrét"),
+        ("strets", "strét"),
+        ("clof", "clò"),
+        ("Tawcs-xi", "Tắc-xi"),
+        ("Gowf-ram", "Gờ-ram"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            type_word(&mut telex(), input),
+            expected,
+            "input: {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn foreign_finals() {
+    assert_eq!(type_word(&mut telex(), "bulf"), "bùl");
+    let mut e = telex();
+    e.set_method(1);
+    assert_eq!(type_word(&mut e, "bus1 bul2"), "bús bùl");
+}
+
+#[test]
+fn english_still_restores() {
+    let mut e = telex();
+    e.set_english_auto_restore(true);
+    assert_eq!(type_word(&mut e, "street stops "), "street stops ");
+
+    let mut e = telex();
+    e.set_english_auto_restore(true);
+    assert_eq!(type_word(&mut e, "bloocs "), "blốc ", "not English: kept");
+}
+
+#[test]
+fn disabled_by_default() {
+    assert_eq!(type_word(&mut Engine::new(), "bloocs"), "bloocs");
+    assert_eq!(type_word(&mut Engine::new(), "strets"), "strets");
+}
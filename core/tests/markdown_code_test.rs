@@ -0,0 +1,106 @@
+//! Markdown code span tests
+//! No Vietnamese transforms inside `inline code` and ``` fenced blocks.
+
+use gonhanh_core::data::keys;
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+fn markdown() -> Engine {
+    let mut e = Engine::new();
+    e.set_markdown_code(true);
+    e
+}
+
+/// Type lines separated by RETURN, joined with '\n'
+fn type_lines(e: &mut Engine, lines: &[&str]) -> String {
+    let mut out = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            e.on_key(keys::RETURN, false, false);
+        }
+        out.push(type_word(e, line));
+    }
+    out.join("\n")
+}
+
+#[test]
+fn inline_code_span() {
+    let cases = [
+        ("chayj `git diff` nhes", "chạy `git diff` nhé"),
+        ("`tesst` roofi", "`tesst` rồi"),
+        ("dufng `as` vaf `of`", "dùng `as` và `of`"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(type_word(&mut markdown(), input), expected, "{:?}", input);
+    }
+}
+
+#[test]
+fn double_backtick_span() {
+    assert_eq!(
+        type_word(&mut markdown(), "``a`s`` nhes"),
+        "``a`s`` nhé",
+        "backtick inside a ``...`` span"
+    );
+}
+
+#[test]
+fn fenced_block() {
+    let mut e = markdown();
+    let screen = type_lines(
+        &mut e,
+        &["Vis duj:", "```", "git diff --stat", "```", "Xong roofi"],
+    );
+    assert_eq!(screen, "Ví dụ:\n```\ngit diff --stat\n```\nXong rồi");
+}
+
+#[test]
+fn backticks_inside_fence() {
+    let mut e = markdown();
+    let screen = type_lines(&mut e, &["```", "echo `as`", "```", "as"]);
+    assert_eq!(screen, "```\necho `as`\n```\ná");
+}
+
+#[test]
+fn stray_backtick_ends_at_line() {
+    let mut e = markdown();
+    let screen = type_lines(&mut e, &["`as", "as"]);
+    assert_eq!(screen, "`as\ná");
+}
+
+#[test]
+fn shortcuts_work_in_code() {
+    let mut e = markdown();
+    e.shortcuts_mut().add(Shortcut::new("vn", "Việt Nam"));
+    assert_eq!(type_word(&mut e, "`vn `"), "`Việt Nam `");
+}
+
+#[test]
+fn reset_on_clear_all() {
+    let mut e = markdown();
+    type_word(&mut e, "`git");
+    assert!(e.in_code_span());
+    e.clear_all();
+    assert!(!e.in_code_span());
+    assert_eq!(type_word(&mut e, "as"), "á");
+
+    let mut e = markdown();
+    type_word(&mut e, "```a");
+    e.set_app("com.apple.Notes", "");
+    assert_eq!(type_word(&mut e, "as"), "á");
+}
+
+#[test]
+fn disabled_by_default() {
+    assert_eq!(type_word(&mut Engine::new(), "`as`"), "`á`");
+}
+
+#[test]
+fn applying_settings_keeps_code_span() {
+    let mut e = markdown();
+    type_word(&mut e, "`");
+    let settings = e.settings();
+    e.apply_settings(&settings);
+    assert_eq!(type_word(&mut e, "tesst` "), "tesst` ");
+}
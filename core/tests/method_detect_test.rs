@@ -0,0 +1,89 @@
+//! Telex/VNI auto-detection tests
+//! With auto-detect on, the first words typed pick the method; the switch
+//! happens between words, once a method leads by two words.
+
+use gonhanh_core::engine::settings::EngineSettings;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+fn detecting(method: u8) -> Engine {
+    let mut e = Engine::new();
+    e.set_method(method);
+    e.set_method_auto_detect(true);
+    e
+}
+
+#[test]
+fn switches_to_vni() {
+    let mut e = detecting(0);
+    type_word(&mut e, "vie65t nam8 ");
+    assert_eq!(e.detected_method(), Some(1));
+    assert_eq!(type_word(&mut e, "d9i"), "đi");
+    assert_eq!(e.settings().method, 1);
+}
+
+#[test]
+fn switches_to_telex() {
+    let mut e = detecting(1);
+    type_word(&mut e, "vieejt namw ");
+    assert_eq!(e.detected_method(), Some(0));
+    assert_eq!(type_word(&mut e, "ddi"), "đi");
+}
+
+#[test]
+fn switches_after_punctuation() {
+    let mut e = detecting(0);
+    // Decided at the comma, applied from the next word
+    type_word(&mut e, "vie65t nam8,d9");
+    assert_eq!(e.get_buffer_string(), "đ");
+}
+
+#[test]
+fn one_word_is_not_enough() {
+    let mut e = detecting(0);
+    type_word(&mut e, "vie65t ");
+    assert_eq!(e.detected_method(), None);
+    assert_eq!(type_word(&mut e, "ddi"), "đi", "still Telex");
+}
+
+#[test]
+fn stray_word_does_not_flip_back() {
+    let mut e = detecting(0);
+    type_word(&mut e, "vie65t nam8 vieejt ");
+    assert_eq!(e.detected_method(), Some(1));
+    assert_eq!(type_word(&mut e, "a1"), "á");
+}
+
+#[test]
+fn english_words_do_not_vote() {
+    let mut e = detecting(1);
+    type_word(&mut e, "bus test fix ");
+    assert_eq!(e.detected_method(), None);
+    assert_eq!(e.settings().method, 1);
+}
+
+#[test]
+fn stops_after_first_words() {
+    let mut e = detecting(0);
+    type_word(&mut e, &"nam ".repeat(20));
+    type_word(&mut e, "vie65t nam8 ");
+    assert_eq!(e.detected_method(), None);
+    assert_eq!(e.settings().method, 0);
+}
+
+#[test]
+fn off_by_default() {
+    let mut e = Engine::new();
+    type_word(&mut e, "vie65t nam8 ");
+    assert_eq!(e.detected_method(), None);
+    assert_eq!(e.settings().method, 0);
+}
+
+#[test]
+fn settings_flag() {
+    let s = EngineSettings::from_json(r#"{"version":1,"method_auto_detect":true}"#).unwrap();
+    assert!(s.method_auto_detect);
+    let mut e = Engine::new();
+    e.apply_settings(&s);
+    assert!(e.method_auto_detect());
+}
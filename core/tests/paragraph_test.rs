@@ -1,5 +1,6 @@
 mod common;
-use common::{telex_auto_restore, vni};
+use common::{telex_auto_restore, type_word, vni};
+use gonhanh_core::engine::Engine;
 
 #[test]
 fn paragraph_telex() {
@@ -57,3 +58,16 @@ fn paragraph_smart_auto_restore() {
 
     telex_auto_restore(&[(input, expected)]);
 }
+
+#[test]
+fn paragraph_raw_mode() {
+    // Raw mode detection: URLs, emails, paths and code identifiers stay as typed,
+    // Vietnamese around them is still transformed
+    let input = "Clone repo tuwf https://github.com/huydepzai121/fkey veef ~/src/fkey, suwar file core/src/engine/mod.rs roofi build. Bieens user_id vaf getUserId dufng trong std::io nhes. Gawpj looix thif email nhatkha1407@gmail.com hoawcj xem www.gonhanh.org nha.";
+    let expected = "Clone repo từ https://github.com/huydepzai121/fkey về ~/src/fkey, sửa file core/src/engine/mod.rs rồi build. Biến user_id và getUserId dùng trong std::io nhé. Gặp lỗi thì email nhatkha1407@gmail.com hoặc xem www.gonhanh.org nha.";
+
+    let mut e = Engine::new();
+    e.set_english_auto_restore(true);
+    e.set_raw_mode_detection(true);
+    assert_eq!(type_word(&mut e, input), expected);
+}
@@ -0,0 +1,105 @@
+//! Settings profile tests
+//! Verifies that switching profiles applies settings + shortcut subset at once
+//! and does not lose the word being composed.
+
+use gonhanh_core::engine::profile::Profile;
+use gonhanh_core::engine::settings::EngineSettings;
+use gonhanh_core::engine::shortcut::Shortcut;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+fn engine_with_profiles() -> Engine {
+    let mut e = Engine::new();
+    e.shortcuts_mut().add(Shortcut::new("vn", "Việt Nam"));
+    e.shortcuts_mut().add(Shortcut::new("fn", "function"));
+    e.add_profile(
+        Profile::new(
+            "Chat",
+            EngineSettings {
+                auto_capitalize: true,
+                ..Default::default()
+            },
+        )
+        .with_shortcuts(&["vn"]),
+    );
+    e.add_profile(
+        Profile::new(
+            "Code",
+            EngineSettings {
+                auto_restore_level: 2,
+                ..Default::default()
+            },
+        )
+        .with_shortcuts(&["fn"]),
+    );
+    e
+}
+
+#[test]
+fn activate_applies_settings() {
+    let mut e = engine_with_profiles();
+    assert!(e.activate_profile("code"));
+    assert_eq!(e.active_profile(), Some("Code"));
+    assert!(e.english_auto_restore());
+    assert!(!e.auto_capitalize());
+    assert_eq!(type_word(&mut e, "text "), "text ");
+
+    assert!(e.activate_profile("Chat"));
+    assert!(!e.english_auto_restore());
+    assert!(e.auto_capitalize());
+    assert_eq!(type_word(&mut e, "text "), "tẽt ");
+}
+
+#[test]
+fn activate_unknown_profile_keeps_settings() {
+    let mut e = engine_with_profiles();
+    e.set_modern_tone(false);
+    assert!(!e.activate_profile("Formal"));
+    assert_eq!(e.active_profile(), None);
+    assert!(!e.modern_tone());
+}
+
+#[test]
+fn shortcut_subset_follows_profile() {
+    let mut e = engine_with_profiles();
+
+    e.activate_profile("Chat");
+    assert_eq!(type_word(&mut e, "vn "), "Việt Nam ");
+    assert_eq!(type_word(&mut e, "fn "), "fn ");
+
+    e.activate_profile("Code");
+    assert_eq!(type_word(&mut e, "vn "), "vn ");
+    assert_eq!(type_word(&mut e, "fn "), "function ");
+
+    // Removing the active profile re-enables every shortcut
+    assert!(e.remove_profile("Code"));
+    assert_eq!(e.active_profile(), None);
+    assert_eq!(type_word(&mut e, "vn "), "Việt Nam ");
+}
+
+#[test]
+fn switch_mid_word_keeps_buffer() {
+    let mut e = engine_with_profiles();
+    e.activate_profile("Chat");
+    assert_eq!(type_word(&mut e, "tieeng"), "tiêng");
+
+    e.activate_profile("Code");
+    assert_eq!(e.debug_buffer_string(), "tiêng");
+    type_word(&mut e, "s");
+    assert_eq!(e.debug_buffer_string(), "tiếng");
+}
+
+#[test]
+fn add_profile_replaces_same_name() {
+    let mut e = engine_with_profiles();
+    e.add_profile(Profile::new(
+        "CODE",
+        EngineSettings {
+            method: 1,
+            ..Default::default()
+        },
+    ));
+    assert_eq!(e.profiles().len(), 2);
+    e.activate_profile("code");
+    assert_eq!(e.method(), 1);
+}
@@ -0,0 +1,90 @@
+//! Raw mode detection tests
+//! URLs, emails, paths and code identifiers pass through untransformed
+//! until whitespace; the part typed before the trigger is restored.
+
+mod common;
+use common::{run_with, type_word};
+use gonhanh_core::engine::Engine;
+
+fn raw_telex(cases: &[(&str, &str)]) {
+    run_with("Raw", |e| e.set_raw_mode_detection(true), cases);
+}
+
+#[test]
+fn urls() {
+    raw_telex(&[
+        ("https://github.com/owner", "https://github.com/owner"),
+        ("http://vieetj.vn", "http://vieetj.vn"),
+        ("www.toois.com", "www.toois.com"),
+        ("vaof www.gooogle.com nhes", "vào www.gooogle.com nhé"),
+    ]);
+}
+
+#[test]
+fn emails() {
+    raw_telex(&[
+        ("nhatkha1407@gmail.com", "nhatkha1407@gmail.com"),
+        ("hoangf@vieetj.vn", "hoangf@vieetj.vn"),
+        ("gui tooi@caas.com nhes", "gui tooi@caas.com nhé"),
+    ]);
+}
+
+#[test]
+fn paths() {
+    raw_telex(&[
+        ("~/src/fkey", "~/src/fkey"),
+        ("./build.sh", "./build.sh"),
+        ("/usr/local/bin", "/usr/local/bin"),
+        ("src/engine/mod.rs", "src/engine/mod.rs"),
+    ]);
+}
+
+#[test]
+fn code_identifiers() {
+    raw_telex(&[
+        ("user_id", "user_id"),
+        ("_private", "_private"),
+        ("getUserId", "getUserId"),
+        ("macOS", "macOS"),
+        ("std::io::Result", "std::io::Result"),
+    ]);
+}
+
+#[test]
+fn ends_at_whitespace() {
+    raw_telex(&[
+        ("user_id tieengs", "user_id tiếng"),
+        ("~/src dduwowcj", "~/src được"),
+    ]);
+}
+
+#[test]
+fn vietnamese_text_unaffected() {
+    raw_telex(&[
+        ("vaf/hoawcj", "và/hoặc"),
+        ("Vieetj Nam.", "Việt Nam."),
+        ("vis duj: xin chaof", "ví dụ: xin chào"),
+        ("#vieetj", "#việt"),
+    ]);
+}
+
+#[test]
+fn backspace_leaves_raw_mode() {
+    raw_telex(&[("user_<<<<<as", "á"), ("user_< tas", "user tá")]);
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut e = Engine::new();
+    assert_eq!(type_word(&mut e, "user_id"), "uẻ_id");
+}
+
+#[test]
+fn applying_settings_keeps_raw_mode() {
+    let mut e = Engine::new();
+    e.set_raw_mode_detection(true);
+    type_word(&mut e, "http://");
+    let settings = e.settings();
+    e.apply_settings(&settings);
+    assert_eq!(type_word(&mut e, "ddaay"), "ddaay");
+}
@@ -0,0 +1,62 @@
+//! Syllable segmentation tests
+//! Words typed without spaces are split into syllables, so marks and tones
+//! apply to the syllable being typed. '<' = DELETE.
+
+mod common;
+use common::{run_with, type_word};
+use gonhanh_core::engine::Engine;
+
+fn segmented(cases: &[(&str, &str)]) {
+    run_with("Segment", |e| e.set_syllable_segmentation(true), cases);
+}
+
+#[test]
+fn compound_words() {
+    segmented(&[
+        ("vieetjnam", "việtnam"),
+        ("#vieetjnamtuwoiddepj", "#việtnamtươiđẹp"),
+        ("hoaxinhddepj", "hoãinhđẹp"),
+        ("tieengsvieetj", "tiếngviệt"),
+        ("nguyeenxvawnbinhf", "nguyễnvănbình"),
+    ]);
+}
+
+#[test]
+fn marks_apply_to_latest_syllable() {
+    segmented(&[
+        ("vieetjnams", "việtnám"),
+        ("anhemf", "anhèm"),
+        ("banjbeef", "bạnbề"),
+        ("toilaf", "toilà"),
+    ]);
+}
+
+#[test]
+fn single_syllable_unchanged() {
+    segmented(&[
+        ("vieetj", "việt"),
+        ("nguwowif", "người"),
+        ("truwowngf", "trường"),
+        ("nghieeng", "nghiêng"),
+    ]);
+}
+
+#[test]
+fn invalid_first_part_not_split() {
+    segmented(&[
+        ("string", "string"),
+        ("github", "github"),
+        ("python", "python"),
+    ]);
+}
+
+#[test]
+fn backspace_returns_to_previous_syllable() {
+    segmented(&[("vieetnam<<<j", "việt"), ("vieetn<s", "viết")]);
+}
+
+#[test]
+fn disabled_by_default() {
+    let mut e = Engine::new();
+    assert_eq!(type_word(&mut e, "banjbeef"), "bạnbeef");
+}
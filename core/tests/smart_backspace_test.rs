@@ -0,0 +1,87 @@
+//! Smart backspace tests
+//! Backspace removes the mark, then the tone, then the letter.
+//! '<' = DELETE, '\x1b' = ESC.
+
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+fn telex() -> Engine {
+    let mut e = Engine::new();
+    e.set_smart_backspace(true);
+    e
+}
+
+fn vni() -> Engine {
+    let mut e = telex();
+    e.set_method(1);
+    e
+}
+
+#[test]
+fn mark_then_tone_then_letter() {
+    let cases = [
+        ("tieengs<", "tiêng"),
+        ("tieengs<<", "tieng"),
+        ("tieengs<<<", "tien"),
+        ("dduwowngf<", "đương"),
+        ("dduwowngf<<", "đuong"),
+        ("dduwowngf<<<", "duong"),
+        ("dduwowngf<<<<", "duon"),
+        ("w<", "u"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            type_word(&mut telex(), input),
+            expected,
+            "input: {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn vni_order() {
+    assert_eq!(type_word(&mut vni(), "tie6ng1<"), "tiêng");
+    assert_eq!(type_word(&mut vni(), "tie6ng1<<"), "tieng");
+    assert_eq!(type_word(&mut vni(), "d9i<"), "di");
+}
+
+#[test]
+fn retype_after_removal() {
+    assert_eq!(type_word(&mut telex(), "tieengs<f"), "tiềng");
+    assert_eq!(type_word(&mut telex(), "tieengs<<e"), "tiêng");
+}
+
+#[test]
+fn raw_input_follows_removal() {
+    let mut e = telex();
+    e.set_esc_restore(true);
+    assert_eq!(type_word(&mut e, "tieengs<\x1b"), "tieeng");
+
+    let mut e = telex();
+    e.set_esc_restore(true);
+    assert_eq!(type_word(&mut e, "tieengs<<\x1b"), "tieng");
+}
+
+#[test]
+fn horn_pair_raw_input() {
+    // "uwow" types a horn per vowel: both keystrokes go with the pair
+    for (input, shown, raw) in [
+        ("dduwowngf<<", "đuong", "dduong"),
+        ("nguwowif<<", "nguoi", "nguoi"),
+        ("nguowif<<", "nguoi", "nguoi"),
+    ] {
+        assert_eq!(type_word(&mut telex(), input), shown, "{input}");
+        let mut e = telex();
+        e.set_esc_restore(true);
+        assert_eq!(type_word(&mut e, &format!("{input}\x1b")), raw, "{input}");
+    }
+    let mut e = vni();
+    e.set_esc_restore(true);
+    assert_eq!(type_word(&mut e, "ngu7o7i2<<\x1b"), "nguoi");
+}
+
+#[test]
+fn disabled_by_default() {
+    assert_eq!(type_word(&mut Engine::new(), "tieengs<"), "tiến");
+}
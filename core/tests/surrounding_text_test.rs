@@ -0,0 +1,79 @@
+//! Surrounding-text tests
+//! The engine loads the syllable under the caret and edits it in place.
+//! Results are caret-relative: (backspace, chars, delete_after, caret_back).
+
+use gonhanh_core::data::keys;
+use gonhanh_core::engine::{Action, Engine};
+
+fn sent(e: &mut Engine, key: u16) -> (u8, String, u8, u8) {
+    let r = e.on_key(key, false, false);
+    assert_eq!(r.action, Action::Send as u8);
+    let chars = r.chars[..r.count as usize]
+        .iter()
+        .filter_map(|&c| char::from_u32(c))
+        .collect();
+    (r.backspace, chars, r.delete_after, r.caret_back)
+}
+
+#[test]
+fn caret_at_word_end() {
+    let mut e = Engine::new();
+    assert_eq!(e.set_surrounding_text("toi di hoc", 6), Some((2, 0)));
+    assert_eq!(e.get_buffer_string(), "di");
+    // Caret at the end: usual diff
+    assert_eq!(sent(&mut e, keys::F), (1, "ì".to_string(), 0, 0));
+}
+
+#[test]
+fn caret_inside_word() {
+    let mut e = Engine::new();
+    assert_eq!(e.set_surrounding_text("toi di hoc", 8), Some((1, 2)));
+    assert_eq!(e.get_buffer_string(), "hoc");
+    // Mark lands after the caret: replace the tail and step back
+    assert_eq!(sent(&mut e, keys::J), (0, "ọc".to_string(), 2, 2));
+    assert_eq!(sent(&mut e, keys::S), (0, "óc".to_string(), 2, 2));
+    assert_eq!(e.get_buffer_string(), "hóc");
+}
+
+#[test]
+fn replaces_existing_mark() {
+    let mut e = Engine::new();
+    assert_eq!(e.set_surrounding_text("tiếng Việt", 5), Some((5, 0)));
+    assert_eq!(sent(&mut e, keys::F), (3, "ềng".to_string(), 0, 0));
+}
+
+#[test]
+fn caret_at_word_start() {
+    let mut e = Engine::new();
+    assert_eq!(e.set_surrounding_text("xin chao", 4), Some((0, 4)));
+    assert_eq!(sent(&mut e, keys::F), (0, "chào".to_string(), 4, 4));
+}
+
+#[test]
+fn letter_inserted_at_caret() {
+    let mut e = Engine::new();
+    e.set_surrounding_text("ngoi", 2);
+    let r = e.on_key(keys::U, false, false);
+    assert_eq!(
+        r.action,
+        Action::None as u8,
+        "App inserts the letter itself"
+    );
+    assert_eq!(e.get_buffer_string(), "nguoi");
+}
+
+#[test]
+fn no_word_at_caret() {
+    let mut e = Engine::new();
+    assert_eq!(e.set_surrounding_text("a, b", 2), None);
+    assert_eq!(e.set_surrounding_text("abc", 9), None);
+    assert_eq!(e.set_surrounding_text("", 0), None);
+    assert_eq!(e.get_buffer_string(), "");
+}
+
+#[test]
+fn punctuation_bounds_word() {
+    let mut e = Engine::new();
+    assert_eq!(e.set_surrounding_text("(viet).", 5), Some((4, 0)));
+    assert_eq!(e.get_buffer_string(), "viet");
+}
@@ -0,0 +1,72 @@
+//! Toggle last word tests
+//! The last committed word swaps between Vietnamese and raw keystrokes,
+//! including the trailing spaces typed after it.
+
+mod common;
+use common::{apply, type_word};
+use gonhanh_core::engine::{Action, Engine};
+
+#[test]
+fn toggle_to_raw_and_back() {
+    let mut e = Engine::new();
+    let mut screen = type_word(&mut e, "xin tex ");
+    assert_eq!(screen, "xin tẽ ");
+    apply(&mut screen, &e.toggle_last_word());
+    assert_eq!(screen, "xin tex ");
+    apply(&mut screen, &e.toggle_last_word());
+    assert_eq!(screen, "xin tẽ ");
+}
+
+#[test]
+fn toggle_auto_restored_word() {
+    let mut e = Engine::new();
+    e.set_english_auto_restore(true);
+    let mut screen = type_word(&mut e, "text ");
+    assert_eq!(screen, "text ");
+    apply(&mut screen, &e.toggle_last_word());
+    assert_eq!(screen, "tẽt ");
+}
+
+#[test]
+fn toggle_across_spaces() {
+    let mut e = Engine::new();
+    let mut screen = type_word(&mut e, "Vieetj   ");
+    apply(&mut screen, &e.toggle_last_word());
+    assert_eq!(screen, "Vieetj   ");
+}
+
+#[test]
+fn nothing_to_toggle() {
+    let mut e = Engine::new();
+    type_word(&mut e, "ban ");
+    assert_eq!(
+        e.toggle_last_word().action,
+        Action::None as u8,
+        "same forms"
+    );
+
+    let mut e = Engine::new();
+    type_word(&mut e, "tex ab");
+    assert_eq!(
+        e.toggle_last_word().action,
+        Action::None as u8,
+        "next word in progress"
+    );
+
+    let mut e = Engine::new();
+    type_word(&mut e, "tex, ");
+    assert_eq!(
+        e.toggle_last_word().action,
+        Action::None as u8,
+        "punctuation ends history"
+    );
+}
+
+#[test]
+fn backspace_restores_toggled_form() {
+    let mut e = Engine::new();
+    type_word(&mut e, "tex ");
+    e.toggle_last_word();
+    type_word(&mut e, "<");
+    assert_eq!(e.get_buffer_string(), "tex");
+}
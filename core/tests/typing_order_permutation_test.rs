@@ -315,14 +315,12 @@ fn generate_all_telex_variants(word: &str) -> Vec<String> {
                 let mut found_first_vowel = false;
 
                 for (i, c) in vowel_chars.iter().enumerate() {
-                    if is_vowel(*c) {
-                        if !found_first_vowel {
-                            found_first_vowel = true;
-                            first_vowel_end = i + 1;
-                            // Include any mark right after the first vowel
-                            if i + 1 < vowel_chars.len() && !is_vowel(vowel_chars[i + 1]) {
-                                first_vowel_end = i + 2;
-                            }
+                    if is_vowel(*c) && !found_first_vowel {
+                        found_first_vowel = true;
+                        first_vowel_end = i + 1;
+                        // Include any mark right after the first vowel
+                        if i + 1 < vowel_chars.len() && !is_vowel(vowel_chars[i + 1]) {
+                            first_vowel_end = i + 2;
                         }
                     }
                 }
@@ -359,10 +357,10 @@ fn generate_vowel_patterns(parts: &SyllableParts) -> Vec<String> {
     // Check for special patterns
     let has_horn_u = vowels
         .iter()
-        .any(|(v, m)| v.to_ascii_lowercase() == 'u' && *m == Some('w'));
+        .any(|(v, m)| v.eq_ignore_ascii_case(&'u') && *m == Some('w'));
     let has_horn_o = vowels
         .iter()
-        .any(|(v, m)| v.to_ascii_lowercase() == 'o' && *m == Some('w'));
+        .any(|(v, m)| v.eq_ignore_ascii_case(&'o') && *m == Some('w'));
     let has_uwo = has_horn_u && has_horn_o;
 
     // Generate base pattern: vowels with their marks immediately after
@@ -384,10 +382,10 @@ fn generate_vowel_patterns(parts: &SyllableParts) -> Vec<String> {
         let mut u_idx = None;
         let mut o_idx = None;
         for (i, (v, m)) in vowels.iter().enumerate() {
-            if v.to_ascii_lowercase() == 'u' && *m == Some('w') {
+            if v.eq_ignore_ascii_case(&'u') && *m == Some('w') {
                 u_idx = Some(i);
             }
-            if v.to_ascii_lowercase() == 'o' && *m == Some('w') {
+            if v.eq_ignore_ascii_case(&'o') && *m == Some('w') {
                 o_idx = Some(i);
             }
         }
@@ -398,7 +396,7 @@ fn generate_vowel_patterns(parts: &SyllableParts) -> Vec<String> {
             for (v, m) in vowels {
                 p.push(*v);
                 // Only add w after o, not after u
-                if v.to_ascii_lowercase() == 'o' && *m == Some('w') {
+                if v.eq_ignore_ascii_case(&'o') && *m == Some('w') {
                     p.push('w');
                 }
             }
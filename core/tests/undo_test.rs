@@ -0,0 +1,123 @@
+//! Per-word undo/redo tests
+//! Undo steps back one transform at a time, keeping the letters typed
+//! after it; redo re-applies the undone transforms.
+
+mod common;
+use common::{apply, type_word};
+use gonhanh_core::data::keys;
+use gonhanh_core::engine::settings::EngineSettings;
+use gonhanh_core::engine::{Action, Engine};
+
+/// Type `input`, then undo `n` times: screen after each undo
+fn undo_steps(e: &mut Engine, input: &str, n: usize) -> Vec<String> {
+    let mut screen = type_word(e, input);
+    (0..n)
+        .map(|_| {
+            apply(&mut screen, &e.undo());
+            screen.clone()
+        })
+        .collect()
+}
+
+#[test]
+fn undo_mark_then_horn() {
+    let mut e = Engine::new();
+    assert_eq!(
+        undo_steps(&mut e, "nguowif", 2),
+        ["ngươi", "nguoi"],
+        "người → ngươi → nguoi"
+    );
+    assert_eq!(e.get_buffer_string(), "nguoi");
+    assert_eq!(e.undo().action, Action::None as u8, "nothing left to undo");
+}
+
+#[test]
+fn undo_keeps_later_letters() {
+    let mut e = Engine::new();
+    assert_eq!(undo_steps(&mut e, "toois", 2), ["tôi", "toi"]);
+}
+
+#[test]
+fn undo_replaced_mark() {
+    let mut e = Engine::new();
+    assert_eq!(undo_steps(&mut e, "asf", 2), ["á", "a"]);
+}
+
+#[test]
+fn undo_stroke() {
+    let mut e = Engine::new();
+    assert_eq!(undo_steps(&mut e, "ddi", 1), ["di"]);
+}
+
+#[test]
+fn undo_w_as_vowel() {
+    let mut e = Engine::new();
+    assert_eq!(undo_steps(&mut e, "w", 1), ["w"]);
+    assert_eq!(e.get_buffer_string(), "w");
+}
+
+#[test]
+fn undo_bracket_vowel() {
+    let mut e = Engine::new();
+    e.set_bracket_shortcut(true);
+    assert_eq!(undo_steps(&mut e, "]", 1), ["]"]);
+    assert_eq!(e.get_buffer_string(), "", "bracket ends the word");
+}
+
+#[test]
+fn redo_reapplies() {
+    let mut e = Engine::new();
+    let mut screen = type_word(&mut e, "nguowif");
+    apply(&mut screen, &e.undo());
+    apply(&mut screen, &e.undo());
+    apply(&mut screen, &e.redo());
+    assert_eq!(screen, "ngươi");
+    apply(&mut screen, &e.redo());
+    assert_eq!(screen, "người");
+    assert_eq!(e.redo().action, Action::None as u8);
+}
+
+#[test]
+fn new_transform_clears_redo() {
+    let mut e = Engine::new();
+    let mut screen = type_word(&mut e, "as");
+    apply(&mut screen, &e.undo());
+    screen = type_word(&mut e, "f");
+    assert_eq!(screen, "à");
+    assert_eq!(e.redo().action, Action::None as u8);
+}
+
+fn undo_key_engine() -> Engine {
+    let mut e = Engine::new();
+    e.set_undo_key(Some(keys::BACKQUOTE));
+    e
+}
+
+#[test]
+fn undo_key() {
+    assert_eq!(type_word(&mut undo_key_engine(), "vieetj`"), "viêt");
+    assert_eq!(type_word(&mut undo_key_engine(), "vieetj``"), "viet");
+    // Shift + undo key redoes
+    assert_eq!(type_word(&mut undo_key_engine(), "vieetj``~"), "viêt");
+}
+
+#[test]
+fn undo_key_types_without_history() {
+    assert_eq!(type_word(&mut undo_key_engine(), "ok`"), "ok`");
+    assert_eq!(type_word(&mut Engine::new(), "vieetj`"), "việt`");
+}
+
+#[test]
+fn undo_key_in_settings() {
+    let json = undo_key_engine().settings().to_json();
+    assert!(json.contains("\"undo_key\":50"), "{json}");
+
+    let mut e = Engine::new();
+    e.apply_settings(&EngineSettings::from_json(&json).unwrap());
+    assert_eq!(e.undo_key(), Some(keys::BACKQUOTE));
+    assert_eq!(type_word(&mut e, "vieetj`"), "viêt");
+
+    // Null disables the key again
+    e.apply_settings(&EngineSettings::from_json(r#"{"version":2,"undo_key":null}"#).unwrap());
+    assert_eq!(e.undo_key(), None);
+}
@@ -0,0 +1,74 @@
+//! Word state snapshot/restore tests
+//! A snapshot taken mid-word, loaded into a fresh engine, continues
+//! exactly like the original engine.
+
+use gonhanh_core::engine::word_state::WordSnapshot;
+use gonhanh_core::engine::Engine;
+use gonhanh_core::utils::type_word;
+
+/// Type `prefix` + `rest` twice: straight through, and with the word moved
+/// to a fresh engine (via JSON) after `prefix`. Returns both buffers.
+fn resume(prefix: &str, rest: &str) -> (String, String) {
+    let mut e = Engine::new();
+    type_word(&mut e, &format!("{}{}", prefix, rest));
+    let expected = e.get_buffer_string();
+
+    let mut e = Engine::new();
+    type_word(&mut e, prefix);
+    let json = e.snapshot().to_json();
+    let mut fresh = Engine::new();
+    fresh.restore(&WordSnapshot::from_json(&json).unwrap());
+    type_word(&mut fresh, rest);
+    (fresh.get_buffer_string(), expected)
+}
+
+#[test]
+fn continues_like_original() {
+    let cases = [
+        ("ddd", "d"),   // stroke reverted: later d's stay plain
+        ("tess", "t"),  // pending pop after mark revert
+        ("duow", "c"),  // pending horn on u
+        ("raw", "n"),   // deferred breve
+        ("vieet", "j"), // plain mark on restored word
+    ];
+    for (prefix, rest) in cases {
+        let (resumed, expected) = resume(prefix, rest);
+        assert_eq!(resumed, expected, "{} + {}", prefix, rest);
+    }
+}
+
+#[test]
+fn json_roundtrip() {
+    let mut e = Engine::new();
+    type_word(&mut e, "dduwow");
+    let json = e.snapshot().to_json();
+    assert!(json.starts_with(r#"{"version":1,"buffer":["#));
+    let snapshot = WordSnapshot::from_json(&json).unwrap();
+    assert_eq!(snapshot.to_json(), json);
+
+    let mut e = Engine::new();
+    e.restore(&snapshot);
+    assert_eq!(e.get_buffer_string(), "đươ");
+    type_word(&mut e, "ngf");
+    assert_eq!(e.get_buffer_string(), "đường");
+}
+
+#[test]
+fn esc_uses_restored_raw_input() {
+    let mut e = Engine::new();
+    e.set_esc_restore(true);
+    assert_eq!(type_word(&mut e, "tex"), "tẽ");
+    let snapshot = e.snapshot();
+    let expected = type_word(&mut e, "\x1b");
+
+    let mut fresh = Engine::new();
+    fresh.set_esc_restore(true);
+    fresh.restore(&snapshot);
+    assert_eq!(type_word(&mut fresh, "\x1b"), expected);
+}
+
+#[test]
+fn invalid_json_rejected() {
+    assert!(WordSnapshot::from_json("{").is_none());
+    assert!(WordSnapshot::from_json(r#"{"buffer":"ab","raw_input":[]}"#).is_none());
+}
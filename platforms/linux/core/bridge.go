@@ -17,13 +17,15 @@ void ime_free(void* ptr);
 
 // Result struct must match Rust's #[repr(C)] layout exactly:
 // - chars[256] comes FIRST (256 * 4 = 1024 bytes) - MAX = 256 in Rust
-// - then action, backspace, count, flags (4 bytes)
+// - then action, backspace, count, flags, delete_after, caret_back (6 bytes)
 typedef struct {
     uint32_t chars[256]; // UTF-32 codepoints - MUST match Rust MAX=256
     uint8_t action;      // 0=None, 1=Send, 2=Restore
     uint8_t backspace;   // Number of backspaces
     uint8_t count;       // Number of valid chars
     uint8_t flags;       // Flags (bit 0 = key_consumed)
+    uint8_t delete_after; // Chars after the caret to delete (caret inside word)
+    uint8_t caret_back;   // Move caret left after sending chars
 } ImeResult;
 
 ImeResult* ime_key(uint16_t key, bool caps, bool ctrl);
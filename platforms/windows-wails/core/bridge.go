@@ -263,9 +263,12 @@ func (b *Bridge) ProcessKey(keycode uint16, capslock, ctrl, shift bool) ImeResul
 	defer b.pImeFree.Call(ptr)
 
 	// Parse native result structure
-	// struct { uint32[256] chars; uint8 action; uint8 backspace; uint8 count; uint8 flags; }
-	// Total size: 256*4 + 4 = 1028 bytes
-	data := (*[1028]byte)(unsafe.Pointer(ptr))
+	// struct { uint32[256] chars; uint8 action; uint8 backspace; uint8 count; uint8 flags;
+	//          uint8 delete_after; uint8 caret_back; }
+	// Total size: 256*4 + 6 = 1030 bytes (1032 with padding)
+	// delete_after and caret_back are only set with caret editing (ime_caret_editing),
+	// which this host never enables: LEFT/RIGHT end the word, so both are always 0.
+	data := (*[1030]byte)(unsafe.Pointer(ptr))
 
 	action := ImeAction(data[1024])
 	backspace := data[1025]